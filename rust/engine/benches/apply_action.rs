@@ -0,0 +1,86 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use engine::{apply_action, apply_action_in_place, list_legal_actions, DraftAction, State};
+
+/// System allocator that counts allocations, to compare the two apply paths
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+    
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn load_fixture() -> State {
+    let json = include_str!("../tests/fixtures/evaluator_benchmark_state.json");
+    serde_json::from_str(json).expect("Benchmark fixture should parse")
+}
+
+/// The moves of one drafting round, always taking the first legal action
+fn round_actions(state: &State) -> Vec<DraftAction> {
+    let mut state = state.clone();
+    let mut actions = Vec::new();
+    while let Some(action) = list_legal_actions(&state, state.active_player_id).into_iter().next() {
+        apply_action_in_place(&mut state, &action).unwrap();
+        actions.push(action);
+    }
+    actions
+}
+
+fn play_cloning(state: &State, actions: &[DraftAction]) -> State {
+    let mut state = state.clone();
+    for action in actions {
+        state = apply_action(&state, action).unwrap();
+    }
+    state
+}
+
+fn play_in_place(state: &State, actions: &[DraftAction]) -> State {
+    let mut state = state.clone();
+    for action in actions {
+        apply_action_in_place(&mut state, action).unwrap();
+    }
+    state
+}
+
+fn allocations_for(play: impl Fn() -> State) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    black_box(play());
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn bench_apply_action(c: &mut Criterion) {
+    let state = load_fixture();
+    let actions = round_actions(&state);
+    
+    println!(
+        "allocations per {}-move round: cloning {}, in place {}",
+        actions.len(),
+        allocations_for(|| play_cloning(&state, &actions)),
+        allocations_for(|| play_in_place(&state, &actions)),
+    );
+    
+    let mut group = c.benchmark_group("apply_action_round");
+    group.bench_function("cloning", |b| {
+        b.iter(|| play_cloning(black_box(&state), &actions))
+    });
+    group.bench_function("in_place", |b| {
+        b.iter(|| play_in_place(black_box(&state), &actions))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_apply_action);
+criterion_main!(benches);
@@ -0,0 +1,32 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use engine::{evaluate_best_move, EvaluatorParams, State};
+
+fn load_fixture() -> State {
+    let json = include_str!("../tests/fixtures/evaluator_benchmark_state.json");
+    serde_json::from_str(json).expect("Benchmark fixture should parse")
+}
+
+fn bench_evaluate_best_move(c: &mut Criterion) {
+    let state = load_fixture();
+    let player_id = state.active_player_id;
+    
+    let mut group = c.benchmark_group("evaluate_best_move");
+    for rollouts_per_action in [5, 10] {
+        let params = EvaluatorParams {
+            // Large budget so the rollout count, not the clock, bounds the work
+            time_budget_ms: 60_000,
+            rollouts_per_action,
+            evaluator_seed: 12345,
+            shortlist_size: 20,
+            ..EvaluatorParams::default()
+        };
+        
+        group.bench_function(format!("20x{}", rollouts_per_action), |b| {
+            b.iter(|| evaluate_best_move(black_box(&state), player_id, &params).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_evaluate_best_move);
+criterion_main!(benches);
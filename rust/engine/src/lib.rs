@@ -5,10 +5,10 @@ pub mod wasm_api;
 
 pub use model::*;
 pub use rules::*;
+pub use version::{engine_version, state_version, ruleset_id, supported_state_versions};
 
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
-use log;
 
 #[wasm_bindgen(start)]
 pub fn init() {
@@ -23,6 +23,17 @@ struct VersionInfo {
     ruleset_id: String,
 }
 
+#[derive(Serialize, Deserialize)]
+struct Capabilities {
+    engine_version: String,
+    ruleset_id: String,
+    player_counts: Vec<u8>,
+    policy_mixes: Vec<String>,
+    utility_modes: Vec<String>,
+    caching: bool,
+    parallelism: bool,
+}
+
 #[derive(Serialize, Deserialize)]
 struct PingResponse {
     status: String,
@@ -32,12 +43,38 @@ struct PingResponse {
 #[wasm_bindgen]
 pub fn get_version() -> String {
     let info = VersionInfo {
+        engine_version: engine_version().to_string(),
+        state_version: state_version(),
+        ruleset_id: ruleset_id().to_string(),
+    };
+    
+    serde_json::to_string(&info).unwrap()
+}
+
+/// Returns the features supported by this engine build as JSON string
+///
+/// Lists supported player counts, `PolicyMix` variants accepted in rollout
+/// and generator configs, evaluator utility modes, and whether result
+/// caching or parallel rollouts are compiled in.
+#[wasm_bindgen]
+pub fn get_capabilities() -> String {
+    let capabilities = Capabilities {
         engine_version: version::ENGINE_VERSION.to_string(),
-        state_version: version::STATE_VERSION,
         ruleset_id: version::RULESET_ID.to_string(),
+        player_counts: vec![2],
+        policy_mixes: vec![
+            "all_random".to_string(),
+            "all_greedy".to_string(),
+            "mixed".to_string(),
+            "greedy_lookahead".to_string(),
+            "profile".to_string(),
+        ],
+        utility_modes: vec!["score_difference".to_string(), "win_probability".to_string()],
+        caching: false,
+        parallelism: false,
     };
     
-    serde_json::to_string(&info).unwrap()
+    serde_json::to_string(&capabilities).unwrap()
 }
 
 /// Ping function that returns a simple status response as JSON string
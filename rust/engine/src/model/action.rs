@@ -9,7 +9,7 @@ use super::TileColor;
 ///
 /// - `Factory(n)` serializes to `{"Factory": n}`
 /// - `Center` serializes to `"Center"`
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub enum ActionSource {
     /// Take from a specific factory (index 0-4 for 2-player)
@@ -26,7 +26,7 @@ pub enum ActionSource {
 ///
 /// - `PatternLine(n)` serializes to `{"PatternLine": n}`
 /// - `Floor` serializes to `"Floor"`
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub enum Destination {
     /// Place tiles in a pattern line (row index 0-4)
@@ -49,6 +49,15 @@ pub enum Destination {
 /// - If taking from center with first-player token: token moves to player's floor
 /// - Tiles that don't fit in pattern line overflow to floor
 ///
+/// # Ordering
+///
+/// The derived `Ord` compares fields in declaration order: source
+/// (factories by index, then center), then color (`TileColor` declaration
+/// order), then destination (pattern lines by row, then floor). This
+/// canonical order lets actions key `BTreeMap`s and `BTreeSet`s, and is the
+/// order `list_legal_actions` returns them in. Reordering the fields or enum
+/// variants changes it.
+///
 /// # Example
 ///
 /// ```
@@ -60,7 +69,7 @@ pub enum Destination {
 ///     destination: Destination::PatternLine(2),
 /// };
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct DraftAction {
     pub source: ActionSource,
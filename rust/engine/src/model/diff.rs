@@ -0,0 +1,249 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use super::{
+    State, TileColor, TileMultiset, TileMultisetExt, RoundStage, GameStage, ScenarioTheme,
+    PatternLine, FloorLine, Wall, PlayerBoard,
+};
+
+/// Changes between two states, for sending moves over the network
+///
+/// Produced by `State::diff` and consumed by `State::apply_diff`. Only what
+/// changed is present: a typical draft move touches the active player, one
+/// factory, a few center colors and one player's pattern line and floor.
+///
+/// # Example
+///
+/// ```
+/// use engine::{State, TileColor};
+///
+/// let before = State::new_test_state();
+/// let mut after = before.clone();
+/// after.active_player_id = 1;
+/// after.center.tiles.insert(TileColor::Red, 2);
+///
+/// let diff = before.diff(&after);
+/// assert_eq!(before.apply_diff(&diff), after);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct StateDiff {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_player_id: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub draft_phase_progress: Option<RoundStage>,
+    /// Scenario and round metadata, sent whole if any of it changed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<DiffMeta>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bag: Option<TileMultiset>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lid: Option<TileMultiset>,
+    /// New factory count, if factories were added or removed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factory_count: Option<usize>,
+    /// Replacement contents of changed factories, by index
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub factories: BTreeMap<usize, TileMultiset>,
+    /// New counts of changed center colors (0 removes the color)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub center_tiles: BTreeMap<TileColor, u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub center_token: Option<bool>,
+    /// Changes to each player board, by player index
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub players: BTreeMap<usize, PlayerDiff>,
+    /// Replacement drafted counts, if either player's changed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drafted_counts: Option<[TileMultiset; 2]>,
+}
+
+/// State metadata that rarely changes between moves
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct DiffMeta {
+    pub state_version: u32,
+    pub ruleset_id: String,
+    pub scenario_seed: Option<String>,
+    pub round_number: u8,
+    pub scenario_game_stage: Option<GameStage>,
+    pub scenario_theme: Option<ScenarioTheme>,
+    pub hidden_bag: bool,
+    pub is_terminal: bool,
+}
+
+/// Changes to one player board
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PlayerDiff {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<i32>,
+    /// Replacement pattern lines, by row
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pattern_lines: BTreeMap<usize, PatternLine>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wall: Option<Wall>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub floor_line: Option<FloorLine>,
+}
+
+impl StateDiff {
+    /// Whether the two states were identical
+    pub fn is_empty(&self) -> bool {
+        *self == StateDiff::default()
+    }
+}
+
+impl DiffMeta {
+    fn of(state: &State) -> Self {
+        Self {
+            state_version: state.state_version,
+            ruleset_id: state.ruleset_id.clone(),
+            scenario_seed: state.scenario_seed.clone(),
+            round_number: state.round_number,
+            scenario_game_stage: state.scenario_game_stage,
+            scenario_theme: state.scenario_theme,
+            hidden_bag: state.hidden_bag,
+            is_terminal: state.is_terminal,
+        }
+    }
+}
+
+impl PlayerDiff {
+    fn between(from: &PlayerBoard, to: &PlayerBoard) -> Self {
+        Self {
+            score: (from.score != to.score).then_some(to.score),
+            pattern_lines: from.pattern_lines.iter()
+                .zip(&to.pattern_lines)
+                .enumerate()
+                .filter(|(_, (a, b))| a != b)
+                .map(|(row, (_, b))| (row, b.clone()))
+                .collect(),
+            wall: (from.wall != to.wall).then_some(to.wall),
+            floor_line: (from.floor_line != to.floor_line).then(|| to.floor_line.clone()),
+        }
+    }
+}
+
+impl State {
+    /// Compute the changes that turn `self` into `other`
+    ///
+    /// `self.apply_diff(&self.diff(other)) == *other` for any two states.
+    pub fn diff(&self, other: &State) -> StateDiff {
+        let meta_before = DiffMeta::of(self);
+        let meta_after = DiffMeta::of(other);
+        
+        let factories = other.factories.iter()
+            .enumerate()
+            .filter(|(idx, factory)| self.factories.get(*idx) != Some(*factory))
+            .map(|(idx, factory)| (idx, factory.clone()))
+            .collect();
+        
+        let center_tiles = self.center.tiles.keys()
+            .chain(other.center.tiles.keys())
+            .filter(|&&color| self.center.tiles.count(color) != other.center.tiles.count(color))
+            .map(|&color| (color, other.center.tiles.count(color)))
+            .collect();
+        
+        let players = self.players.iter()
+            .zip(&other.players)
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(idx, (a, b))| (idx, PlayerDiff::between(a, b)))
+            .collect();
+        
+        StateDiff {
+            active_player_id: (self.active_player_id != other.active_player_id)
+                .then_some(other.active_player_id),
+            draft_phase_progress: (self.draft_phase_progress != other.draft_phase_progress)
+                .then_some(other.draft_phase_progress),
+            meta: (meta_before != meta_after).then_some(meta_after),
+            bag: (self.bag != other.bag).then(|| other.bag.clone()),
+            lid: (self.lid != other.lid).then(|| other.lid.clone()),
+            factory_count: (self.factories.len() != other.factories.len())
+                .then_some(other.factories.len()),
+            factories,
+            center_tiles,
+            center_token: (self.center.has_first_player_token != other.center.has_first_player_token)
+                .then_some(other.center.has_first_player_token),
+            players,
+            drafted_counts: (self.drafted_counts != other.drafted_counts)
+                .then(|| other.drafted_counts.clone()),
+        }
+    }
+    
+    /// Apply a diff produced by `State::diff`, returning the updated state
+    ///
+    /// Entries that point past the end of `factories` or `players` are
+    /// ignored unless `factory_count` grows the factory list to cover them.
+    pub fn apply_diff(&self, diff: &StateDiff) -> State {
+        let mut state = self.clone();
+        
+        if let Some(active_player_id) = diff.active_player_id {
+            state.active_player_id = active_player_id;
+        }
+        if let Some(stage) = diff.draft_phase_progress {
+            state.draft_phase_progress = stage;
+        }
+        if let Some(meta) = &diff.meta {
+            state.state_version = meta.state_version;
+            state.ruleset_id = meta.ruleset_id.clone();
+            state.scenario_seed = meta.scenario_seed.clone();
+            state.round_number = meta.round_number;
+            state.scenario_game_stage = meta.scenario_game_stage;
+            state.scenario_theme = meta.scenario_theme;
+            state.hidden_bag = meta.hidden_bag;
+            state.is_terminal = meta.is_terminal;
+        }
+        if let Some(bag) = &diff.bag {
+            state.bag = bag.clone();
+        }
+        if let Some(lid) = &diff.lid {
+            state.lid = lid.clone();
+        }
+        
+        if let Some(count) = diff.factory_count {
+            state.factories.resize_with(count, TileMultiset::new);
+        }
+        for (&idx, factory) in &diff.factories {
+            if let Some(slot) = state.factories.get_mut(idx) {
+                *slot = factory.clone();
+            }
+        }
+        
+        for (&color, &count) in &diff.center_tiles {
+            if count == 0 {
+                state.center.tiles.remove(&color);
+            } else {
+                state.center.tiles.insert(color, count);
+            }
+        }
+        if let Some(token) = diff.center_token {
+            state.center.has_first_player_token = token;
+        }
+        
+        for (&idx, player_diff) in &diff.players {
+            let Some(player) = state.players.get_mut(idx) else {
+                continue;
+            };
+            if let Some(score) = player_diff.score {
+                player.score = score;
+            }
+            for (&row, line) in &player_diff.pattern_lines {
+                if let Some(slot) = player.pattern_lines.get_mut(row) {
+                    *slot = line.clone();
+                }
+            }
+            if let Some(wall) = player_diff.wall {
+                player.wall = wall;
+            }
+            if let Some(floor_line) = &player_diff.floor_line {
+                player.floor_line = floor_line.clone();
+            }
+        }
+        if let Some(drafted_counts) = &diff.drafted_counts {
+            state.drafted_counts = drafted_counts.clone();
+        }
+        
+        state
+    }
+}
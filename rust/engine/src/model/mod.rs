@@ -2,11 +2,14 @@ mod types;
 mod state;
 mod action;
 mod player;
+mod diff;
 
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests;
 
 pub use types::*;
 pub use state::*;
 pub use action::*;
 pub use player::*;
+pub use diff::*;
@@ -41,12 +41,13 @@ impl PatternLine {
 
 /// Floor line holds tiles that incur penalties
 ///
-/// The floor line has 7 penalty slots, but can hold more than 7 tiles.
-/// All tiles are tracked for discard, but only the first 7 incur penalties.
+/// The floor line has 7 penalty slots. Once they are full, further tiles go
+/// straight to the lid (see `place_on_floor`), so every tile held here is
+/// penalized.
 ///
 /// Penalty values: [-1, -1, -2, -2, -2, -3, -3] for slots 0-6.
 /// The first-player token occupies slot 0 if present.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct FloorLine {
     pub tiles: Vec<TileColor>,
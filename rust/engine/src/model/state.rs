@@ -1,6 +1,6 @@
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use super::{TileColor, RoundStage, GameStage, PlayerBoard};
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
+use super::{TileColor, RoundStage, GameStage, ScenarioTheme, PlayerBoard};
 
 /// Multiset of tiles represented as HashMap
 ///
@@ -8,6 +8,74 @@ use super::{TileColor, RoundStage, GameStage, PlayerBoard};
 /// This provides a sparse representation that is memory-efficient and easy to work with.
 pub type TileMultiset = HashMap<TileColor, u8>;
 
+/// Convenience operations on a `TileMultiset`
+///
+/// `TileMultiset` is a plain `HashMap` alias, so these helpers live on an
+/// extension trait. They keep the sparse invariant (no zero-count entries)
+/// that the rest of the engine relies on.
+///
+/// # Example
+///
+/// ```
+/// use engine::{TileColor, TileMultiset, TileMultisetExt};
+///
+/// let mut tiles = TileMultiset::new();
+/// tiles.add(TileColor::Red, 3);
+/// assert_eq!(tiles.count(TileColor::Red), 3);
+/// assert_eq!(tiles.remove_color(TileColor::Red), 3);
+/// assert_eq!(tiles.total(), 0);
+/// ```
+pub trait TileMultisetExt {
+    /// Number of tiles of `color` (0 if absent)
+    fn count(&self, color: TileColor) -> u8;
+    /// Add `n` tiles of `color` (no entry is created when `n` is 0)
+    fn add(&mut self, color: TileColor, n: u8);
+    /// Remove all tiles of `color`, returning how many were removed
+    fn remove_color(&mut self, color: TileColor) -> u8;
+    /// Total number of tiles across all colors
+    fn total(&self) -> u32;
+}
+
+impl TileMultisetExt for TileMultiset {
+    fn count(&self, color: TileColor) -> u8 {
+        self.get(&color).copied().unwrap_or(0)
+    }
+
+    fn add(&mut self, color: TileColor, n: u8) {
+        if n > 0 {
+            *self.entry(color).or_insert(0) += n;
+        }
+    }
+
+    fn remove_color(&mut self, color: TileColor) -> u8 {
+        self.remove(&color).unwrap_or(0)
+    }
+
+    fn total(&self) -> u32 {
+        self.values().map(|&count| count as u32).sum()
+    }
+}
+
+/// Serialize a `TileMultiset` with colors in canonical order
+///
+/// `HashMap` iteration order varies between runs, so without this equal
+/// multisets could serialize to different JSON. Colors are emitted in
+/// `TileColor` order (Blue, Yellow, Red, Black, White).
+pub(crate) fn serialize_tile_multiset<S: Serializer>(
+    tiles: &TileMultiset,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    tiles.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
+
+/// Serialize a list of `TileMultiset`s with colors in canonical order
+pub(crate) fn serialize_tile_multisets<S: Serializer>(
+    multisets: &[TileMultiset],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(multisets.iter().map(|tiles| tiles.iter().collect::<BTreeMap<_, _>>()))
+}
+
 /// Center area with tiles and first-player token
 ///
 /// The center accumulates tiles from factories as players take tiles.
@@ -18,6 +86,7 @@ pub type TileMultiset = HashMap<TileColor, u8>;
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct CenterArea {
+    #[serde(serialize_with = "serialize_tile_multiset")]
     pub tiles: TileMultiset,
     pub has_first_player_token: bool,
 }
@@ -30,7 +99,8 @@ pub struct CenterArea {
 /// # Serialization
 ///
 /// The state serializes to JSON with snake_case field names. The `scenario_seed` field
-/// is omitted from JSON when None.
+/// is omitted from JSON when None. Tile multisets (bag, lid, factories, center)
+/// list colors in canonical order, so equal states serialize to identical JSON.
 ///
 /// # Invariants
 ///
@@ -64,17 +134,56 @@ pub struct State {
     /// Across-game progress (Early/Mid/Late game based on wall development)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scenario_game_stage: Option<GameStage>,
+    /// Dominant decision the scenario showcases (see `classify_scenario_theme`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scenario_theme: Option<ScenarioTheme>,
     
     // Supply
+    #[serde(serialize_with = "serialize_tile_multiset")]
     pub bag: TileMultiset,
+    #[serde(serialize_with = "serialize_tile_multiset")]
     pub lid: TileMultiset,
     
     // Table
+    #[serde(serialize_with = "serialize_tile_multisets")]
     pub factories: Vec<TileMultiset>,
     pub center: CenterArea,
     
     // Players
     pub players: [PlayerBoard; 2],
+    
+    /// Bag contents are hidden from the player (see `redact_bag`)
+    ///
+    /// When set, `bag` is empty and its size is implied by tile conservation.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hidden_bag: bool,
+    
+    /// The game has ended and final scores (with bonuses) are in
+    ///
+    /// Set by `resolve_end_of_round` when a wall row is complete; no further
+    /// actions are legal.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_terminal: bool,
+    
+    /// Tiles of each color each player has drafted this game, by player index
+    ///
+    /// Bookkeeping for analytics and feedback, updated by `apply_action`.
+    /// These are not tiles in play and do not count toward conservation.
+    #[serde(
+        default,
+        skip_serializing_if = "drafted_counts_empty",
+        serialize_with = "serialize_tile_multisets"
+    )]
+    pub drafted_counts: [TileMultiset; 2],
+}
+
+fn drafted_counts_empty(counts: &[TileMultiset; 2]) -> bool {
+    counts.iter().all(|tiles| tiles.is_empty())
+}
+
+/// Equal per-color counts, treating absent colors as 0
+fn same_tiles(a: &TileMultiset, b: &TileMultiset) -> bool {
+    TileColor::all().all(|color| a.count(color) == b.count(color))
 }
 
 impl State {
@@ -98,6 +207,7 @@ impl State {
             round_number: 1,
             draft_phase_progress: RoundStage::Start,
             scenario_game_stage: None,
+            scenario_theme: None,
             bag: HashMap::new(),
             lid: HashMap::new(),
             factories: vec![HashMap::new(); 5],
@@ -106,6 +216,46 @@ impl State {
                 has_first_player_token: true,
             },
             players: [PlayerBoard::new(), PlayerBoard::new()],
+            hidden_bag: false,
+            is_terminal: false,
+            drafted_counts: Default::default(),
         }
     }
+    
+    /// Serialize without bag and lid contents, keeping only their sizes
+    ///
+    /// For sending positions to a UI that never shows the supply. The `bag`
+    /// and `lid` maps are replaced by `bag_total` and `lid_total`;
+    /// `from_lean_json` rebuilds a placeholder supply from them. Legality and
+    /// evaluation work on the rebuilt state, but anything that draws from the
+    /// bag (refill, end-of-round resolution) needs the full state for the
+    /// real colors.
+    pub fn to_lean_json(&self) -> String {
+        let mut value = serde_json::to_value(self).expect("State serialization cannot fail");
+        let fields = value.as_object_mut().expect("State serializes to an object");
+        fields.remove("bag");
+        fields.remove("lid");
+        fields.insert("bag_total".to_string(), self.bag.total().into());
+        fields.insert("lid_total".to_string(), self.lid.total().into());
+        value.to_string()
+    }
+    
+    /// Whether two states are the same game position, ignoring metadata
+    ///
+    /// Compares factories, center, players, bag, lid, active player and
+    /// round. Scenario labels, version fields, stage tags and the
+    /// `drafted_counts` history are ignored, as are zero-count multiset
+    /// entries. Unlike `fingerprint`, factory order matters, since actions
+    /// name factories by index.
+    pub fn position_eq(&self, other: &State) -> bool {
+        self.active_player_id == other.active_player_id
+            && self.round_number == other.round_number
+            && self.factories.len() == other.factories.len()
+            && self.factories.iter().zip(&other.factories).all(|(a, b)| same_tiles(a, b))
+            && same_tiles(&self.center.tiles, &other.center.tiles)
+            && self.center.has_first_player_token == other.center.has_first_player_token
+            && self.players == other.players
+            && same_tiles(&self.bag, &other.bag)
+            && same_tiles(&self.lid, &other.lid)
+    }
 }
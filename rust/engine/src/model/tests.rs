@@ -29,6 +29,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tile_color_all_in_canonical_order() {
+        let colors: Vec<TileColor> = TileColor::all().collect();
+        
+        assert_eq!(
+            colors,
+            vec![TileColor::Blue, TileColor::Yellow, TileColor::Red, TileColor::Black, TileColor::White]
+        );
+        assert!(colors.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_tile_color_index_round_trips() {
+        for (expected_index, color) in TileColor::all().enumerate() {
+            assert_eq!(color.index(), expected_index);
+            assert_eq!(TileColor::from_index(color.index()), Some(color));
+        }
+        assert_eq!(TileColor::from_index(TileColor::COUNT), None);
+    }
+
+    #[test]
+    fn test_tile_color_symbols_are_unique() {
+        let symbols: std::collections::HashSet<char> = TileColor::all().map(TileColor::symbol).collect();
+        assert_eq!(symbols.len(), TileColor::COUNT);
+        
+        for color in TileColor::all() {
+            let json = serde_json::to_string(&color).unwrap();
+            assert_eq!(json, format!("\"{}\"", color.label()));
+        }
+    }
+
     #[test]
     fn test_round_stage_serialization() {
         let stage = RoundStage::Mid;
@@ -259,6 +290,22 @@ mod tests {
         assert_eq!(restored.scenario_seed, Some("test_seed_123".to_string()));
     }
 
+    #[test]
+    fn test_position_eq_ignores_scenario_seed() {
+        let mut state = State::new_test_state();
+        state.factories[0].insert(TileColor::Red, 4);
+        
+        let mut relabeled = state.clone();
+        relabeled.scenario_seed = Some("test_seed_123".to_string());
+        relabeled.factories[1].insert(TileColor::Blue, 0);
+        assert_ne!(state, relabeled);
+        assert!(state.position_eq(&relabeled));
+        
+        let mut moved = state.clone();
+        moved.active_player_id = 1;
+        assert!(!state.position_eq(&moved));
+    }
+
     #[test]
     fn test_json_field_names_snake_case() {
         let state = State::new_test_state();
@@ -298,6 +345,47 @@ mod tests {
         assert_eq!(restored.get(&TileColor::Yellow), None);
     }
 
+    #[test]
+    fn test_tile_multiset_count_and_add() {
+        let mut multiset = TileMultiset::new();
+        assert_eq!(multiset.count(TileColor::Blue), 0);
+        
+        multiset.add(TileColor::Blue, 2);
+        multiset.add(TileColor::Blue, 1);
+        assert_eq!(multiset.count(TileColor::Blue), 3);
+        
+        // Adding zero tiles must not create an entry
+        multiset.add(TileColor::Red, 0);
+        assert!(!multiset.contains_key(&TileColor::Red));
+        assert_eq!(multiset.len(), 1);
+    }
+
+    #[test]
+    fn test_tile_multiset_remove_color() {
+        let mut multiset = TileMultiset::new();
+        multiset.add(TileColor::Black, 4);
+        
+        assert_eq!(multiset.remove_color(TileColor::Black), 4);
+        assert_eq!(multiset.count(TileColor::Black), 0);
+        assert!(multiset.is_empty());
+        
+        // Removing an absent color is a no-op returning 0
+        assert_eq!(multiset.remove_color(TileColor::White), 0);
+        assert!(multiset.is_empty());
+    }
+
+    #[test]
+    fn test_tile_multiset_total() {
+        let mut multiset = TileMultiset::new();
+        assert_eq!(multiset.total(), 0);
+        
+        for &color in &[TileColor::Blue, TileColor::Yellow, TileColor::Red, TileColor::Black, TileColor::White] {
+            multiset.add(color, 20);
+        }
+        
+        assert_eq!(multiset.total(), 100);
+    }
+
     #[test]
     fn test_floor_line_serialization() {
         let floor_line = FloorLine {
@@ -313,6 +401,39 @@ mod tests {
         assert!(restored.has_first_player_token);
     }
 
+    #[test]
+    fn test_equal_multisets_serialize_identically() {
+        let colors = [TileColor::Blue, TileColor::Yellow, TileColor::Red, TileColor::Black, TileColor::White];
+        let build = |order: &[TileColor]| {
+            let mut state = State::new_test_state();
+            for &color in order {
+                let count = colors.iter().position(|&c| c == color).unwrap() as u8 + 1;
+                state.bag.insert(color, count);
+                state.lid.insert(color, count + 1);
+                state.factories[0].insert(color, count);
+                state.factories[count as usize % 4 + 1].insert(color, 1);
+                state.center.tiles.insert(color, count);
+            }
+            state
+        };
+        
+        let forward = build(&colors);
+        let mut reversed_order = colors;
+        reversed_order.reverse();
+        let reversed = build(&reversed_order);
+        assert_eq!(forward, reversed);
+        
+        let forward_json = serde_json::to_string(&forward).unwrap();
+        // Fresh HashMaps get fresh hash seeds, so repeat to exercise iteration order
+        for _ in 0..10 {
+            let reversed_json = serde_json::to_string(&reversed.clone()).unwrap();
+            assert_eq!(forward_json, reversed_json);
+            let rebuilt: State = serde_json::from_str(&reversed_json).unwrap();
+            assert_eq!(serde_json::to_string(&rebuilt).unwrap(), forward_json);
+        }
+        assert!(forward_json.contains(r#""center":{"tiles":{"Blue":1,"Yellow":2,"Red":3,"Black":4,"White":5}"#));
+    }
+
     #[test]
     fn test_center_area_serialization() {
         let mut tiles = TileMultiset::new();
@@ -364,4 +485,34 @@ mod tests {
         assert!(restored[4][4]);
         assert!(!restored[2][2]);
     }
+
+    #[test]
+    fn test_draft_action_canonical_order() {
+        use std::collections::BTreeSet;
+        
+        let action = |source, color, destination| DraftAction { source, color, destination };
+        let mut actions = vec![
+            action(ActionSource::Center, TileColor::Blue, Destination::Floor),
+            action(ActionSource::Factory(1), TileColor::Blue, Destination::PatternLine(0)),
+            action(ActionSource::Factory(0), TileColor::Red, Destination::PatternLine(1)),
+            action(ActionSource::Factory(0), TileColor::Blue, Destination::Floor),
+            action(ActionSource::Factory(0), TileColor::Blue, Destination::PatternLine(4)),
+            action(ActionSource::Center, TileColor::Blue, Destination::PatternLine(2)),
+        ];
+        let expected = vec![
+            action(ActionSource::Factory(0), TileColor::Blue, Destination::PatternLine(4)),
+            action(ActionSource::Factory(0), TileColor::Blue, Destination::Floor),
+            action(ActionSource::Factory(0), TileColor::Red, Destination::PatternLine(1)),
+            action(ActionSource::Factory(1), TileColor::Blue, Destination::PatternLine(0)),
+            action(ActionSource::Center, TileColor::Blue, Destination::PatternLine(2)),
+            action(ActionSource::Center, TileColor::Blue, Destination::Floor),
+        ];
+        
+        let set: BTreeSet<DraftAction> = actions.iter().cloned().collect();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), expected);
+        
+        actions.reverse();
+        actions.sort();
+        assert_eq!(actions, expected);
+    }
 }
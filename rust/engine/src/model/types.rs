@@ -3,7 +3,8 @@ use serde::{Deserialize, Serialize};
 /// The 5 tile colors in Azul
 ///
 /// Each color appears 20 times in the game, for a total of 100 tiles.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Colors order by declaration (wall row 0 order).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum TileColor {
     Blue,
     Yellow,
@@ -12,6 +13,74 @@ pub enum TileColor {
     White,
 }
 
+impl TileColor {
+    /// Number of tile colors
+    pub const COUNT: usize = 5;
+    
+    /// Every color, in canonical (wall row 0) order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use engine::TileColor;
+    ///
+    /// assert_eq!(TileColor::all().next(), Some(TileColor::Blue));
+    /// assert_eq!(TileColor::all().count(), TileColor::COUNT);
+    /// ```
+    pub fn all() -> impl Iterator<Item = TileColor> {
+        [
+            TileColor::Blue,
+            TileColor::Yellow,
+            TileColor::Red,
+            TileColor::Black,
+            TileColor::White,
+        ]
+        .into_iter()
+    }
+    
+    /// Position of this color in canonical order (0-4), for array storage
+    pub fn index(self) -> usize {
+        self as usize
+    }
+    
+    /// Color at a canonical position, or None if `index` is not 0-4
+    pub fn from_index(index: usize) -> Option<TileColor> {
+        TileColor::all().nth(index)
+    }
+    
+    /// Distinct glyph for this color, for players who tell tiles apart by shape
+    ///
+    /// Display-only; serialization still uses the color name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use engine::TileColor;
+    ///
+    /// assert_eq!(TileColor::Blue.symbol(), '◆');
+    /// ```
+    pub fn symbol(self) -> char {
+        match self {
+            TileColor::Blue => '◆',
+            TileColor::Yellow => '●',
+            TileColor::Red => '▲',
+            TileColor::Black => '■',
+            TileColor::White => '★',
+        }
+    }
+    
+    /// Human-readable color name
+    pub fn label(self) -> &'static str {
+        match self {
+            TileColor::Blue => "Blue",
+            TileColor::Yellow => "Yellow",
+            TileColor::Red => "Red",
+            TileColor::Black => "Black",
+            TileColor::White => "White",
+        }
+    }
+}
+
 /// Phase of the draft within a single round (within-round progress)
 ///
 /// Tracks how much of the current round's drafting has progressed based on
@@ -42,6 +111,28 @@ pub enum GameStage {
     Late,
 }
 
+/// Dominant decision a scenario showcases
+///
+/// Used to organize a practice library; see `classify_scenario_theme`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScenarioTheme {
+    /// Best move keeps tiles off the floor where the alternatives overflow
+    AvoidOverflow,
+    /// Best move takes the first player token from the center
+    TakeFirstPlayerToken,
+    /// Best move completes pattern lines the alternatives leave open
+    CompleteLine,
+    /// Best move takes a color the opponent needs to finish a pattern line
+    BlockOpponent,
+    /// Best move fills a line whose wall cell touches tiles in its column
+    SetUpColumn,
+    /// Every legal move goes to the floor; the lesson is what to dump
+    ForcedDump,
+    /// No single decision stands out
+    General,
+}
+
 /// Legacy alias for backward compatibility
 /// This will be deprecated in favor of separate RoundStage and GameStage
 pub type DraftPhase = RoundStage;
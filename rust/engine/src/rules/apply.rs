@@ -1,7 +1,9 @@
-use crate::{State, DraftAction, ActionSource, Destination};
+use crate::{State, DraftAction, ActionSource, Destination, TileMultisetExt};
 use super::{ValidationError, can_place_in_pattern_line, get_wall_column_for_color};
+use super::{place_on_floor, place_first_player_token};
+use super::trace::{trace_tile_move, TileLocation};
 #[cfg(debug_assertions)]
-use super::check_tile_conservation;
+use super::{check_tile_conservation, check_factory_capacity, check_floor_capacity};
 
 /// Apply a draft action to the game state
 ///
@@ -10,7 +12,9 @@ use super::check_tile_conservation;
 /// - Tile removal from source
 /// - Factory remnants moving to center
 /// - First-player token transfer
-/// - Tile placement in destination (with overflow)
+/// - Tile placement in destination (with overflow; tiles that do not fit
+///   on a full floor go to the lid)
+/// - Drafted-count bookkeeping (`State::drafted_counts`)
 /// - Active player toggle
 ///
 /// # Arguments
@@ -45,8 +49,38 @@ use super::check_tile_conservation;
 /// let new_state = apply_action(&state, &action).unwrap();
 /// ```
 pub fn apply_action(state: &State, action: &DraftAction) -> Result<State, ValidationError> {
-    // Step 1: Validate action legality
-    let player = &state.players[state.active_player_id as usize];
+    let tile_count = validate_action(state, action)?;
+    let mut new_state = state.clone();
+    apply_validated(&mut new_state, action, tile_count);
+    Ok(new_state)
+}
+
+/// Apply a draft action to the game state without cloning it
+///
+/// Same rules and errors as `apply_action`, but mutates `state` in place
+/// for hot loops such as rollouts, which discard the previous position
+/// anyway and would otherwise copy the bag, lid, and both boards per move.
+/// On error `state` is left unchanged.
+pub fn apply_action_in_place(state: &mut State, action: &DraftAction) -> Result<(), ValidationError> {
+    let tile_count = validate_action(state, action)?;
+    apply_validated(state, action, tile_count);
+    Ok(())
+}
+
+/// Check that `action` is legal for the active player
+///
+/// Returns the number of tiles the action takes.
+fn validate_action(state: &State, action: &DraftAction) -> Result<u8, ValidationError> {
+    // Validate action legality (nothing is legal once the game ends,
+    // matching list_legal_actions)
+    if state.is_terminal {
+        return Err(ValidationError::game_over());
+    }
+    
+    // A corrupted or hand-edited state may name a seat that does not exist
+    let Some(player) = state.players.get(state.active_player_id as usize) else {
+        return Err(ValidationError::invalid_active_player(state.active_player_id));
+    };
     
     // Check source exists and has the color
     let tile_count = match &action.source {
@@ -54,10 +88,10 @@ pub fn apply_action(state: &State, action: &DraftAction) -> Result<State, Valida
             if *idx >= state.factories.len() {
                 return Err(ValidationError::invalid_source(*idx));
             }
-            *state.factories[*idx].get(&action.color).unwrap_or(&0)
+            state.factories[*idx].count(action.color)
         }
         ActionSource::Center => {
-            *state.center.tiles.get(&action.color).unwrap_or(&0)
+            state.center.tiles.count(action.color)
         }
     };
     
@@ -96,41 +130,51 @@ pub fn apply_action(state: &State, action: &DraftAction) -> Result<State, Valida
         }
     }
     
-    // Action is valid, proceed with state mutation
-    // Step 2: Clone state
-    let mut new_state = state.clone();
-    
-    // Step 3: Remove tiles from source
+    Ok(tile_count)
+}
+
+/// Apply an action `validate_action` has accepted
+fn apply_validated(state: &mut State, action: &DraftAction, tile_count: u8) {
+    // Step 1: Remove tiles from source
     match &action.source {
         ActionSource::Factory(idx) => {
-            new_state.factories[*idx].remove(&action.color);
+            state.factories[*idx].remove_color(action.color);
         }
         ActionSource::Center => {
-            new_state.center.tiles.remove(&action.color);
+            state.center.tiles.remove_color(action.color);
         }
     }
     
-    // Step 4: Move factory remnants to center (if taking from factory)
+    // Step 2: Move factory remnants to center (if taking from factory)
     if let ActionSource::Factory(idx) = &action.source {
-        // Get all remaining tiles from factory
-        for (color, count) in new_state.factories[*idx].iter() {
-            *new_state.center.tiles.entry(*color).or_insert(0) += count;
+        // Move all remaining tiles from factory (clearing it)
+        for (color, count) in state.factories[*idx].drain() {
+            trace_tile_move(TileLocation::Factory(*idx), TileLocation::Center, color, count);
+            state.center.tiles.add(color, count);
         }
-        
-        // Clear the factory
-        new_state.factories[*idx].clear();
     }
     
-    // Step 5: Handle first-player token
-    if action.source == ActionSource::Center && new_state.center.has_first_player_token {
-        new_state.center.has_first_player_token = false;
+    let player_idx = state.active_player_id as usize;
+    let from = match &action.source {
+        ActionSource::Factory(idx) => TileLocation::Factory(*idx),
+        ActionSource::Center => TileLocation::Center,
+    };
+    let floor = TileLocation::Floor { player: player_idx };
+    
+    // Step 3: Handle first-player token
+    if action.source == ActionSource::Center && state.center.has_first_player_token {
+        state.center.has_first_player_token = false;
         
-        let player = &mut new_state.players[new_state.active_player_id as usize];
-        player.floor_line.has_first_player_token = true;
+        let player = &mut state.players[player_idx];
+        if let Some(bumped) = place_first_player_token(&mut player.floor_line, &mut state.lid) {
+            trace_tile_move(floor, TileLocation::Lid, bumped, 1);
+        }
     }
     
-    // Step 6: Place tiles in destination (with overflow)
-    let player = &mut new_state.players[new_state.active_player_id as usize];
+    // Step 4: Place tiles in destination (with overflow; a full floor sends
+    // the rest to the lid)
+    state.drafted_counts[state.active_player_id as usize].add(action.color, tile_count);
+    let player = &mut state.players[state.active_player_id as usize];
     
     match &action.destination {
         Destination::PatternLine(row) => {
@@ -147,29 +191,38 @@ pub fn apply_action(state: &State, action: &DraftAction) -> Result<State, Valida
                 pattern_line.color = Some(action.color);
             }
             
+            trace_tile_move(
+                from,
+                TileLocation::PatternLine { player: player_idx, row: *row },
+                action.color,
+                tiles_to_place,
+            );
+            
             // Overflow tiles go to floor
-            for _ in 0..overflow {
-                player.floor_line.tiles.push(action.color);
-            }
+            let to_lid = place_on_floor(&mut player.floor_line, &mut state.lid, action.color, overflow);
+            trace_tile_move(from, floor, action.color, overflow - to_lid);
+            trace_tile_move(from, TileLocation::Lid, action.color, to_lid);
         }
         
         Destination::Floor => {
             // All tiles go directly to floor
-            for _ in 0..tile_count {
-                player.floor_line.tiles.push(action.color);
-            }
+            let to_lid = place_on_floor(&mut player.floor_line, &mut state.lid, action.color, tile_count);
+            trace_tile_move(from, floor, action.color, tile_count - to_lid);
+            trace_tile_move(from, TileLocation::Lid, action.color, to_lid);
         }
     }
     
-    // Step 7: Update active player
-    new_state.active_player_id = 1 - new_state.active_player_id;
+    // Step 5: Update active player
+    state.active_player_id = 1 - state.active_player_id;
     
-    // Step 8: Verify invariants (in debug mode)
+    // Step 6: Verify invariants (in debug mode)
     #[cfg(debug_assertions)]
     {
-        check_tile_conservation(&new_state)
+        check_tile_conservation(state)
             .expect("Tile conservation invariant violated");
+        check_factory_capacity(state)
+            .expect("Factory capacity invariant violated");
+        check_floor_capacity(state)
+            .expect("Floor capacity invariant violated");
     }
-    
-    Ok(new_state)
 }
@@ -0,0 +1,266 @@
+use crate::model::{FloorLine, PatternLine, PlayerBoard, TileColor};
+use super::constants::FLOOR_LINE_SLOTS;
+use super::floor::floor_slots_used;
+use super::wall_utils::get_wall_column_for_color;
+
+/// Letters for each color in canonical order (K is black, as in CMYK)
+const COLOR_LETTERS: [char; TileColor::COUNT] = ['B', 'Y', 'R', 'K', 'W'];
+
+/// Floor marker for the first-player token
+const TOKEN_LETTER: char = '1';
+
+/// Errors that prevent a board code from being decoded
+#[derive(Debug, Clone, PartialEq)]
+pub enum BoardCodeError {
+    /// Code does not have the four dot-separated sections
+    WrongSectionCount(usize),
+    /// Wall section is not a 25-bit hex number
+    InvalidWall(String),
+    /// Pattern line section is malformed or breaks a line's invariants
+    InvalidPatternLine { row: usize, reason: String },
+    /// Floor section holds an unknown letter or too many slots
+    InvalidFloor(String),
+    /// Score section is not an integer
+    InvalidScore(String),
+}
+
+impl std::fmt::Display for BoardCodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BoardCodeError::WrongSectionCount(n) => write!(
+                f, "Expected 4 board code sections, found {}", n
+            ),
+            BoardCodeError::InvalidWall(reason) => write!(f, "Invalid wall: {}", reason),
+            BoardCodeError::InvalidPatternLine { row, reason } => write!(
+                f, "Pattern line {} is invalid: {}", row, reason
+            ),
+            BoardCodeError::InvalidFloor(reason) => write!(f, "Invalid floor: {}", reason),
+            BoardCodeError::InvalidScore(reason) => write!(f, "Invalid score: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for BoardCodeError {}
+
+fn color_letter(color: TileColor) -> char {
+    COLOR_LETTERS[color.index()]
+}
+
+fn letter_color(letter: char) -> Option<TileColor> {
+    COLOR_LETTERS
+        .iter()
+        .position(|&c| c == letter)
+        .and_then(TileColor::from_index)
+}
+
+/// Encode a single player board as a short shareable string
+///
+/// The code is `wall.lines.floor.score`: the wall as 7 hex digits (bit
+/// `row * 5 + col`), each pattern line as a color letter and count (`-0`
+/// when empty), the floor as color letters with `1` for the first-player
+/// token, and the score in decimal. Unlike a full state, it carries no
+/// factories, bag, or opponent.
+///
+/// # Example
+///
+/// ```
+/// use engine::{encode_board, PlayerBoard};
+///
+/// assert_eq!(encode_board(&PlayerBoard::new()), "0000000.-0-0-0-0-0..0");
+/// ```
+pub fn encode_board(player: &PlayerBoard) -> String {
+    let mut wall_bits: u32 = 0;
+    for (row, cells) in player.wall.iter().enumerate() {
+        for (col, &filled) in cells.iter().enumerate() {
+            if filled {
+                wall_bits |= 1 << (row * 5 + col);
+            }
+        }
+    }
+
+    let lines: String = player
+        .pattern_lines
+        .iter()
+        .map(|line| {
+            let letter = line.color.map_or('-', color_letter);
+            format!("{}{}", letter, line.count_filled)
+        })
+        .collect();
+
+    let mut floor = String::new();
+    if player.floor_line.has_first_player_token {
+        floor.push(TOKEN_LETTER);
+    }
+    floor.extend(player.floor_line.tiles.iter().map(|&c| color_letter(c)));
+
+    format!("{:07x}.{}.{}.{}", wall_bits, lines, floor, player.score)
+}
+
+/// Decode a board code produced by `encode_board`
+///
+/// Rejects codes that describe impossible boards: overfull or colorless
+/// pattern lines, a line whose color is already on that wall row, or a
+/// floor over 7 slots.
+///
+/// # Errors
+///
+/// Returns `BoardCodeError` naming the first malformed section.
+pub fn decode_board(code: &str) -> Result<PlayerBoard, BoardCodeError> {
+    let sections: Vec<&str> = code.trim().split('.').collect();
+    let [wall_code, lines_code, floor_code, score_code] = sections[..] else {
+        return Err(BoardCodeError::WrongSectionCount(sections.len()));
+    };
+
+    let mut board = PlayerBoard::new();
+
+    // Wall
+    if wall_code.len() != 7 {
+        return Err(BoardCodeError::InvalidWall(format!(
+            "expected 7 hex digits, found {}", wall_code.len()
+        )));
+    }
+    let wall_bits = u32::from_str_radix(wall_code, 16)
+        .map_err(|e| BoardCodeError::InvalidWall(e.to_string()))?;
+    if wall_bits >> 25 != 0 {
+        return Err(BoardCodeError::InvalidWall("bits set beyond cell 24".to_string()));
+    }
+    for (row, cells) in board.wall.iter_mut().enumerate() {
+        for (col, cell) in cells.iter_mut().enumerate() {
+            *cell = wall_bits & (1 << (row * 5 + col)) != 0;
+        }
+    }
+
+    // Pattern lines
+    let line_chars: Vec<char> = lines_code.chars().collect();
+    if line_chars.len() != 10 {
+        return Err(BoardCodeError::InvalidPatternLine {
+            row: line_chars.len() / 2,
+            reason: format!("expected 10 characters, found {}", line_chars.len()),
+        });
+    }
+    for (row, pair) in line_chars.chunks(2).enumerate() {
+        let invalid = |reason: String| BoardCodeError::InvalidPatternLine { row, reason };
+        let count = pair[1]
+            .to_digit(10)
+            .ok_or_else(|| invalid(format!("'{}' is not a count", pair[1])))? as u8;
+        let color = match pair[0] {
+            '-' => None,
+            letter => Some(
+                letter_color(letter)
+                    .ok_or_else(|| invalid(format!("'{}' is not a color", letter)))?,
+            ),
+        };
+
+        let line = PatternLine { capacity: (row + 1) as u8, color, count_filled: count };
+        if line.count_filled > line.capacity {
+            return Err(invalid(format!(
+                "{} tiles exceed capacity {}", line.count_filled, line.capacity
+            )));
+        }
+        match line.color {
+            None if count > 0 => return Err(invalid("tiles without a color".to_string())),
+            Some(_) if count == 0 => return Err(invalid("color without tiles".to_string())),
+            Some(color) if board.wall[row][get_wall_column_for_color(row, color)] => {
+                return Err(invalid(format!("{:?} is already on the wall", color)));
+            }
+            _ => {}
+        }
+        board.pattern_lines[row] = line;
+    }
+
+    // Floor
+    let mut floor = FloorLine { tiles: Vec::new(), has_first_player_token: false };
+    for (idx, letter) in floor_code.chars().enumerate() {
+        if letter == TOKEN_LETTER && idx == 0 {
+            floor.has_first_player_token = true;
+        } else {
+            let color = letter_color(letter).ok_or_else(|| {
+                BoardCodeError::InvalidFloor(format!("'{}' is not a color", letter))
+            })?;
+            floor.tiles.push(color);
+        }
+    }
+    let used = floor_slots_used(&floor);
+    if used > FLOOR_LINE_SLOTS {
+        return Err(BoardCodeError::InvalidFloor(format!(
+            "{} slots used (max {})", used, FLOOR_LINE_SLOTS
+        )));
+    }
+    board.floor_line = floor;
+
+    // Score
+    board.score = score_code
+        .parse()
+        .map_err(|e: std::num::ParseIntError| BoardCodeError::InvalidScore(e.to_string()))?;
+
+    Ok(board)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// A board with random wall, lines, and floor that obeys every invariant
+    fn random_board(rng: &mut StdRng) -> PlayerBoard {
+        let mut board = PlayerBoard::new();
+        for cells in board.wall.iter_mut() {
+            for cell in cells.iter_mut() {
+                *cell = rng.gen_bool(0.4);
+            }
+        }
+        for row in 0..5 {
+            let color = TileColor::from_index(rng.gen_range(0..TileColor::COUNT)).unwrap();
+            let count = rng.gen_range(0..=row as u8 + 1);
+            if count > 0 && !board.wall[row][get_wall_column_for_color(row, color)] {
+                board.pattern_lines[row].color = Some(color);
+                board.pattern_lines[row].count_filled = count;
+            }
+        }
+        board.floor_line.has_first_player_token = rng.gen_bool(0.3);
+        let tiles = rng.gen_range(0..=FLOOR_LINE_SLOTS - floor_slots_used(&board.floor_line));
+        for _ in 0..tiles {
+            let color = TileColor::from_index(rng.gen_range(0..TileColor::COUNT)).unwrap();
+            board.floor_line.tiles.push(color);
+        }
+        board.score = rng.gen_range(0..150);
+        board
+    }
+
+    #[test]
+    fn test_board_code_round_trips_random_boards() {
+        let mut rng = StdRng::seed_from_u64(2173);
+        for _ in 0..500 {
+            let board = random_board(&mut rng);
+            let code = encode_board(&board);
+            assert_eq!(decode_board(&code), Ok(board), "code {}", code);
+        }
+    }
+
+    #[test]
+    fn test_decode_board_rejects_impossible_boards() {
+        // Blue already sits at row 0, col 0
+        assert!(matches!(
+            decode_board("0000001.B0-0-0-0-0..0"),
+            Err(BoardCodeError::InvalidPatternLine { row: 0, .. })
+        ));
+        assert!(matches!(
+            decode_board("0000001.B1-0-0-0-0..0"),
+            Err(BoardCodeError::InvalidPatternLine { row: 0, .. })
+        ));
+        assert!(matches!(
+            decode_board("0000000.-0R3-0-0-0..0"),
+            Err(BoardCodeError::InvalidPatternLine { row: 1, .. })
+        ));
+        assert!(matches!(
+            decode_board("0000000.-0-0-0-0-0.1BBBBBBB.0"),
+            Err(BoardCodeError::InvalidFloor(_))
+        ));
+        assert!(matches!(
+            decode_board("2000000.-0-0-0-0-0..0"),
+            Err(BoardCodeError::InvalidWall(_))
+        ));
+        assert_eq!(decode_board("0000000.-0-0-0-0-0"), Err(BoardCodeError::WrongSectionCount(2)));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::model::{State, TileMultiset, TileMultisetExt};
+use super::constants::ALL_COLORS;
+
+/// Reorder a state so equivalent factory arrangements compare equal
+///
+/// Factories are interchangeable: which one holds a given set of tiles does
+/// not change the position. The returned state sorts factories by their
+/// per-color counts and drops zero-count entries from every multiset, so two
+/// states that differ only by factory order canonicalize to the same value.
+///
+/// Sorting changes factory indices, so actions computed on the canonical
+/// form do not apply to the original. Use it for comparison and caching only.
+pub fn canonicalize(state: &State) -> State {
+    let mut canonical = state.clone();
+
+    for tiles in canonical.factories.iter_mut()
+        .chain([&mut canonical.bag, &mut canonical.lid, &mut canonical.center.tiles])
+    {
+        tiles.retain(|_, count| *count > 0);
+    }
+    canonical.factories.sort_by_key(factory_key);
+
+    canonical
+}
+
+/// Stable 64-bit hash of a position, for dedup and cache keys
+///
+/// Built on `canonicalize`, so factory order does not matter. Scenario labels
+/// (`scenario_seed`, `scenario_game_stage`, `scenario_theme`) and the
+/// `drafted_counts` history describe how a position was reached rather than
+/// the position itself and are ignored. The
+/// hash is FNV-1a over the canonical JSON, which is the same on every
+/// platform, including WASM.
+pub fn fingerprint(state: &State) -> u64 {
+    let mut canonical = canonicalize(state);
+    canonical.scenario_seed = None;
+    canonical.scenario_game_stage = None;
+    canonical.scenario_theme = None;
+    canonical.drafted_counts = Default::default();
+
+    let json = serde_json::to_string(&canonical).expect("State serialization cannot fail");
+    json.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Sort key for a factory: tile counts in canonical color order
+fn factory_key(tiles: &TileMultiset) -> [u8; 5] {
+    ALL_COLORS.map(|color| tiles.count(color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::TileColor;
+
+    #[test]
+    fn test_swapped_factories_canonicalize_equal() {
+        let mut state = State::new_test_state();
+        state.factories[0].insert(TileColor::Red, 4);
+        state.factories[1].insert(TileColor::Blue, 2);
+        state.factories[1].insert(TileColor::White, 2);
+
+        let mut swapped = state.clone();
+        swapped.factories.swap(0, 1);
+        swapped.factories[4].insert(TileColor::Black, 0);
+        assert_ne!(state, swapped);
+
+        assert_eq!(canonicalize(&state), canonicalize(&swapped));
+        assert_eq!(fingerprint(&state), fingerprint(&swapped));
+    }
+
+    #[test]
+    fn test_fingerprint_distinguishes_positions() {
+        let mut state = State::new_test_state();
+        state.factories[0].insert(TileColor::Red, 4);
+
+        let mut other = state.clone();
+        other.active_player_id = 1;
+        let mut relabeled = state.clone();
+        relabeled.scenario_seed = Some("2132".to_string());
+
+        assert_ne!(fingerprint(&state), fingerprint(&other));
+        assert_eq!(fingerprint(&state), fingerprint(&relabeled));
+        assert!(state.position_eq(&relabeled));
+    }
+}
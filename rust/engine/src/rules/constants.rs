@@ -39,3 +39,12 @@ pub const PATTERN_LINE_COUNT: usize = 5;
 ///
 /// Score cannot go below 0.
 pub const FLOOR_PENALTIES: [i32; 7] = [-1, -1, -2, -2, -2, -3, -3];
+
+/// End-of-game bonus for each complete horizontal wall row
+pub const ROW_BONUS: i32 = 2;
+
+/// End-of-game bonus for each complete vertical wall column
+pub const COLUMN_BONUS: i32 = 7;
+
+/// End-of-game bonus for each color with all 5 tiles on the wall
+pub const COLOR_BONUS: i32 = 10;
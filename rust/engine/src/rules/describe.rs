@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use crate::model::{State, TileMultiset};
+use super::feedback::describe_tile_colors;
+use super::scoring::calculate_floor_penalty;
+
+/// Plain-language description of a position, for screen readers
+///
+/// Each field is a complete sentence or phrase so the UI can read them aloud
+/// in order. Factories, lines and players are numbered from 1, as in the
+/// rest of the coaching text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PositionDescription {
+    /// Player to move (0 or 1)
+    pub active_player_id: u8,
+    /// Whose turn it is, e.g. "Player 1 to move (your turn)."
+    pub turn: String,
+    /// Number of factories on the table, including empty ones
+    pub factory_count: usize,
+    /// One entry per factory, e.g. "Factory 2: 3 Red tiles and 1 Black tile."
+    pub factories: Vec<String>,
+    pub center: String,
+    /// The described player's pattern lines, top to bottom
+    pub pattern_lines: Vec<String>,
+    pub floor: String,
+}
+
+/// Describe a position from one player's point of view
+///
+/// Built directly from `state`, so it always matches what the engine
+/// considers legal. The pattern lines and floor are those of `player_id`.
+///
+/// # Panics
+///
+/// Panics if `player_id` is not 0 or 1.
+pub fn describe_position(state: &State, player_id: u8) -> PositionDescription {
+    let active = state.active_player_id;
+    let whose = if active == player_id { "your turn" } else { "opponent's turn" };
+    let turn = format!("Player {} to move ({}).", active + 1, whose);
+    
+    let factories = state.factories
+        .iter()
+        .enumerate()
+        .map(|(idx, factory)| format!("Factory {}: {}.", idx + 1, describe_tiles(factory)))
+        .collect();
+    
+    let mut center = format!("Center: {}", describe_tiles(&state.center.tiles));
+    if state.center.has_first_player_token {
+        center.push_str(", plus the first player token");
+    }
+    center.push('.');
+    
+    let player = &state.players[player_id as usize];
+    let pattern_lines = player.pattern_lines
+        .iter()
+        .enumerate()
+        .map(|(row, line)| match line.color {
+            Some(color) if line.count_filled > 0 => format!(
+                "Pattern line {}: {} of {} {}.",
+                row + 1, line.count_filled, line.capacity, color.label()
+            ),
+            _ => format!("Pattern line {}: empty, holds {}.", row + 1, line.capacity),
+        })
+        .collect();
+    
+    let mut floor_tiles: BTreeMap<_, u8> = BTreeMap::new();
+    for &color in &player.floor_line.tiles {
+        *floor_tiles.entry(color).or_insert(0) += 1;
+    }
+    let mut floor_parts = Vec::new();
+    if !floor_tiles.is_empty() {
+        floor_parts.push(describe_tile_colors(&floor_tiles));
+    }
+    if player.floor_line.has_first_player_token {
+        floor_parts.push("the first player token".to_string());
+    }
+    let floor = if floor_parts.is_empty() {
+        "Floor: empty.".to_string()
+    } else {
+        format!(
+            "Floor: {}, costing {} points.",
+            floor_parts.join(" and "),
+            calculate_floor_penalty(&player.floor_line).abs()
+        )
+    };
+    
+    PositionDescription {
+        active_player_id: active,
+        turn,
+        factory_count: state.factories.len(),
+        factories,
+        center,
+        pattern_lines,
+        floor,
+    }
+}
+
+/// Describe a group of tiles, or "empty" if there are none
+fn describe_tiles(tiles: &TileMultiset) -> String {
+    let by_color: BTreeMap<_, u8> = tiles
+        .iter()
+        .filter(|(_, &count)| count > 0)
+        .map(|(&color, &count)| (color, count))
+        .collect();
+    if by_color.is_empty() {
+        "empty".to_string()
+    } else {
+        describe_tile_colors(&by_color)
+    }
+}
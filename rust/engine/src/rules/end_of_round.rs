@@ -1,19 +1,60 @@
-use crate::model::State;
+use serde::{Deserialize, Serialize};
+use crate::model::{PlayerBoard, State, TileColor, TileMultisetExt};
 use crate::rules::error::ValidationError;
-use crate::rules::resolution::resolve_pattern_lines;
-use crate::rules::scoring::apply_floor_penalties;
-use crate::rules::refill::refill_factories;
+use crate::rules::trace::{trace_tile_move, TileLocation};
+use crate::rules::resolution::resolve_pattern_lines_with;
+use crate::rules::scoring::{apply_end_game_bonuses, calculate_end_game_bonus, calculate_floor_penalty};
+use crate::rules::refill::refill_factories_with_rng;
+use rand::Rng;
+use crate::rules::invariants::count_tiles;
 
 /// Check if game has ended (any player has complete horizontal row)
 pub fn check_game_end(state: &State) -> bool {
-    for player in &state.players {
-        for row in &player.wall {
-            if row.iter().all(|&filled| filled) {
-                return true;
-            }
+    state.players.iter().any(has_complete_row)
+}
+
+/// Whether this player's wall has a complete horizontal row
+pub fn has_complete_row(player: &PlayerBoard) -> bool {
+    player.wall.iter().any(|row| row.iter().all(|&filled| filled))
+}
+
+/// Discard all floor tiles to the lid without scoring.
+///
+/// Moves every floor tile of both players to the lid and returns the
+/// first-player token to the center if a player was holding it. Scores,
+/// pattern lines and walls are left alone. Intended for sandbox tooling that
+/// rewinds a round; use `resolve_end_of_round` for normal play.
+///
+/// # Example
+///
+/// ```
+/// use engine::{State, TileColor, clear_round_floors};
+///
+/// let mut state = State::new_test_state();
+/// state.center.has_first_player_token = false;
+/// state.players[1].floor_line.tiles.push(TileColor::Red);
+/// state.players[1].floor_line.has_first_player_token = true;
+///
+/// clear_round_floors(&mut state);
+///
+/// assert!(state.players[1].floor_line.tiles.is_empty());
+/// assert_eq!(state.lid.get(&TileColor::Red), Some(&1));
+/// assert!(state.center.has_first_player_token);
+/// ```
+pub fn clear_round_floors(state: &mut State) {
+    for (player_idx, player) in state.players.iter_mut().enumerate() {
+        // Discard floor tiles to lid
+        for tile_color in player.floor_line.tiles.drain(..) {
+            trace_tile_move(TileLocation::Floor { player: player_idx }, TileLocation::Lid, tile_color, 1);
+            state.lid.add(tile_color, 1);
+        }
+        
+        // Token goes back to the center, not the lid
+        if player.floor_line.has_first_player_token {
+            player.floor_line.has_first_player_token = false;
+            state.center.has_first_player_token = true;
         }
     }
-    false
 }
 
 /// Resolve end of round: score tiles, apply penalties, cleanup, check end, refill.
@@ -22,9 +63,12 @@ pub fn check_game_end(state: &State) -> bool {
 /// 1. Pattern line resolution with scoring (Sprint 03A + 03B)
 /// 2. Floor penalty application (Sprint 03B)
 /// 3. Floor cleanup and first player determination
-/// 4. Game end detection
+/// 4. Game end detection; a finished game gets end-of-game bonuses and is
+///    marked `is_terminal`
 /// 5. Factory refill for next round (if game continues)
 ///
+/// `end_of_round_steps` reports the same resolution one step at a time.
+///
 /// # Arguments
 ///
 /// * `state` - Reference to current game state
@@ -43,19 +87,110 @@ pub fn check_game_end(state: &State) -> bool {
 /// assert_eq!(new_state.round_number, state.round_number + 1);
 /// ```
 pub fn resolve_end_of_round(state: &State) -> Result<State, ValidationError> {
+    let mut rng = rand::thread_rng();
+    resolve_end_of_round_with_rng(state, &mut rng)
+}
+
+/// Resolve end of round, refilling factories from the given RNG
+///
+/// Same as `resolve_end_of_round`, but the refill draws from `rng`, so
+/// seeded callers (e.g. scenario generation) stay reproducible across rounds.
+pub fn resolve_end_of_round_with_rng<R: Rng>(
+    state: &State,
+    rng: &mut R,
+) -> Result<State, ValidationError> {
+    run_end_of_round(state, rng, |_, _| {})
+}
+
+/// One transition during end-of-round resolution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum EndOfRoundEvent {
+    /// A complete pattern line moved a tile to the wall (the rest to the lid)
+    PlaceTile { player: usize, row: usize, col: usize, color: TileColor, points: i32 },
+    /// Floor penalty taken from a player's score, after the clamp at zero
+    FloorPenalty { player: usize, penalty: i32 },
+    /// Floors discarded to the lid; the token is back in the center
+    ClearFloors { next_first_player: u8 },
+    /// The game ended and end-of-game bonuses were added
+    EndGameBonuses { bonuses: [i32; 2] },
+    /// Factories refilled for the next round
+    Refill { round_number: u8 },
+}
+
+/// An end-of-round transition and the position it leads to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct EndOfRoundStep {
+    pub event: EndOfRoundEvent,
+    /// Position after this step
+    pub state: State,
+}
+
+/// List end-of-round resolution as individual steps, for animation
+///
+/// Steps come in resolution order: each wall placement (players in seat
+/// order, rows top to bottom), each non-zero floor penalty, floor cleanup,
+/// then either end-of-game bonuses or the refill. The last step's state is
+/// what `resolve_end_of_round` would return for the same refill.
+///
+/// # Errors
+///
+/// Returns `ValidationError` if the state cannot be resolved.
+pub fn end_of_round_steps(state: &State) -> Result<Vec<EndOfRoundStep>, ValidationError> {
+    let mut rng = rand::thread_rng();
+    end_of_round_steps_with_rng(state, &mut rng)
+}
+
+/// List end-of-round steps, refilling factories from the given RNG
+pub fn end_of_round_steps_with_rng<R: Rng>(
+    state: &State,
+    rng: &mut R,
+) -> Result<Vec<EndOfRoundStep>, ValidationError> {
+    let mut steps = Vec::new();
+    run_end_of_round(state, rng, |event, state| {
+        steps.push(EndOfRoundStep { event, state: state.clone() });
+    })?;
+    Ok(steps)
+}
+
+/// Resolve end of round, reporting each step to `on_step` as it happens
+fn run_end_of_round<R: Rng>(
+    state: &State,
+    rng: &mut R,
+    mut on_step: impl FnMut(EndOfRoundEvent, &State),
+) -> Result<State, ValidationError> {
     let mut new_state = state.clone();
     
     // ========== Phase 1: Wall Tiling & Scoring ==========
     
     // Resolve pattern lines and score (Sprint 03A + 03B integrated)
-    resolve_pattern_lines(&mut new_state);
+    resolve_pattern_lines_with(&mut new_state, |player, row, placed, state| {
+        let event = EndOfRoundEvent::PlaceTile {
+            player,
+            row,
+            col: placed.col,
+            color: placed.color,
+            points: placed.points,
+        };
+        on_step(event, state);
+    })?;
     
     // Apply floor penalties (Sprint 03B)
-    apply_floor_penalties(&mut new_state);
+    for player in 0..2 {
+        let board = &mut new_state.players[player];
+        let before = board.score;
+        board.score = std::cmp::max(0, before + calculate_floor_penalty(&board.floor_line));
+        let penalty = board.score - before;
+        if penalty != 0 {
+            on_step(EndOfRoundEvent::FloorPenalty { player, penalty }, &new_state);
+        }
+    }
     
     // ========== Phase 2: Cleanup ==========
     
-    // Determine next first player (whoever has token)
+    // Determine next first player (whoever has token). A floor claim wins
+    // over a stale center claim; see check_first_player_token_unique
     let next_first_player = if new_state.players[0].floor_line.has_first_player_token {
         0
     } else if new_state.players[1].floor_line.has_first_player_token {
@@ -66,33 +201,42 @@ pub fn resolve_end_of_round(state: &State) -> Result<State, ValidationError> {
     };
     
     // Clear floor lines and discard tiles to lid
-    for player in &mut new_state.players {
-        // Discard floor tiles to lid
-        for tile_color in &player.floor_line.tiles {
-            *new_state.lid.entry(*tile_color).or_insert(0) += 1;
-        }
-        
-        // Clear floor line
-        player.floor_line.tiles.clear();
-        player.floor_line.has_first_player_token = false;
-    }
+    clear_round_floors(&mut new_state);
+    
+    // Resolution only moves tiles (lines to wall and lid, floors to lid), so
+    // the count before refill must match the round's; a lid miscount would
+    // otherwise surface only as a short refill
+    debug_assert_eq!(
+        count_tiles(&new_state),
+        count_tiles(state),
+        "Tile count changed during end-of-round resolution"
+    );
     
     // Move token to center for next round
     new_state.center.has_first_player_token = true;
     new_state.active_player_id = next_first_player;
+    on_step(EndOfRoundEvent::ClearFloors { next_first_player }, &new_state);
     
     // ========== Phase 3: Check Game End ==========
     
     if check_game_end(&new_state) {
-        // Game is over, do not refill factories
-        // Future: add end-of-game bonuses here
+        // Game is over: both players' final round is already scored above,
+        // so add bonuses and stop without refilling factories
+        let bonuses = [
+            calculate_end_game_bonus(&new_state.players[0].wall),
+            calculate_end_game_bonus(&new_state.players[1].wall),
+        ];
+        apply_end_game_bonuses(&mut new_state);
+        new_state.is_terminal = true;
+        on_step(EndOfRoundEvent::EndGameBonuses { bonuses }, &new_state);
         return Ok(new_state);
     }
     
     // ========== Phase 4: Refill for Next Round ==========
     
     new_state.round_number += 1;
-    refill_factories(&mut new_state);
+    refill_factories_with_rng(&mut new_state, rng);
+    on_step(EndOfRoundEvent::Refill { round_number: new_state.round_number }, &new_state);
     
     Ok(new_state)
 }
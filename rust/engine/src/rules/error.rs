@@ -24,6 +24,24 @@ impl ValidationError {
         }
     }
     
+    /// The state's `active_player_id` does not name a seat
+    pub fn invalid_active_player(active_player_id: u8) -> Self {
+        Self {
+            code: "INVALID_STATE".to_string(),
+            message: format!("Active player ID {} is out of range", active_player_id),
+            context: Some(json!({"active_player_id": active_player_id})),
+        }
+    }
+    
+    /// The game has ended, so no action can be applied
+    pub fn game_over() -> Self {
+        Self {
+            code: "GAME_OVER".to_string(),
+            message: "The game is over; no actions are legal".to_string(),
+            context: None,
+        }
+    }
+    
     /// Factory index is out of bounds
     pub fn invalid_source(factory_idx: usize) -> Self {
         Self {
@@ -88,6 +106,18 @@ impl ValidationError {
         }
     }
     
+    /// A complete pattern line targets a wall cell that is already filled
+    pub fn wall_cell_occupied(player_id: usize, row: usize, col: usize) -> Self {
+        Self {
+            code: "WALL_CELL_OCCUPIED".to_string(),
+            message: format!(
+                "Player {} wall cell ({}, {}) is already occupied",
+                player_id, row, col
+            ),
+            context: Some(json!({"player_id": player_id, "row": row, "col": col})),
+        }
+    }
+    
     /// Internal invariant was violated (programming error)
     pub fn invariant_violation(message: String) -> Self {
         Self {
@@ -7,14 +7,34 @@ use crate::rules::{
     PolicyMix,
     ActionFeatures,
     FeedbackBullet,
+    FeedbackThresholds,
     Grade,
     count_pattern_lines_completed,
-    calculate_floor_penalty_for_player,
     count_tiles_in_action,
+    floor_tiles_by_color,
     generate_feedback_bullets,
+    summarize_best_move,
     compute_grade,
+    GRADE_THRESHOLDS,
+    check_game_end,
+    has_complete_row,
+    is_clean_completion,
+    sample_hidden_bag,
+    create_rng_from_seed,
+    explain_placement,
+    PlacementLegality,
+    fingerprint,
+    DraftPolicy,
+    GreedyPolicy,
+    calculate_floor_penalty,
+    place_on_floor,
+    place_first_player_token,
+    preview_placement,
 };
+use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::Instant;
@@ -26,12 +46,20 @@ pub enum EvaluatorError {
     NoLegalActions,
     /// Invalid player ID
     InvalidPlayer(u8),
+    /// The state's `active_player_id` does not name a seat
+    InvalidActivePlayer(u8),
     /// Rollout simulation failed
     RolloutFailure(String),
     /// Action application failed
     ActionFailed(String),
     /// Invalid parameters
     InvalidParams(String),
+    /// Game has already ended (a wall row is complete)
+    GameOver,
+    /// The user's action breaks a rule; carries the reason and the best move
+    IllegalUserMove(Box<IllegalUserMove>),
+    /// A plan step is illegal in the position it is played from
+    IllegalPlanStep { step: usize, legality: PlacementLegality },
 }
 
 impl std::fmt::Display for EvaluatorError {
@@ -39,15 +67,38 @@ impl std::fmt::Display for EvaluatorError {
         match self {
             EvaluatorError::NoLegalActions => write!(f, "No legal actions available"),
             EvaluatorError::InvalidPlayer(id) => write!(f, "Invalid player ID: {}", id),
+            EvaluatorError::InvalidActivePlayer(id) => write!(f, "Active player ID {} is out of range", id),
             EvaluatorError::RolloutFailure(msg) => write!(f, "Rollout failed: {}", msg),
             EvaluatorError::ActionFailed(msg) => write!(f, "Action failed: {}", msg),
             EvaluatorError::InvalidParams(msg) => write!(f, "Invalid parameters: {}", msg),
+            EvaluatorError::GameOver => write!(f, "Game is over, no moves to evaluate"),
+            EvaluatorError::IllegalUserMove(illegal) => write!(f, "Illegal move: {}", illegal.explanation),
+            EvaluatorError::IllegalPlanStep { step, legality } => write!(
+                f, "Plan step {} is illegal: {}", step + 1, legality
+            ),
         }
     }
 }
 
 impl std::error::Error for EvaluatorError {}
 
+/// Structured response to grading an illegal user action
+///
+/// Lets the UI explain the mistake and point at a legal alternative instead
+/// of showing a bare error.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct IllegalUserMove {
+    /// The action the user attempted
+    pub action: DraftAction,
+    /// Which rule the action breaks
+    pub legality: PlacementLegality,
+    /// Human-readable reason the move is illegal
+    pub explanation: String,
+    /// Best legal move from the evaluation
+    pub best_action: DraftAction,
+}
+
 /// Configuration for rollout policies
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -70,6 +121,9 @@ impl Default for RolloutPolicyConfig {
 #[serde(rename_all = "snake_case")]
 pub struct EvaluatorParams {
     /// Time budget in milliseconds
+    ///
+    /// 0 means no time limit: every candidate runs its rollouts, and
+    /// `rollouts_per_action` (at least 1) caps the work.
     #[serde(default = "default_time_budget")]
     pub time_budget_ms: u64,
     
@@ -78,6 +132,10 @@ pub struct EvaluatorParams {
     pub rollouts_per_action: usize,
     
     /// Seed for deterministic evaluation
+    ///
+    /// `SEED_FROM_STATE` (or the string `"from_state"` in JSON) derives the
+    /// seed from the position instead; see `SeedSource`.
+    #[serde(deserialize_with = "deserialize_evaluator_seed")]
     pub evaluator_seed: u64,
     
     /// Number of actions to shortlist (0 = no shortlisting)
@@ -87,11 +145,266 @@ pub struct EvaluatorParams {
     /// Policies for rollout simulation
     #[serde(default)]
     pub rollout_config: RolloutPolicyConfig,
+    
+    /// Grade on delta EV divided by the spread (stddev) of candidate EVs
+    #[serde(default)]
+    pub normalize_grades: bool,
+    
+    /// Number of independent base seeds to average EVs over (1 = single seed)
+    #[serde(default = "default_seed_replicas")]
+    pub seed_replicas: usize,
+    
+    /// Evaluate every legal action in canonical order (analysis mode)
+    ///
+    /// Requires `shortlist_size` 0. Only the time budget limits how many
+    /// actions are reached; see `EvaluationMetadata::candidates_evaluated`.
+    #[serde(default)]
+    pub exhaustive: bool,
+    
+    /// Penalty per unit of rollout utility stddev when ranking candidates
+    ///
+    /// Candidates are ranked by `mean - risk_aversion * stddev`, so positive
+    /// values prefer steadier moves. 0 ranks by mean alone.
+    #[serde(default)]
+    pub risk_aversion: f64,
+    
+    /// Weight (0-1) of the move-ordering heuristic in each candidate's ranking
+    ///
+    /// Candidates are ranked by `(1 - w) * risk-adjusted EV + w * prior`,
+    /// where the prior is the shortlist heuristic scaled to points. Steadies
+    /// rankings on small rollout budgets; 0 ranks on rollouts alone.
+    #[serde(default)]
+    pub heuristic_prior_weight: f64,
+    
+    /// Action to evaluate even if shortlisting or the time budget would skip it
+    ///
+    /// Set to the user's move before grading so `grade_user_action` reuses its
+    /// candidate EV, from the same seeds as the best move. Must be legal.
+    #[serde(default)]
+    pub must_include: Option<DraftAction>,
+    
+    /// Number of candidates, by shortlist heuristic, that get the full budget
+    ///
+    /// The top `top_tier_size` candidates run `rollouts_per_action` rollouts
+    /// and the rest run `tail_rollouts`, spending less on long shots. 0 gives
+    /// every candidate the full budget.
+    #[serde(default)]
+    pub top_tier_size: usize,
+    
+    /// Rollouts per candidate outside the top tier (needs `top_tier_size` > 0)
+    #[serde(default = "default_tail_rollouts")]
+    pub tail_rollouts: usize,
+    
+    /// What each rollout is worth when averaging into an EV
+    #[serde(default)]
+    pub utility_mode: UtilityMode,
+    
+    /// Points subtracted per tile of a cheap token grab left to the opponent
+    ///
+    /// A move that leaves the first-player token in the center, next to
+    /// tiles the opponent can use, hands them next round's first pick for
+    /// free. Each candidate's EV is lowered by this weight times
+    /// `token_tempo_exposure`. 0 leaves EVs to the rollouts alone.
+    #[serde(default)]
+    pub token_tempo_weight: f64,
+    
+    /// Cutoffs for which feature differences become feedback bullets
+    #[serde(default)]
+    pub feedback_thresholds: FeedbackThresholds,
+    
+    /// Return the position after the best action in `best_action_state`
+    #[serde(default)]
+    pub include_result_state: bool,
 }
 
 fn default_time_budget() -> u64 { 250 }
 fn default_rollouts_per_action() -> usize { 10 }
 fn default_shortlist_size() -> usize { 20 }
+fn default_seed_replicas() -> usize { 1 }
+fn default_tail_rollouts() -> usize { 3 }
+
+/// How a rollout's outcome is scored for the player being evaluated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UtilityMode {
+    /// Score margin in points
+    #[default]
+    ScoreDifference,
+    /// 1 for a win, 0.5 for a tie, 0 for a loss, so EVs are win chances
+    ///
+    /// EVs, deltas, and `token_tempo_weight` are then in win probability,
+    /// not points, so grade thresholds suit it only with `normalize_grades`.
+    WinProbability,
+}
+
+/// Value of one rollout under `mode`, given the player's score margin
+pub(crate) fn rollout_value(margin: i32, tied: bool, mode: UtilityMode) -> f64 {
+    match mode {
+        UtilityMode::ScoreDifference => margin as f64,
+        UtilityMode::WinProbability if tied => 0.5,
+        UtilityMode::WinProbability if margin > 0 => 1.0,
+        UtilityMode::WinProbability => 0.0,
+    }
+}
+
+/// Sentinel `evaluator_seed` meaning "derive the seed from the position"
+pub const SEED_FROM_STATE: u64 = u64::MAX;
+
+/// Where an evaluation's rollout seed comes from
+///
+/// `FromState` hashes the position with `fingerprint`, so the same position
+/// evaluates identically whoever asks, without callers agreeing on a seed.
+/// It is stored in `EvaluatorParams::evaluator_seed` as `SEED_FROM_STATE`,
+/// which is therefore not usable as an explicit seed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedSource {
+    /// Use this seed as given
+    Explicit(u64),
+    /// Derive the seed from the position's fingerprint
+    FromState,
+}
+
+impl SeedSource {
+    /// The seed to evaluate `state` with
+    pub fn resolve(self, state: &State) -> u64 {
+        match self {
+            SeedSource::Explicit(seed) => seed,
+            SeedSource::FromState => fingerprint(state),
+        }
+    }
+}
+
+impl From<SeedSource> for u64 {
+    fn from(source: SeedSource) -> u64 {
+        match source {
+            SeedSource::Explicit(seed) => seed,
+            SeedSource::FromState => SEED_FROM_STATE,
+        }
+    }
+}
+
+/// Accept either a number or `"from_state"` for `evaluator_seed`
+///
+/// JavaScript numbers cannot hold `u64::MAX` exactly, so the sentinel gets a
+/// string spelling.
+fn deserialize_evaluator_seed<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum SeedJson {
+        Number(u64),
+        Named(String),
+    }
+    
+    match SeedJson::deserialize(deserializer)? {
+        SeedJson::Number(seed) => Ok(seed),
+        SeedJson::Named(name) if name == "from_state" => Ok(SEED_FROM_STATE),
+        SeedJson::Named(name) => Err(serde::de::Error::custom(format!(
+            "evaluator_seed must be a number or \"from_state\", got \"{}\"", name
+        ))),
+    }
+}
+
+/// Base seed for a replica of the evaluation
+///
+/// Replica 0 uses `seed` itself so a single replica matches the plain
+/// single-seed evaluation; later replicas are spread out by a large odd
+/// constant so their rollout seed ranges do not overlap in practice.
+fn replica_base_seed(seed: u64, replica: usize) -> u64 {
+    seed.wrapping_add((replica as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+/// (replica, rollout index) pairs to run for an action given `rollouts`
+fn replica_rollout_plan(params: &EvaluatorParams, rollouts: usize) -> Vec<(usize, usize)> {
+    (0..params.seed_replicas.max(1))
+        .flat_map(|replica| (0..rollouts).map(move |i| (replica, i)))
+        .collect()
+}
+
+/// Candidates in the top tier of a tiered budget, by shortlist heuristic
+///
+/// Returns every candidate when tiering is off (`top_tier_size` 0).
+fn top_tier(state: &State, candidates: &[DraftAction], top_tier_size: usize) -> Vec<DraftAction> {
+    if top_tier_size == 0 || candidates.len() <= top_tier_size {
+        return candidates.to_vec();
+    }
+    let mut scored: Vec<(&DraftAction, f64)> = candidates
+        .iter()
+        .map(|action| (action, score_action_heuristic(state, action)))
+        .collect();
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    scored.into_iter()
+        .take(top_tier_size)
+        .map(|(action, _)| action.clone())
+        .collect()
+}
+
+/// Starting state for one rollout
+///
+/// A redacted bag (`hidden_bag`) gets a fresh sample of plausible contents
+/// per rollout, so EVs average over the bags the player cannot rule out.
+fn rollout_start_state(state: &State, rollout_seed: u64) -> Cow<'_, State> {
+    if state.hidden_bag {
+        let mut rng = create_rng_from_seed(!rollout_seed);
+        Cow::Owned(sample_hidden_bag(state, &mut rng))
+    } else {
+        Cow::Borrowed(state)
+    }
+}
+
+impl EvaluatorParams {
+    /// How `evaluator_seed` should be interpreted
+    pub fn seed_source(&self) -> SeedSource {
+        if self.evaluator_seed == SEED_FROM_STATE {
+            SeedSource::FromState
+        } else {
+            SeedSource::Explicit(self.evaluator_seed)
+        }
+    }
+    
+    /// Preset that models a stronger opponent than the active player
+    ///
+    /// With the default all-greedy rollouts the opponent never looks at what
+    /// its moves score, so it rarely punishes a weak move and EVs come out
+    /// optimistic. This preset keeps the active player greedy but has the
+    /// opponent use one-ply lookahead on half its moves, giving more
+    /// conservative EVs at some extra rollout cost.
+    pub fn realistic() -> Self {
+        Self {
+            rollout_config: RolloutPolicyConfig {
+                active_player_policy: PolicyMix::AllGreedy,
+                opponent_policy: PolicyMix::GreedyLookahead { lookahead_ratio: 0.5 },
+            },
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for EvaluatorParams {
+    fn default() -> Self {
+        Self {
+            time_budget_ms: default_time_budget(),
+            rollouts_per_action: default_rollouts_per_action(),
+            evaluator_seed: 0,
+            shortlist_size: default_shortlist_size(),
+            rollout_config: RolloutPolicyConfig::default(),
+            normalize_grades: false,
+            seed_replicas: default_seed_replicas(),
+            exhaustive: false,
+            risk_aversion: 0.0,
+            heuristic_prior_weight: 0.0,
+            must_include: None,
+            top_tier_size: 0,
+            tail_rollouts: default_tail_rollouts(),
+            token_tempo_weight: 0.0,
+            utility_mode: UtilityMode::default(),
+            feedback_thresholds: FeedbackThresholds::default(),
+            include_result_state: false,
+        }
+    }
+}
 
 /// Candidate action with evaluation metrics
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -100,6 +413,24 @@ pub struct CandidateAction {
     pub action: DraftAction,
     pub ev: f64,
     pub rollouts: usize,
+    /// Value candidates are ranked on: EV penalized for rollout variance,
+    /// blended with the heuristic prior (equals `ev` with neither)
+    #[serde(default)]
+    pub risk_adjusted_ev: f64,
+    /// Population variance of the rollout utilities behind `ev`
+    #[serde(default)]
+    pub ev_variance: f64,
+    /// Estimated chance this candidate has the highest true EV
+    ///
+    /// See `compute_optimality_probabilities`.
+    #[serde(default)]
+    pub optimality_probability: f64,
+    /// Lowest rollout utility behind `ev` (the worst case seen)
+    #[serde(default)]
+    pub worst_utility: i32,
+    /// Rollout statistics for this action
+    #[serde(default)]
+    pub features: ActionFeatures,
 }
 
 /// Metadata about the evaluation process
@@ -112,6 +443,9 @@ pub struct EvaluationMetadata {
     pub total_legal_actions: usize,
     pub seed: u64,
     pub completed_within_budget: bool,
+    /// The best action came from an opening book, not from rollouts
+    #[serde(default)]
+    pub from_book: bool,
 }
 
 /// Result of best-move evaluation
@@ -122,6 +456,12 @@ pub struct EvaluationResult {
     pub best_action_ev: f64,
     pub user_action_ev: Option<f64>,
     pub delta_ev: Option<f64>,
+    /// Runner-up candidate, ranked the same way as `best_action`
+    #[serde(default)]
+    pub second_best_action: Option<DraftAction>,
+    /// Raw EV of the runner-up candidate
+    #[serde(default)]
+    pub second_best_ev: Option<f64>,
     pub metadata: EvaluationMetadata,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub candidates: Option<Vec<CandidateAction>>,
@@ -134,15 +474,172 @@ pub struct EvaluationResult {
     pub feedback: Option<Vec<FeedbackBullet>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub grade: Option<Grade>,
+    /// Standalone explanation of the best move
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub best_move_summary: Option<String>,
+    /// Position after the best action (only with `include_result_state`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub best_action_state: Option<State>,
+}
+
+/// Calculate mean of rollout values
+fn mean_value(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().sum::<f64>() / values.len() as f64
 }
 
-/// Calculate mean of integer values
-fn mean(values: &[i32]) -> f64 {
+/// Calculate population standard deviation of EV values
+fn std_dev(values: &[f64]) -> f64 {
     if values.is_empty() {
         return 0.0;
     }
-    let sum: i32 = values.iter().sum();
-    sum as f64 / values.len() as f64
+    let n = values.len() as f64;
+    let avg = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - avg).powi(2)).sum::<f64>() / n;
+    variance.sqrt()
+}
+
+/// Mean rollout value penalized by `risk_aversion` times its stddev
+pub(crate) fn risk_adjusted_value(values: &[f64], risk_aversion: f64) -> f64 {
+    let ev = mean_value(values);
+    if risk_aversion == 0.0 {
+        return ev;
+    }
+    ev - risk_aversion * std_dev(values)
+}
+
+/// Heuristic points per point of score margin in the rollout prior
+///
+/// `score_action_heuristic` gives ~10 per tile taken, so this puts the prior
+/// on roughly the same scale as rollout utilities.
+const HEURISTIC_PRIOR_SCALE: f64 = 10.0;
+
+/// Blend a candidate's rollout value with its heuristic score
+pub(crate) fn blend_heuristic_prior(rollout_ev: f64, heuristic: f64, weight: f64) -> f64 {
+    if weight == 0.0 {
+        return rollout_ev;
+    }
+    (1.0 - weight) * rollout_ev + weight * heuristic / HEURISTIC_PRIOR_SCALE
+}
+
+/// How cheaply the opponent can take the first-player token after a move
+///
+/// 0 when the token is gone from the center. Otherwise the value of the
+/// opponent's best center pick: the most tiles one color places in a
+/// pattern line, less any overflow. A center holding only the token, or
+/// only tiles the opponent cannot use, scores 0.
+pub fn token_tempo_exposure(state_after: &State, player_id: u8) -> f64 {
+    if !state_after.center.has_first_player_token {
+        return 0.0;
+    }
+    let opponent = &state_after.players[1 - player_id as usize];
+    state_after.center.tiles
+        .iter()
+        .flat_map(|(&color, &count)| {
+            (0..opponent.pattern_lines.len())
+                .filter_map(move |row| preview_placement(opponent, row, color, count))
+        })
+        .map(|preview| preview.placed as i32 - preview.overflow as i32)
+        .max()
+        .unwrap_or(0)
+        .max(0) as f64
+}
+
+/// Position after `action`, if the caller asked for it
+fn result_state(
+    state: &State,
+    action: &DraftAction,
+    params: &EvaluatorParams,
+) -> Result<Option<State>, EvaluatorError> {
+    if !params.include_result_state {
+        return Ok(None);
+    }
+    apply_action(state, action)
+        .map(Some)
+        .map_err(|e| EvaluatorError::ActionFailed(e.message))
+}
+
+/// Simulated draws used to estimate optimality probabilities
+const OPTIMALITY_SAMPLES: usize = 2000;
+
+/// Estimate each candidate's probability of having the highest true EV
+///
+/// Normal approximation: a candidate's true EV is taken to be distributed
+/// around its rollout mean with standard error `sqrt(ev_variance /
+/// rollouts)`. Draws one EV per candidate `OPTIMALITY_SAMPLES` times and
+/// counts how often each comes out on top, splitting ties evenly. The draws
+/// use a fixed seed, so the same candidates always give the same result.
+///
+/// Returns probabilities in candidate order, summing to 1 (or empty).
+pub fn compute_optimality_probabilities(candidates: &[CandidateAction]) -> Vec<f64> {
+    if candidates.len() <= 1 {
+        return vec![1.0; candidates.len()];
+    }
+    
+    let std_errors: Vec<f64> = candidates
+        .iter()
+        .map(|c| (c.ev_variance / c.rollouts.max(1) as f64).sqrt())
+        .collect();
+    let mut rng = create_rng_from_seed(0);
+    let mut wins = vec![0.0; candidates.len()];
+    let mut draws = vec![0.0; candidates.len()];
+    
+    for _ in 0..OPTIMALITY_SAMPLES {
+        for (i, draw) in draws.iter_mut().enumerate() {
+            *draw = candidates[i].ev + std_errors[i] * standard_normal(&mut rng);
+        }
+        let top = draws.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let leaders = draws.iter().filter(|&&d| d == top).count() as f64;
+        for (win, &draw) in wins.iter_mut().zip(&draws) {
+            if draw == top {
+                *win += 1.0 / leaders;
+            }
+        }
+    }
+    
+    wins.iter().map(|w| w / OPTIMALITY_SAMPLES as f64).collect()
+}
+
+/// Standard normal sample (Box-Muller)
+fn standard_normal<R: Rng>(rng: &mut R) -> f64 {
+    let u1: f64 = 1.0 - rng.gen::<f64>(); // (0, 1], keeps ln finite
+    let u2: f64 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// Highest-ranked candidate other than the best action
+///
+/// Ties keep evaluation order, matching how the best action is chosen.
+fn second_best_candidate<'a>(
+    candidates: &'a [CandidateAction],
+    best_action: &DraftAction,
+) -> Option<&'a CandidateAction> {
+    candidates
+        .iter()
+        .filter(|c| c.action != *best_action)
+        .fold(None, |runner_up: Option<&CandidateAction>, c| match runner_up {
+            Some(r) if r.risk_adjusted_ev >= c.risk_adjusted_ev => Some(r),
+            _ => Some(c),
+        })
+}
+
+/// Scale delta EV by the spread of candidate EVs
+///
+/// Early-game positions tend to have small EV differences between moves and
+/// late-game positions large ones, so a fixed delta means different things at
+/// different stages. Dividing by the stddev of candidate EVs puts deltas on a
+/// common scale. Returns the raw delta when there is no spread to divide by.
+pub(crate) fn normalize_delta_ev(delta_ev: f64, candidates: &[CandidateAction]) -> f64 {
+    let evs: Vec<f64> = candidates.iter().map(|c| c.ev).collect();
+    let spread = std_dev(&evs);
+    
+    if spread > f64::EPSILON {
+        delta_ev / spread
+    } else {
+        delta_ev
+    }
 }
 
 /// Count total tiles of a color in a source
@@ -230,6 +727,11 @@ fn score_action_heuristic(state: &State, action: &DraftAction) -> f64 {
 }
 
 /// Shortlist top N actions using heuristic scoring
+///
+/// Clean completions (moves that fill a pattern line with nothing going to
+/// the floor) are always kept, even if the heuristic ranks them low; they
+/// take slots from the bottom of the heuristic ranking. If there are more
+/// clean completions than `shortlist_size`, all of them are returned.
 pub fn shortlist_actions(
     state: &State,
     legal_actions: &[DraftAction],
@@ -244,13 +746,81 @@ pub fn shortlist_actions(
     // Sort by score descending
     scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
     
-    // Take top N
+    // Safeguard: never prune an obviously strong move
+    let guaranteed = scored.iter()
+        .filter(|(action, _)| is_clean_completion(state, state.active_player_id, action))
+        .count();
+    let mut heuristic_slots = shortlist_size.saturating_sub(guaranteed);
+    
+    // Take top N, keeping heuristic order
     scored.into_iter()
-        .take(shortlist_size)
+        .filter(|(action, _)| {
+            if is_clean_completion(state, state.active_player_id, action) {
+                true
+            } else if heuristic_slots > 0 {
+                heuristic_slots -= 1;
+                true
+            } else {
+                false
+            }
+        })
         .map(|(action, _)| action)
         .collect()
 }
 
+/// Rank the ways to dump tiles to the floor, best first (no simulation)
+///
+/// For positions where nothing good is left and the question is which color
+/// hurts least on the floor. Each floor action scores, in points:
+///
+/// - the floor penalty it adds (including the first player token)
+/// - minus one per tile the player could still have put in a pattern line
+/// - plus one per tile the opponent needed for a line in progress (denial)
+///
+/// Ties are broken by canonical action order. Returns an empty list if
+/// `player_id` is not 0 or 1 or has no legal floor action.
+pub fn evaluate_dump_options(state: &State, player_id: u8) -> Vec<(DraftAction, i32)> {
+    if player_id > 1 {
+        return Vec::new();
+    }
+    let player = &state.players[player_id as usize];
+    let opponent = &state.players[1 - player_id as usize];
+    let penalty_before = calculate_floor_penalty(&player.floor_line);
+    
+    let mut dumps: Vec<(DraftAction, i32)> = list_legal_actions(state, player_id)
+        .into_iter()
+        .filter(|action| action.destination == Destination::Floor)
+        .map(|action| {
+            let count = count_tiles_in_action(state, &action);
+            
+            let mut floor = player.floor_line.clone();
+            let mut scratch_lid = HashMap::new();
+            if action.source == ActionSource::Center && state.center.has_first_player_token {
+                place_first_player_token(&mut floor, &mut scratch_lid);
+            }
+            place_on_floor(&mut floor, &mut scratch_lid, action.color, count);
+            let penalty = calculate_floor_penalty(&floor) - penalty_before;
+            
+            let wasted = if count_placeable_rows(state, player_id, action.color) > 0 {
+                count as i32
+            } else {
+                0
+            };
+            
+            let opponent_need: u8 = opponent.pattern_lines.iter()
+                .filter(|line| line.color == Some(action.color))
+                .map(|line| line.capacity - line.count_filled)
+                .sum();
+            let denied = count.min(opponent_need) as i32;
+            
+            (action, penalty - wasted + denied)
+        })
+        .collect();
+    
+    dumps.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    dumps
+}
+
 /// Evaluate best move using rollout-based Monte Carlo sampling
 ///
 /// Takes a game state and evaluates all legal actions by running Monte Carlo
@@ -279,6 +849,7 @@ pub fn shortlist_actions(
 ///     evaluator_seed: 12345,
 ///     shortlist_size: 20,
 ///     rollout_config: RolloutPolicyConfig::default(),
+///     ..EvaluatorParams::default()
 /// };
 ///
 /// let result = evaluate_best_move(&state, 0, &params).unwrap();
@@ -289,10 +860,78 @@ pub fn evaluate_best_move(
     player_id: u8,
     params: &EvaluatorParams,
 ) -> Result<EvaluationResult, EvaluatorError> {
+    evaluate_with_waiting_seat(state, player_id, params).map(|(result, _)| result)
+}
+
+/// The waiting seat's view of one mover candidate, from the same rollouts
+struct WaitingSeatCandidate {
+    ev: f64,
+    risk_adjusted_ev: f64,
+    ev_variance: f64,
+    worst_utility: i32,
+    features: ActionFeatures,
+}
+
+/// Reject evaluator params that would make the result meaningless
+///
+/// Shared by every entry point that runs rollouts.
+fn validate_params(params: &EvaluatorParams) -> Result<(), EvaluatorError> {
+    // Zero rollouts would give every action EV 0, making the "best" move
+    // arbitrary. The rollout cap is also what bounds an unlimited
+    // (`time_budget_ms` 0) evaluation.
+    if params.rollouts_per_action == 0 {
+        let reason = if params.time_budget_ms == 0 {
+            "rollouts_per_action must be at least 1 when time_budget_ms is 0 (no limit)"
+        } else {
+            "rollouts_per_action must be at least 1"
+        };
+        return Err(EvaluatorError::InvalidParams(reason.to_string()));
+    }
+    if !(0.0..=1.0).contains(&params.heuristic_prior_weight) {
+        return Err(EvaluatorError::InvalidParams(
+            "heuristic_prior_weight must be between 0 and 1".to_string()
+        ));
+    }
+    if params.token_tempo_weight < 0.0 {
+        return Err(EvaluatorError::InvalidParams(
+            "token_tempo_weight must not be negative".to_string()
+        ));
+    }
+    if params.top_tier_size > 0
+        && !(1..=params.rollouts_per_action).contains(&params.tail_rollouts)
+    {
+        return Err(EvaluatorError::InvalidParams(
+            "tail_rollouts must be between 1 and rollouts_per_action".to_string()
+        ));
+    }
+    Ok(())
+}
+
+/// Evaluate for the mover, also collecting the waiting seat's utilities
+///
+/// Each rollout's final scores give both seats' utilities, so the waiting
+/// seat's view costs no extra simulation. Returns one `WaitingSeatCandidate`
+/// per entry in the mover's candidate list, in the same order.
+fn evaluate_with_waiting_seat(
+    state: &State,
+    player_id: u8,
+    params: &EvaluatorParams,
+) -> Result<(EvaluationResult, Vec<WaitingSeatCandidate>), EvaluatorError> {
     // 1. Validate inputs
     if player_id > 1 {
         return Err(EvaluatorError::InvalidPlayer(player_id));
     }
+    // Shortlisting scores moves for the seat to move
+    if state.active_player_id > 1 {
+        return Err(EvaluatorError::InvalidActivePlayer(state.active_player_id));
+    }
+    
+    validate_params(params)?;
+    
+    // A completed wall row ends the game; leftover factory tiles are not real moves
+    if check_game_end(state) {
+        return Err(EvaluatorError::GameOver);
+    }
     
     // 2. Get all legal actions
     let legal_actions = list_legal_actions(state, player_id);
@@ -301,14 +940,39 @@ pub fn evaluate_best_move(
     }
     
     let total_legal_actions = legal_actions.len();
+    let seed = params.seed_source().resolve(state);
+    if let Some(forced) = &params.must_include {
+        if !legal_actions.contains(forced) {
+            return Err(EvaluatorError::InvalidParams(
+                "must_include is not a legal action".to_string()
+            ));
+        }
+    }
     
-    // 3. Shortlist candidates
-    let candidates = if params.shortlist_size > 0 && legal_actions.len() > params.shortlist_size {
+    // 3. Shortlist candidates (exhaustive mode takes all, in canonical order)
+    let mut candidates = if params.exhaustive {
+        if params.shortlist_size > 0 {
+            return Err(EvaluatorError::InvalidParams(
+                "exhaustive evaluation requires shortlist_size 0".to_string()
+            ));
+        }
+        let mut all_actions = legal_actions;
+        all_actions.sort();
+        all_actions
+    } else if params.shortlist_size > 0 && legal_actions.len() > params.shortlist_size {
         shortlist_actions(state, &legal_actions, params.shortlist_size)
     } else {
         legal_actions
     };
     
+    // A forced action the shortlist dropped goes last, so the others keep
+    // their rollout seeds
+    if let Some(forced) = &params.must_include {
+        if !candidates.contains(forced) {
+            candidates.push(forced.clone());
+        }
+    }
+    
     // 4. Initialize tracking
     #[cfg(not(target_arch = "wasm32"))]
     let start_time = Instant::now();
@@ -316,19 +980,30 @@ pub fn evaluate_best_move(
     let total_candidates = candidates.len();
     let mut best_action: Option<DraftAction> = None;
     let mut best_ev = f64::NEG_INFINITY;
+    let mut best_adjusted_ev = f64::NEG_INFINITY;
     let mut best_features = ActionFeatures::default();
     let mut candidate_results = Vec::new();
+    let mut waiting_seat = Vec::new();
     let mut rollouts_run = 0;
     let mut candidates_evaluated = 0;
+    let top_tier = top_tier(state, &candidates, params.top_tier_size);
+    let full_plan = replica_rollout_plan(params, params.rollouts_per_action);
+    let tail_plan = replica_rollout_plan(params, params.tail_rollouts);
     
     // 5. Evaluate each candidate
-    for action in candidates {
+    for (candidate_idx, action) in candidates.into_iter().enumerate() {
         // Check time budget (skip in WASM where timing is not available)
         #[cfg(not(target_arch = "wasm32"))]
         {
             let elapsed_ms = start_time.elapsed().as_millis() as u64;
-            if elapsed_ms >= params.time_budget_ms && candidates_evaluated > 0 {
-                break;  // Time expired, return best so far
+            let forced = params.must_include.as_ref() == Some(&action);
+            let out_of_time = params.time_budget_ms > 0 && elapsed_ms >= params.time_budget_ms;
+            if out_of_time && candidates_evaluated > 0 && !forced {
+                // Time expired, return best so far (still reaching a forced action)
+                if params.must_include.is_some() {
+                    continue;
+                }
+                break;
             }
         }
         
@@ -338,12 +1013,16 @@ pub fn evaluate_best_move(
         
         // Run rollouts and track features
         let mut utilities = Vec::new();
-        let mut features = ActionFeatures::default();
-        let player_before = &state_after_action.players[player_id as usize];
+        let mut values = Vec::new();
+        let mut waiting_values = Vec::new();
+        let mut seat_features = [ActionFeatures::default(), ActionFeatures::default()];
         
-        for _i in 0..params.rollouts_per_action {
-            // Unique seed per rollout
-            let rollout_seed = params.evaluator_seed.wrapping_add(rollouts_run as u64);
+        let rollout_plan = if top_tier.contains(&action) { &full_plan } else { &tail_plan };
+        for &(replica, i) in rollout_plan {
+            // Unique seed per rollout within each replica's seed range
+            let rollout_index = candidate_idx * params.rollouts_per_action + i;
+            let rollout_seed = replica_base_seed(seed, replica)
+                .wrapping_add(rollout_index as u64);
             
             let rollout_config = RolloutConfig {
                 active_player_policy: params.rollout_config.active_player_policy,
@@ -353,7 +1032,8 @@ pub fn evaluate_best_move(
             };
             
             // Simulate
-            let result = simulate_rollout(&state_after_action, &rollout_config)
+            let rollout_start = rollout_start_state(&state_after_action, rollout_seed);
+            let result = simulate_rollout(&rollout_start, &rollout_config)
                 .map_err(|e| EvaluatorError::RolloutFailure(e.to_string()))?;
             
             rollouts_run += 1;
@@ -366,45 +1046,87 @@ pub fn evaluate_best_move(
             };
             
             utilities.push(utility);
+            values.push(rollout_value(utility, result.tied, params.utility_mode));
+            waiting_values.push(rollout_value(-utility, result.tied, params.utility_mode));
             
-            // Track features
-            let player_after = &result.final_state.players[player_id as usize];
-            
-            let floor_penalty = calculate_floor_penalty_for_player(player_after);
-            features.expected_floor_penalty += floor_penalty as f64;
-            
-            let completions = count_pattern_lines_completed(player_before, player_after);
-            features.expected_completions += completions as f64;
-            
-            let tiles_to_floor = player_after.floor_line.tiles.len();
-            features.expected_tiles_to_floor += tiles_to_floor as f64;
+            // Track features for both seats from the same simulated game
+            for (seat, seat_feature) in seat_features.iter_mut().enumerate() {
+                let player_before = &state_after_action.players[seat];
+                let player_after = &result.final_state.players[seat];
+                
+                // Resolution clears floors, so read them as drafting ended
+                let drafted_floor = &result.drafted_floors[seat];
+                let floor_penalty = calculate_floor_penalty(drafted_floor);
+                seat_feature.expected_floor_penalty += floor_penalty as f64;
+                
+                let completions = count_pattern_lines_completed(player_before, player_after);
+                seat_feature.expected_completions += completions as f64;
+                
+                let tiles_to_floor = drafted_floor.tiles.len();
+                seat_feature.expected_tiles_to_floor += tiles_to_floor as f64;
+                
+                // Only the seat's own completed row counts as its game end
+                if has_complete_row(player_after) {
+                    seat_feature.game_end_probability += 1.0;
+                }
+            }
         }
         
         // Average features across rollouts
         let rollout_count = utilities.len() as f64;
         if rollout_count > 0.0 {
-            features.expected_floor_penalty /= rollout_count;
-            features.expected_completions /= rollout_count;
-            features.expected_tiles_to_floor /= rollout_count;
+            for seat_feature in &mut seat_features {
+                seat_feature.expected_floor_penalty /= rollout_count;
+                seat_feature.expected_completions /= rollout_count;
+                seat_feature.expected_tiles_to_floor /= rollout_count;
+                seat_feature.game_end_probability /= rollout_count;
+            }
         }
+        let [seat_0_features, seat_1_features] = seat_features;
+        let (mut features, waiting_features) = if player_id == 0 {
+            (seat_0_features, seat_1_features)
+        } else {
+            (seat_1_features, seat_0_features)
+        };
         
         // Static features
         features.tiles_acquired = count_tiles_in_action(state, &action);
         features.takes_first_player_token = matches!(action.source, ActionSource::Center) 
             && state.center.has_first_player_token;
+        features.floor_tiles_by_color = floor_tiles_by_color(state, &action);
         
-        // Compute EV
-        let ev = mean(&utilities);
+        // Compute EV (ranked on the risk-adjusted value, blended with the prior)
+        let tempo_penalty = params.token_tempo_weight
+            * token_tempo_exposure(&state_after_action, player_id);
+        let ev = mean_value(&values) - tempo_penalty;
+        let adjusted_ev = blend_heuristic_prior(
+            risk_adjusted_value(&values, params.risk_aversion) - tempo_penalty,
+            score_action_heuristic(state, &action),
+            params.heuristic_prior_weight,
+        );
         
         // Track candidate
         candidate_results.push(CandidateAction {
             action: action.clone(),
             ev,
             rollouts: utilities.len(),
+            risk_adjusted_ev: adjusted_ev,
+            ev_variance: std_dev(&values).powi(2),
+            optimality_probability: 0.0,
+            worst_utility: utilities.iter().copied().min().unwrap_or(0),
+            features: features.clone(),
+        });
+        waiting_seat.push(WaitingSeatCandidate {
+            ev: mean_value(&waiting_values),
+            risk_adjusted_ev: risk_adjusted_value(&waiting_values, params.risk_aversion),
+            ev_variance: std_dev(&waiting_values).powi(2),
+            worst_utility: -utilities.iter().copied().max().unwrap_or(0),
+            features: waiting_features,
         });
         
         // Update best
-        if ev > best_ev {
+        if adjusted_ev > best_adjusted_ev {
+            best_adjusted_ev = adjusted_ev;
             best_ev = ev;
             best_action = Some(action.clone());
             best_features = features.clone();
@@ -423,79 +1145,359 @@ pub fn evaluate_best_move(
     let elapsed_ms = 0; // Timing not available in WASM
     
     let completed_within_budget = candidates_evaluated >= total_candidates;
+    let probabilities = compute_optimality_probabilities(&candidate_results);
+    for (candidate, probability) in candidate_results.iter_mut().zip(probabilities) {
+        candidate.optimality_probability = probability;
+    }
+    let second_best = second_best_candidate(&candidate_results, &best_action);
+    let best_move_summary = summarize_best_move(&best_features, &best_action, state);
+    let best_action_state = result_state(state, &best_action, params)?;
     
-    Ok(EvaluationResult {
+    Ok((EvaluationResult {
         best_action,
         best_action_ev: best_ev,
         user_action_ev: None,
         delta_ev: None,
+        second_best_action: second_best.map(|c| c.action.clone()),
+        second_best_ev: second_best.map(|c| c.ev),
         metadata: EvaluationMetadata {
             elapsed_ms,
             rollouts_run,
             candidates_evaluated,
             total_legal_actions,
-            seed: params.evaluator_seed,
+            seed,
             completed_within_budget,
+            from_book: false,
         },
         candidates: Some(candidate_results),
         best_features,
         user_features: None,
         feedback: None,
         grade: None,
-    })
+        best_move_summary: Some(best_move_summary),
+        best_action_state,
+    }, waiting_seat))
 }
 
-/// Grade user's action by comparing its EV to the best action
+/// Evaluate a position for both seats in one pass
 ///
-/// Evaluates the user's action using rollout sampling and compares it to
-/// the best action found by `evaluate_best_move`.
+/// The player to move is evaluated as in `evaluate_best_move`. The waiting
+/// player's result is read from the same rollouts: its candidates are the
+/// mover's candidates with EVs from the waiting seat's perspective (the
+/// negated score margin, or 1 - p under `WinProbability`), and its best
+/// action is the mover's best action, the move it should expect to face.
+/// Roughly half the cost of evaluating each seat separately.
 ///
-/// # Arguments
+/// Returns `(player 0 result, player 1 result)`.
 ///
-/// * `state` - Current game state
-/// * `player_id` - Player whose turn it is (0 or 1)
-/// * `user_action` - Action chosen by the user
-/// * `params` - Evaluation parameters
-/// * `best_result` - Result from `evaluate_best_move`
+/// # Errors
+///
+/// Returns `EvaluatorError` under the same conditions as `evaluate_best_move`
+/// for the active player.
+pub fn evaluate_both_players(
+    state: &State,
+    params: &EvaluatorParams,
+) -> Result<(EvaluationResult, EvaluationResult), EvaluatorError> {
+    let mover = state.active_player_id;
+    let (mover_result, waiting_seat) = evaluate_with_waiting_seat(state, mover, params)?;
+    let waiting_result = waiting_seat_result(&mover_result, waiting_seat);
+    
+    if mover == 0 {
+        Ok((mover_result, waiting_result))
+    } else {
+        Ok((waiting_result, mover_result))
+    }
+}
+
+/// Build the waiting seat's result from the mover's
+///
+/// EVs, variances and risk-adjusted values come from the waiting seat's own
+/// rollout values (the negated margin, or 1 - p under `WinProbability`).
+/// Optimality probabilities describe the mover's choice, not the waiting
+/// seat's, so they are left at 0. The best and second-best actions stay the
+/// mover's, as the moves the waiting seat should expect to face.
+fn waiting_seat_result(
+    mover_result: &EvaluationResult,
+    waiting_seat: Vec<WaitingSeatCandidate>,
+) -> EvaluationResult {
+    let mover_candidates = mover_result.candidates.as_deref().unwrap_or_default();
+    let candidates: Vec<CandidateAction> = mover_candidates
+        .iter()
+        .zip(waiting_seat)
+        .map(|(c, waiting)| CandidateAction {
+            action: c.action.clone(),
+            ev: waiting.ev,
+            rollouts: c.rollouts,
+            risk_adjusted_ev: waiting.risk_adjusted_ev,
+            ev_variance: waiting.ev_variance,
+            optimality_probability: 0.0,
+            worst_utility: waiting.worst_utility,
+            features: waiting.features,
+        })
+        .collect();
+    let waiting_candidate = |action: &DraftAction| candidates.iter().find(|c| c.action == *action);
+    let best = waiting_candidate(&mover_result.best_action);
+    let second_best = mover_result.second_best_action.as_ref().and_then(waiting_candidate);
+    
+    EvaluationResult {
+        best_action: mover_result.best_action.clone(),
+        best_action_ev: best.map_or(0.0, |c| c.ev),
+        user_action_ev: None,
+        delta_ev: None,
+        second_best_action: mover_result.second_best_action.clone(),
+        second_best_ev: second_best.map(|c| c.ev),
+        metadata: mover_result.metadata.clone(),
+        best_features: best.map(|c| c.features.clone()).unwrap_or_default(),
+        candidates: Some(candidates),
+        user_features: None,
+        feedback: None,
+        grade: None,
+        best_move_summary: None,
+        best_action_state: mover_result.best_action_state.clone(),
+    }
+}
+
+/// Find the move whose worst rollout is least bad (maximin)
+///
+/// For "never blunder" coaching: instead of the best average, pick the
+/// candidate whose lowest rollout utility is highest. Ties go to the higher
+/// EV, then to evaluation order. The rollouts are the ones
+/// `evaluate_best_move` runs, so more `rollouts_per_action` sees more of
+/// each move's downside.
+///
+/// # Errors
+///
+/// Returns `EvaluatorError` under the same conditions as `evaluate_best_move`.
+pub fn safest_move(
+    state: &State,
+    player_id: u8,
+    params: &EvaluatorParams,
+) -> Result<DraftAction, EvaluatorError> {
+    let result = evaluate_best_move(state, player_id, params)?;
+    let candidates = result.candidates.unwrap_or_default();
+    Ok(safest_candidate(&candidates)
+        .map(|c| c.action.clone())
+        .unwrap_or(result.best_action))
+}
+
+/// Candidate with the highest worst-case utility, ties to the higher EV
+pub(crate) fn safest_candidate(candidates: &[CandidateAction]) -> Option<&CandidateAction> {
+    candidates
+        .iter()
+        .fold(None, |safest: Option<&CandidateAction>, c| match safest {
+            Some(s) if (s.worst_utility, s.ev) >= (c.worst_utility, c.ev) => Some(s),
+            _ => Some(c),
+        })
+}
+
+/// How clearly one move stands out, from 0 (all alike) toward 1
+///
+/// Runs `evaluate_best_move` and takes the gap between the best candidate's
+/// EV and the median candidate EV. The gap is squashed as `gap / (gap + s)`,
+/// where `s` is the Good/Okay grade boundary, so a best move a full grade
+/// step ahead of a typical move scores 0.5. A position with a single
+/// candidate scores 0: there is nothing to find.
+///
+/// # Errors
+///
+/// Returns `EvaluatorError` under the same conditions as `evaluate_best_move`.
+pub fn position_sharpness(
+    state: &State,
+    player_id: u8,
+    params: &EvaluatorParams,
+) -> Result<f64, EvaluatorError> {
+    let result = evaluate_best_move(state, player_id, params)?;
+    Ok(sharpness_of(&result.candidates.unwrap_or_default(), result.best_action_ev))
+}
+
+/// Squashed gap between `best_ev` and the median candidate EV
+pub(crate) fn sharpness_of(candidates: &[CandidateAction], best_ev: f64) -> f64 {
+    if candidates.len() < 2 {
+        return 0.0;
+    }
+    
+    let mut evs: Vec<f64> = candidates.iter().map(|c| c.ev).collect();
+    evs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let mid = evs.len() / 2;
+    let median = if evs.len().is_multiple_of(2) {
+        (evs[mid - 1] + evs[mid]) / 2.0
+    } else {
+        evs[mid]
+    };
+    
+    let gap = (best_ev - median).max(0.0);
+    gap / (gap + GRADE_THRESHOLDS.good_max)
+}
+
+/// EV of grabbing the center now, relative to the best factory pick
+///
+/// Returns the best center candidate's EV minus the best factory
+/// candidate's EV. Positive means the center pick is worth taking now;
+/// negative means waiting on the center (taking a factory pick and letting
+/// the center pool grow) is better. If shortlisting left one kind of pick
+/// out, its top heuristic pick is evaluated in a second run through
+/// `must_include`, which replaces any `must_include` in `params`. Returns 0
+/// when only one kind of pick is legal, as there is nothing to weigh.
+///
+/// # Errors
+///
+/// Returns `EvaluatorError` under the same conditions as `evaluate_best_move`.
+pub fn value_of_waiting(
+    state: &State,
+    player_id: u8,
+    params: &EvaluatorParams,
+) -> Result<f64, EvaluatorError> {
+    let result = evaluate_best_move(state, player_id, params)?;
+    let legal_actions = list_legal_actions(state, player_id);
+    let (center_picks, factory_picks): (Vec<DraftAction>, Vec<DraftAction>) = legal_actions
+        .into_iter()
+        .partition(|a| a.source == ActionSource::Center);
+    if center_picks.is_empty() || factory_picks.is_empty() {
+        return Ok(0.0);
+    }
+    
+    let mut candidates = result.candidates.unwrap_or_default();
+    for picks in [&center_picks, &factory_picks] {
+        if candidates.iter().any(|c| picks.contains(&c.action)) {
+            continue;
+        }
+        let params = EvaluatorParams {
+            must_include: shortlist_actions(state, picks, 1).into_iter().next(),
+            ..params.clone()
+        };
+        candidates = evaluate_best_move(state, player_id, &params)?
+            .candidates
+            .unwrap_or_default();
+    }
+    
+    let best_ev = |picks: &[DraftAction]| {
+        candidates
+            .iter()
+            .filter(|c| picks.contains(&c.action))
+            .map(|c| c.ev)
+            .fold(f64::NEG_INFINITY, f64::max)
+    };
+    Ok(best_ev(&center_picks) - best_ev(&factory_picks))
+}
+
+/// Recommended moves for known positions, keyed by `fingerprint`
+///
+/// Lets a lesson teach a fixed opening instead of whatever the rollouts
+/// happen to prefer. Positions that differ only in factory order share a
+/// fingerprint, but a booked move names its factory by index, so book each
+/// position in the factory order it will be dealt in.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OpeningBook {
+    moves: HashMap<u64, DraftAction>,
+}
+
+impl OpeningBook {
+    /// Create an empty book
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Book `action` as the recommended move in `state`
+    pub fn insert(&mut self, state: &State, action: DraftAction) {
+        self.moves.insert(fingerprint(state), action);
+    }
+    
+    /// The booked move for `state`, if any
+    pub fn get(&self, state: &State) -> Option<&DraftAction> {
+        self.moves.get(&fingerprint(state))
+    }
+}
+
+/// Evaluate best move, consulting an opening book first
+///
+/// If `opening_book` has a move for this position and that move is legal,
+/// it is returned as the best action without running rollouts:
+/// `metadata.from_book` is set, `best_action_ev` is 0.0 (no EV was
+/// estimated) and there are no candidates. Otherwise, including when the
+/// booked move is illegal here, this is `evaluate_best_move`.
 ///
 /// # Returns
 ///
-/// * `Ok(EvaluationResult)` - Updated result with user action EV and delta
-/// * `Err(EvaluatorError)` - Grading failed
-pub fn grade_user_action(
+/// * `Ok(EvaluationResult)` - Booked or evaluated best action
+/// * `Err(EvaluatorError)` - Evaluation failed
+pub fn evaluate_best_move_with_book(
     state: &State,
     player_id: u8,
-    user_action: &DraftAction,
     params: &EvaluatorParams,
-    best_result: &EvaluationResult,
+    opening_book: Option<&OpeningBook>,
 ) -> Result<EvaluationResult, EvaluatorError> {
-    // 1. Verify user action is legal
+    // Bad input is reported by the regular evaluation
+    if player_id > 1 || check_game_end(state) {
+        return evaluate_best_move(state, player_id, params);
+    }
+    let Some(action) = opening_book.and_then(|book| book.get(state)) else {
+        return evaluate_best_move(state, player_id, params);
+    };
+    
     let legal_actions = list_legal_actions(state, player_id);
-    if !legal_actions.contains(user_action) {
-        return Err(EvaluatorError::ActionFailed("User action is not legal".to_string()));
+    if !legal_actions.contains(action) {
+        return evaluate_best_move(state, player_id, params);
     }
     
-    // 2. Check if user action was already evaluated in candidates
-    // If so, reuse that EV for consistency (avoids seed variance)
-    let user_ev_from_candidates = if let Some(candidates) = &best_result.candidates {
-        candidates.iter().find(|c| &c.action == user_action).map(|c| c.ev)
-    } else {
-        None
+    let best_features = ActionFeatures {
+        tiles_acquired: count_tiles_in_action(state, action),
+        takes_first_player_token: matches!(action.source, ActionSource::Center)
+            && state.center.has_first_player_token,
+        floor_tiles_by_color: floor_tiles_by_color(state, action),
+        ..ActionFeatures::default()
     };
+    let best_move_summary = summarize_best_move(&best_features, action, state);
+    let best_action_state = result_state(state, action, params)?;
     
-    // 3. Apply user action
+    Ok(EvaluationResult {
+        best_action: action.clone(),
+        best_action_ev: 0.0,
+        user_action_ev: None,
+        delta_ev: None,
+        second_best_action: None,
+        second_best_ev: None,
+        metadata: EvaluationMetadata {
+            elapsed_ms: 0,
+            rollouts_run: 0,
+            candidates_evaluated: 0,
+            total_legal_actions: legal_actions.len(),
+            seed: params.seed_source().resolve(state),
+            completed_within_budget: true,
+            from_book: true,
+        },
+        candidates: None,
+        best_features,
+        user_features: None,
+        feedback: None,
+        grade: None,
+        best_move_summary: Some(best_move_summary),
+        best_action_state,
+    })
+}
+
+/// EV and features of an action `evaluate_best_move` did not evaluate
+fn rollout_user_action(
+    state: &State,
+    player_id: u8,
+    user_action: &DraftAction,
+    params: &EvaluatorParams,
+) -> Result<(f64, ActionFeatures), EvaluatorError> {
+    let seed = params.seed_source().resolve(state);
+    
+    // Apply user action
     let state_after_action = apply_action(state, user_action)
         .map_err(|e| EvaluatorError::ActionFailed(e.message.clone()))?;
     
-    // 4. Run rollouts to track features (always needed for feedback)
-    // If user action was in candidates, we'll use the original EV but still need features
+    // Run rollouts and track features
     let mut utilities = Vec::new();
+    let mut values = Vec::new();
     let mut user_features = ActionFeatures::default();
     let player_before = &state_after_action.players[player_id as usize];
     
-    for i in 0..params.rollouts_per_action {
+    for (replica, i) in replica_rollout_plan(params, params.rollouts_per_action) {
         // Offset seed to avoid collision with best-move evaluation
-        let rollout_seed = params.evaluator_seed.wrapping_add(1_000_000 + i as u64);
+        let rollout_seed = replica_base_seed(seed, replica)
+            .wrapping_add(1_000_000 + i as u64);
         
         let rollout_config = RolloutConfig {
             active_player_policy: params.rollout_config.active_player_policy,
@@ -504,7 +1506,8 @@ pub fn grade_user_action(
             max_actions: 100,
         };
         
-        let result = simulate_rollout(&state_after_action, &rollout_config)
+        let rollout_start = rollout_start_state(&state_after_action, rollout_seed);
+        let result = simulate_rollout(&rollout_start, &rollout_config)
             .map_err(|e| EvaluatorError::RolloutFailure(e.to_string()))?;
         
         let utility = if player_id == 0 {
@@ -514,18 +1517,24 @@ pub fn grade_user_action(
         };
         
         utilities.push(utility);
+        values.push(rollout_value(utility, result.tied, params.utility_mode));
         
         // Track features
         let player_after = &result.final_state.players[player_id as usize];
         
-        let floor_penalty = calculate_floor_penalty_for_player(player_after);
+        let drafted_floor = &result.drafted_floors[player_id as usize];
+        let floor_penalty = calculate_floor_penalty(drafted_floor);
         user_features.expected_floor_penalty += floor_penalty as f64;
         
         let completions = count_pattern_lines_completed(player_before, player_after);
         user_features.expected_completions += completions as f64;
         
-        let tiles_to_floor = player_after.floor_line.tiles.len();
+        let tiles_to_floor = drafted_floor.tiles.len();
         user_features.expected_tiles_to_floor += tiles_to_floor as f64;
+        
+        if has_complete_row(player_after) {
+            user_features.game_end_probability += 1.0;
+        }
     }
     
     // Average features across rollouts
@@ -534,27 +1543,91 @@ pub fn grade_user_action(
         user_features.expected_floor_penalty /= rollout_count;
         user_features.expected_completions /= rollout_count;
         user_features.expected_tiles_to_floor /= rollout_count;
+        user_features.game_end_probability /= rollout_count;
     }
     
     // Static features
     user_features.tiles_acquired = count_tiles_in_action(state, user_action);
     user_features.takes_first_player_token = matches!(user_action.source, ActionSource::Center) 
         && state.center.has_first_player_token;
+    user_features.floor_tiles_by_color = floor_tiles_by_color(state, user_action);
+    
+    let tempo_penalty = params.token_tempo_weight
+        * token_tempo_exposure(&state_after_action, player_id);
+    Ok((mean_value(&values) - tempo_penalty, user_features))
+}
+
+/// Grade user's action by comparing its EV to the best action
+///
+/// Evaluates the user's action using rollout sampling and compares it to
+/// the best action found by `evaluate_best_move`. An action that is among
+/// `best_result`'s candidates keeps that candidate's EV and features, so
+/// the grade and the feedback bullets come from the same rollouts. Set
+/// `must_include` to the user's action when evaluating to guarantee that.
+///
+/// # Arguments
+///
+/// * `state` - Current game state
+/// * `player_id` - Player whose turn it is (0 or 1)
+/// * `user_action` - Action chosen by the user
+/// * `params` - Evaluation parameters
+/// * `best_result` - Result from `evaluate_best_move`
+///
+/// # Returns
+///
+/// * `Ok(EvaluationResult)` - Updated result with user action EV and delta
+/// * `Err(EvaluatorError::IllegalUserMove)` - User action breaks a rule
+/// * `Err(EvaluatorError)` - Grading failed
+pub fn grade_user_action(
+    state: &State,
+    player_id: u8,
+    user_action: &DraftAction,
+    params: &EvaluatorParams,
+    best_result: &EvaluationResult,
+) -> Result<EvaluationResult, EvaluatorError> {
+    // 1. Verify user action is legal, explaining why not
+    if !list_legal_actions(state, player_id).contains(user_action) {
+        let legality = explain_placement(state, player_id, user_action);
+        return Err(EvaluatorError::IllegalUserMove(Box::new(IllegalUserMove {
+            action: user_action.clone(),
+            legality,
+            explanation: legality.to_string(),
+            best_action: best_result.best_action.clone(),
+        })));
+    }
+    
+    // 2. Reuse the candidate's EV and features if the action was already
+    // evaluated: both come from the same rollouts, so the feedback bullets
+    // describe the rollouts that set the grade
+    let evaluated_candidate = best_result.candidates
+        .iter()
+        .flatten()
+        .find(|c| &c.action == user_action);
     
-    // 5. Compute user EV
-    // Use EV from original evaluation if available, otherwise use new rollouts
-    let user_ev = user_ev_from_candidates.unwrap_or_else(|| mean(&utilities));
+    // 3. Otherwise run fresh rollouts for the user's action
+    let (user_ev, user_features) = match evaluated_candidate {
+        Some(candidate) => (candidate.ev, candidate.features.clone()),
+        None => rollout_user_action(state, player_id, user_action, params)?,
+    };
     
-    // 6. Compute delta
+    // 4. Compute delta
     let delta_ev = user_ev - best_result.best_action_ev;
     
-    // 7. Compute grade
-    let grade = compute_grade(delta_ev);
+    // 5. Compute grade (optionally on the spread-normalized delta)
+    let graded_delta = match (&best_result.candidates, params.normalize_grades) {
+        (Some(candidates), true) => normalize_delta_ev(delta_ev, candidates),
+        _ => delta_ev,
+    };
+    let grade = compute_grade(graded_delta);
     
-    // 8. Generate feedback
-    let feedback = generate_feedback_bullets(&user_features, &best_result.best_features);
+    // 6. Generate feedback
+    let feedback = generate_feedback_bullets(
+        &user_features,
+        &best_result.best_features,
+        &params.feedback_thresholds,
+    );
     
-    // 9. Return updated result
+    // 7. Return updated result
     Ok(EvaluationResult {
         user_action_ev: Some(user_ev),
         delta_ev: Some(delta_ev),
@@ -564,3 +1637,117 @@ pub fn grade_user_action(
         ..best_result.clone()
     })
 }
+
+/// Result of evaluating a sequence of the player's own moves
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PlanEvaluation {
+    /// The player's moves, in order
+    pub plan: Vec<DraftAction>,
+    /// Opponent replies played between plan steps
+    pub opponent_moves: Vec<DraftAction>,
+    /// Expected value after the whole plan, from the player's perspective
+    pub ev: f64,
+    /// Number of rollouts run from the final position
+    pub rollouts: usize,
+    /// Position after the last plan step
+    pub final_state: State,
+}
+
+/// Evaluate a plan spanning several of the player's turns
+///
+/// Plays `plan` in order from `state`, with the opponent answering each
+/// step (except the last) with a deterministic greedy move, so the line of
+/// play is reproducible. Each step is checked against the position it is
+/// actually played from, so a move may rely on tiles an earlier step pushed
+/// into the center. Rollouts then run from the position after the last step,
+/// seeded like `grade_user_action`.
+///
+/// The plan must stay within the current round: once the table is empty,
+/// further steps have no tiles to take and are reported as illegal.
+///
+/// # Returns
+///
+/// * `Ok(PlanEvaluation)` - The line played and its EV
+/// * `Err(EvaluatorError::IllegalPlanStep)` - A step breaks a rule when reached
+/// * `Err(EvaluatorError)` - Evaluation failed
+pub fn evaluate_plan(
+    state: &State,
+    player_id: u8,
+    plan: &[DraftAction],
+    params: &EvaluatorParams,
+) -> Result<PlanEvaluation, EvaluatorError> {
+    if player_id > 1 {
+        return Err(EvaluatorError::InvalidPlayer(player_id));
+    }
+    validate_params(params)?;
+    if check_game_end(state) {
+        return Err(EvaluatorError::GameOver);
+    }
+    if plan.is_empty() {
+        return Err(EvaluatorError::InvalidParams("Plan must contain at least one move".to_string()));
+    }
+    if state.active_player_id != player_id {
+        return Err(EvaluatorError::InvalidParams(format!(
+            "Plan must start on player {}'s turn", player_id
+        )));
+    }
+    
+    let seed = params.seed_source().resolve(state);
+    let mut rng = create_rng_from_seed(seed);
+    let opponent = GreedyPolicy::deterministic();
+    let mut current = state.clone();
+    let mut opponent_moves = Vec::new();
+    
+    for (step, action) in plan.iter().enumerate() {
+        let legality = explain_placement(&current, player_id, action);
+        if !legality.is_legal() {
+            return Err(EvaluatorError::IllegalPlanStep { step, legality });
+        }
+        current = apply_action(&current, action)
+            .map_err(|e| EvaluatorError::ActionFailed(e.message))?;
+        
+        // The opponent replies before every step but the last
+        if step + 1 < plan.len() {
+            let replies = list_legal_actions(&current, current.active_player_id);
+            if let Some(reply) = opponent.select_action(&current, &replies, &mut rng) {
+                current = apply_action(&current, &reply)
+                    .map_err(|e| EvaluatorError::ActionFailed(e.message))?;
+                opponent_moves.push(reply);
+            }
+        }
+    }
+    
+    let mut utilities = Vec::new();
+    for (replica, i) in replica_rollout_plan(params, params.rollouts_per_action) {
+        // Offset seed to avoid collision with best-move and grading rollouts
+        let rollout_seed = replica_base_seed(seed, replica)
+            .wrapping_add(2_000_000 + i as u64);
+        
+        let rollout_config = RolloutConfig {
+            active_player_policy: params.rollout_config.active_player_policy,
+            opponent_policy: params.rollout_config.opponent_policy,
+            seed: rollout_seed,
+            max_actions: 100,
+        };
+        
+        let rollout_start = rollout_start_state(&current, rollout_seed);
+        let result = simulate_rollout(&rollout_start, &rollout_config)
+            .map_err(|e| EvaluatorError::RolloutFailure(e.to_string()))?;
+        
+        let margin = if player_id == 0 {
+            result.player_0_score - result.player_1_score
+        } else {
+            result.player_1_score - result.player_0_score
+        };
+        utilities.push(rollout_value(margin, result.tied, params.utility_mode));
+    }
+    
+    Ok(PlanEvaluation {
+        plan: plan.to_vec(),
+        opponent_moves,
+        ev: mean_value(&utilities),
+        rollouts: utilities.len(),
+        final_state: current,
+    })
+}
@@ -1,10 +1,11 @@
-use crate::model::{PlayerBoard, DraftAction, ActionSource, State};
-use crate::rules::constants::FLOOR_PENALTIES;
+use crate::model::{PlayerBoard, DraftAction, ActionSource, Destination, State, TileColor};
+use crate::rules::scoring::calculate_floor_penalty;
+use crate::rules::legality::preview_placement;
 use serde::{Deserialize, Serialize};
-use std::cmp::min;
+use std::collections::BTreeMap;
 
 /// Statistics collected for an action across rollouts
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct ActionFeatures {
     /// Expected floor penalty (average across rollouts)
@@ -19,6 +20,13 @@ pub struct ActionFeatures {
     pub takes_first_player_token: bool,
     /// Number of tiles acquired in the action
     pub tiles_acquired: u8,
+    /// Tiles the action itself sends to the floor, by color
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub floor_tiles_by_color: BTreeMap<TileColor, u8>,
+    /// Fraction of rollouts where this player's wall completes a row this
+    /// round, ending the game
+    #[serde(default)]
+    pub game_end_probability: f64,
 }
 
 impl Default for ActionFeatures {
@@ -30,6 +38,39 @@ impl Default for ActionFeatures {
             expected_tiles_to_floor: 0.0,
             takes_first_player_token: false,
             tiles_acquired: 0,
+            floor_tiles_by_color: BTreeMap::new(),
+            game_end_probability: 0.0,
+        }
+    }
+}
+
+/// Minimum feature differences before a feedback bullet is shown
+///
+/// Defaults suit casual play; lower cutoffs give stricter coaching that
+/// points out smaller differences.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct FeedbackThresholds {
+    /// Expected floor penalty difference, in points
+    pub floor_delta: f64,
+    /// Expected pattern line completion difference
+    pub completion_delta: f64,
+    /// Tiles sent to the floor difference
+    pub waste_delta: f64,
+    /// Expected adjacency points difference
+    pub adjacency_delta: f64,
+    /// Rollout share ending the game before a move counts as game-ending
+    pub game_end_likelihood: f64,
+}
+
+impl Default for FeedbackThresholds {
+    fn default() -> Self {
+        Self {
+            floor_delta: 0.5,
+            completion_delta: 0.1,
+            waste_delta: 0.5,
+            adjacency_delta: 0.5,
+            game_end_likelihood: 0.5,
         }
     }
 }
@@ -43,6 +84,7 @@ pub enum FeedbackCategory {
     WastedTiles,
     Adjacency,
     FirstPlayerToken,
+    GameEndTrigger,
 }
 
 /// Human-readable feedback bullet
@@ -95,6 +137,56 @@ pub fn compute_grade(delta_ev: f64) -> Grade {
     }
 }
 
+/// Summary of a practice session's graded moves
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SessionStats {
+    pub total: usize,
+    pub excellent: usize,
+    pub good: usize,
+    pub okay: usize,
+    pub miss: usize,
+    /// Mean delta EV across the session, when deltas were provided
+    pub average_delta_ev: Option<f64>,
+    /// Consecutive Excellent/Good moves ending with the latest move
+    pub current_streak: usize,
+    /// Longest run of consecutive Excellent/Good moves
+    pub longest_streak: usize,
+}
+
+/// Aggregate a session's grades for progress tracking
+///
+/// `grades` are in the order the moves were played. `delta_evs`, if given,
+/// are averaged as-is; an empty slice leaves the average unset.
+pub fn aggregate_grades(grades: &[Grade], delta_evs: Option<&[f64]>) -> SessionStats {
+    let mut stats = SessionStats {
+        total: grades.len(),
+        ..SessionStats::default()
+    };
+    
+    for grade in grades {
+        match grade {
+            Grade::Excellent => stats.excellent += 1,
+            Grade::Good => stats.good += 1,
+            Grade::Okay => stats.okay += 1,
+            Grade::Miss => stats.miss += 1,
+        }
+        
+        if matches!(grade, Grade::Excellent | Grade::Good) {
+            stats.current_streak += 1;
+            stats.longest_streak = stats.longest_streak.max(stats.current_streak);
+        } else {
+            stats.current_streak = 0;
+        }
+    }
+    
+    stats.average_delta_ev = delta_evs
+        .filter(|deltas| !deltas.is_empty())
+        .map(|deltas| deltas.iter().sum::<f64>() / deltas.len() as f64);
+    
+    stats
+}
+
 /// Count pattern lines that were completed in this round
 pub fn count_pattern_lines_completed(before: &PlayerBoard, after: &PlayerBoard) -> u8 {
     let mut completed = 0;
@@ -113,20 +205,7 @@ pub fn count_pattern_lines_completed(before: &PlayerBoard, after: &PlayerBoard)
 
 /// Calculate floor penalty for a player's floor line
 pub fn calculate_floor_penalty_for_player(player: &PlayerBoard) -> i32 {
-    let floor_count = player.floor_line.tiles.len();
-    let has_token = player.floor_line.has_first_player_token;
-    
-    let total_slots = if has_token {
-        min(floor_count + 1, 7)
-    } else {
-        min(floor_count, 7)
-    };
-    
-    let mut penalty = 0;
-    for i in 0..total_slots {
-        penalty += FLOOR_PENALTIES[i];
-    }
-    penalty
+    calculate_floor_penalty(&player.floor_line)
 }
 
 /// Count tiles in an action source
@@ -144,16 +223,125 @@ pub fn count_tiles_in_action(state: &State, action: &DraftAction) -> u8 {
     }
 }
 
+/// Tiles an action sends straight to the floor, by color
+///
+/// Counts overflow beyond the pattern line's free space, or every tile for a
+/// floor placement. The first player token is not included.
+pub fn floor_tiles_by_color(state: &State, action: &DraftAction) -> BTreeMap<TileColor, u8> {
+    let tiles = count_tiles_in_action(state, action);
+    let to_floor = match action.destination {
+        Destination::PatternLine(row) => {
+            let player = &state.players[state.active_player_id as usize];
+            preview_placement(player, row, action.color, tiles)
+                .map_or(tiles, |preview| preview.overflow)
+        }
+        Destination::Floor => tiles,
+    };
+    
+    let mut by_color = BTreeMap::new();
+    if to_floor > 0 {
+        by_color.insert(action.color, to_floor);
+    }
+    by_color
+}
+
+/// Describe a color breakdown, e.g. "1 Blue tile and 2 Red tiles"
+pub(crate) fn describe_tile_colors(by_color: &BTreeMap<TileColor, u8>) -> String {
+    let parts: Vec<String> = by_color
+        .iter()
+        .map(|(color, &count)| {
+            format!("{} {} tile{}", count, color.label(), if count == 1 { "" } else { "s" })
+        })
+        .collect();
+    parts.join(" and ")
+}
+
+/// Explain the recommended move on its own, without a user move to compare
+///
+/// Describes what the move takes, which pattern line it advances or
+/// completes, any immediate overflow or first-player token, and the rollout
+/// estimates for floor cost and line completions this round.
+pub fn summarize_best_move(
+    best_features: &ActionFeatures,
+    action: &DraftAction,
+    state: &State,
+) -> String {
+    let tiles = count_tiles_in_action(state, action);
+    let source = match action.source {
+        ActionSource::Factory(idx) => format!("factory {}", idx + 1),
+        ActionSource::Center => "the center".to_string(),
+    };
+    let plural = if tiles == 1 { "" } else { "s" };
+    let mut sentences = vec![format!("Take {} {} tile{} from {}", tiles, action.color.label(), plural, source)];
+    
+    match action.destination {
+        Destination::PatternLine(row) => {
+            let player = &state.players[state.active_player_id as usize];
+            let line = &player.pattern_lines[row];
+            if let Some(preview) = preview_placement(player, row, action.color, tiles) {
+                let target = if preview.completes_line {
+                    format!(", which completes pattern line {}", row + 1)
+                } else {
+                    format!(
+                        ", advancing pattern line {} to {}/{}",
+                        row + 1,
+                        line.count_filled + preview.placed,
+                        line.capacity
+                    )
+                };
+                sentences[0].push_str(&target);
+                if preview.overflow > 0 {
+                    sentences[0].push_str(&format!(" ({} overflow to the floor)", preview.overflow));
+                }
+            }
+        }
+        Destination::Floor => sentences[0].push_str(" and place them on the floor"),
+    }
+    sentences[0].push('.');
+    
+    if best_features.takes_first_player_token {
+        sentences.push("It takes the first player token, so you start next round.".to_string());
+    }
+    
+    if best_features.expected_floor_penalty < -0.05 {
+        sentences.push(format!(
+            "Expected floor cost this round: ~{:.1} points.",
+            best_features.expected_floor_penalty.abs()
+        ));
+    } else {
+        sentences.push("It keeps your floor clean.".to_string());
+    }
+    
+    if best_features.expected_completions > 0.05 {
+        sentences.push(format!(
+            "Expect ~{:.1} pattern line completions by the end of the round.",
+            best_features.expected_completions
+        ));
+    }
+    
+    sentences.join(" ")
+}
+
+/// Importance of the game-end bullet, in points like the other deltas
+///
+/// Whether the game ends this round outweighs most single-round
+/// differences, so the bullet ranks above them.
+const GAME_END_BULLET_DELTA: f64 = 5.0;
+
 /// Generate 1-3 feedback bullets comparing user to best action
+///
+/// A difference only produces a bullet once it exceeds its cutoff in
+/// `thresholds`.
 pub fn generate_feedback_bullets(
     user_features: &ActionFeatures,
     best_features: &ActionFeatures,
+    thresholds: &FeedbackThresholds,
 ) -> Vec<FeedbackBullet> {
     let mut bullets = Vec::new();
     
     // 1. Floor penalty difference
     let floor_delta = user_features.expected_floor_penalty - best_features.expected_floor_penalty;
-    if floor_delta.abs() > 0.5 {
+    if floor_delta.abs() > thresholds.floor_delta {
         let text = if floor_delta > 0.0 {
             format!(
                 "Best move reduces floor penalty by ~{:.1} points more than your move.",
@@ -174,7 +362,7 @@ pub fn generate_feedback_bullets(
     
     // 2. Line completion difference
     let completion_delta = best_features.expected_completions - user_features.expected_completions;
-    if completion_delta > 0.1 {
+    if completion_delta > thresholds.completion_delta {
         let text = format!(
             "Best move is more likely to complete a pattern line this round ({:.0}% vs {:.0}%).",
             best_features.expected_completions * 100.0,
@@ -187,13 +375,23 @@ pub fn generate_feedback_bullets(
         });
     }
     
-    // 3. Wasted tiles difference
-    let waste_delta = user_features.expected_tiles_to_floor - best_features.expected_tiles_to_floor;
-    if waste_delta > 0.5 {
-        let text = format!(
-            "Your move sends ~{:.1} more tiles to the floor than the best move.",
-            waste_delta
-        );
+    // 3. Wasted tiles difference (rollout estimate, or what the move itself dumps)
+    let user_dumped: u8 = user_features.floor_tiles_by_color.values().sum();
+    let best_dumped: u8 = best_features.floor_tiles_by_color.values().sum();
+    let waste_delta = (user_features.expected_tiles_to_floor - best_features.expected_tiles_to_floor)
+        .max(user_dumped as f64 - best_dumped as f64);
+    if waste_delta > thresholds.waste_delta {
+        let text = if user_dumped > best_dumped {
+            format!(
+                "Your move sends {} to the floor that you couldn't place.",
+                describe_tile_colors(&user_features.floor_tiles_by_color)
+            )
+        } else {
+            format!(
+                "Your move sends ~{:.1} more tiles to the floor than the best move.",
+                waste_delta
+            )
+        };
         bullets.push(FeedbackBullet {
             category: FeedbackCategory::WastedTiles,
             text,
@@ -203,7 +401,7 @@ pub fn generate_feedback_bullets(
     
     // 4. Adjacency difference
     let adjacency_delta = best_features.expected_adjacency_points - user_features.expected_adjacency_points;
-    if adjacency_delta > 0.5 {
+    if adjacency_delta > thresholds.adjacency_delta {
         let text = format!(
             "Best move creates better wall adjacency, scoring ~{:.1} more points.",
             adjacency_delta
@@ -229,6 +427,22 @@ pub fn generate_feedback_bullets(
         });
     }
     
+    // 6. Game end: completing a wall row ends the game after this round
+    let user_ends = user_features.game_end_probability >= thresholds.game_end_likelihood;
+    let best_ends = best_features.game_end_probability >= thresholds.game_end_likelihood;
+    if user_ends != best_ends {
+        let text = if user_ends {
+            "Your move completes a wall row and ends the game; the best move plays on for more rounds.".to_string()
+        } else {
+            "Best move completes a wall row and ends the game, denying your opponent further rounds.".to_string()
+        };
+        bullets.push(FeedbackBullet {
+            category: FeedbackCategory::GameEndTrigger,
+            text,
+            delta: GAME_END_BULLET_DELTA,
+        });
+    }
+    
     // Sort by importance and take top 3
     bullets.sort_by(|a, b| b.delta.partial_cmp(&a.delta).unwrap_or(std::cmp::Ordering::Equal));
     bullets.truncate(3);
@@ -1,5 +1,5 @@
 use crate::model::{State, DraftAction, Destination};
-use crate::rules::list_legal_actions;
+use crate::rules::{list_legal_actions, evaluate_best_move, EvaluationResult, EvaluatorParams, SEED_FROM_STATE};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -18,6 +18,8 @@ pub enum FilterError {
     ValueGapTooSmall { actual: f32, minimum: f32 },
     /// Value gap too large
     ValueGapTooLarge { actual: f32, maximum: f32 },
+    /// The position does not drill what `drill_target` asks for
+    DrillTargetMissed(DrillTarget),
 }
 
 impl std::fmt::Display for FilterError {
@@ -49,12 +51,24 @@ impl std::fmt::Display for FilterError {
             FilterError::ValueGapTooLarge { actual, maximum } => {
                 write!(f, "Value gap too large: {:.1} (maximum: {:.1})", actual, maximum)
             }
+            FilterError::DrillTargetMissed(target) => {
+                write!(f, "Position does not fit drill target {:?}", target)
+            }
         }
     }
 }
 
 impl std::error::Error for FilterError {}
 
+/// A skill a generated scenario should drill, checked by evaluating it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DrillTarget {
+    /// Every move costs floor points; the best one costs no more than any
+    /// alternative (damage control)
+    FloorDamageControl,
+}
+
 /// Configuration for quality filters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FilterConfig {
@@ -89,6 +103,11 @@ pub struct FilterConfig {
     /// Default: None (balanced mix allows clear best moves)
     #[serde(default)]
     pub max_value_gap: Option<f32>,
+    
+    /// Skill the scenario must drill (see `check_drill_target`)
+    /// Default: None (any position)
+    #[serde(default)]
+    pub drill_target: Option<DrillTarget>,
 }
 
 fn default_min_legal_actions() -> usize {
@@ -116,6 +135,25 @@ impl Default for FilterConfig {
             max_floor_ratio: default_max_floor_ratio(),
             min_value_gap: None,
             max_value_gap: None,
+            drill_target: None,
+        }
+    }
+}
+
+impl FilterConfig {
+    /// Relaxed filters for "forced dump" teaching positions
+    ///
+    /// The default filters reject positions where the active player can only
+    /// draft to the floor. Those are exactly the positions that teach which
+    /// tiles to dump (see `ScenarioTheme::ForcedDump`), so this preset keeps
+    /// the minimum number of legal actions but drops the destination and
+    /// floor-ratio checks.
+    pub fn forced_dump() -> Self {
+        Self {
+            min_unique_destinations: 1,
+            require_non_floor_option: false,
+            max_floor_ratio: 1.0,
+            ..Self::default()
         }
     }
 }
@@ -213,6 +251,60 @@ pub fn apply_quality_filters(
     Ok(())
 }
 
+/// Evaluator settings for drill checks
+///
+/// Seeded from the position, with a budget large enough that the rollout
+/// count rather than the clock decides the result, so a check is
+/// reproducible.
+pub(crate) fn drill_evaluator_params() -> EvaluatorParams {
+    EvaluatorParams {
+        time_budget_ms: 60_000,
+        rollouts_per_action: 8,
+        evaluator_seed: SEED_FROM_STATE,
+        ..EvaluatorParams::default()
+    }
+}
+
+/// Whether an evaluation shows the position drills `target`
+pub(crate) fn fits_drill_target(result: &EvaluationResult, target: DrillTarget) -> bool {
+    let Some(candidates) = &result.candidates else {
+        return false;
+    };
+    match target {
+        DrillTarget::FloorDamageControl => {
+            let best = result.best_features.expected_floor_penalty;
+            let alternatives: Vec<f64> = candidates
+                .iter()
+                .filter(|c| c.action != result.best_action)
+                .map(|c| c.features.expected_floor_penalty)
+                .collect();
+            if alternatives.is_empty() || alternatives.iter().any(|&penalty| penalty >= 0.0) {
+                return false;
+            }
+            let cheapest_alternative = alternatives.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            best < 0.0 && best >= cheapest_alternative
+        }
+    }
+}
+
+/// Check that the best move in `state` drills `target`
+///
+/// Runs a full evaluation, so it is far more expensive than
+/// `apply_quality_filters`; `generate_scenario_with_filters` only calls it
+/// on positions that passed those.
+///
+/// # Errors
+///
+/// `FilterError::DrillTargetMissed` if the position does not fit, or
+/// cannot be evaluated.
+pub fn check_drill_target(state: &State, target: DrillTarget) -> Result<(), FilterError> {
+    evaluate_best_move(state, state.active_player_id, &drill_evaluator_params())
+        .ok()
+        .filter(|result| fits_drill_target(result, target))
+        .map(|_| ())
+        .ok_or(FilterError::DrillTargetMissed(target))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -223,10 +315,11 @@ mod tests {
         let config = FilterConfig::default();
         assert_eq!(config.min_legal_actions, 6);  // Raised from 3
         assert_eq!(config.min_unique_destinations, 2);
-        assert_eq!(config.require_non_floor_option, true);
+        assert!(config.require_non_floor_option);
         assert_eq!(config.max_floor_ratio, 0.5);
         assert_eq!(config.min_value_gap, None);
         assert_eq!(config.max_value_gap, None);
+        assert_eq!(config.drill_target, None);
     }
 
     #[test]
@@ -305,6 +398,7 @@ mod tests {
             max_floor_ratio: 0.5,
             min_value_gap: None,
             max_value_gap: None,
+            drill_target: None,
         };
         
         // Add minimal tiles to create few actions
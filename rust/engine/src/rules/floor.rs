@@ -0,0 +1,50 @@
+use crate::model::{FloorLine, TileColor, TileMultiset, TileMultisetExt};
+use super::constants::FLOOR_LINE_SLOTS;
+
+/// Floor slots in use, counting the first player token
+pub fn floor_slots_used(floor_line: &FloorLine) -> usize {
+    floor_line.tiles.len() + usize::from(floor_line.has_first_player_token)
+}
+
+/// Put tiles on the floor line, returning any that do not fit to the lid
+///
+/// The floor has 7 slots. Once they are full, further tiles go straight back
+/// to the box lid and cost nothing, so the floor never holds more than 7.
+///
+/// # Returns
+///
+/// Number of tiles sent to the lid
+pub fn place_on_floor(
+    floor_line: &mut FloorLine,
+    lid: &mut TileMultiset,
+    color: TileColor,
+    count: u8,
+) -> u8 {
+    let free = FLOOR_LINE_SLOTS.saturating_sub(floor_slots_used(floor_line));
+    let placed = count.min(free as u8);
+    floor_line.tiles.extend(std::iter::repeat_n(color, placed as usize));
+    
+    let to_lid = count - placed;
+    lid.add(color, to_lid);
+    to_lid
+}
+
+/// Put the first player token on the floor line
+///
+/// The token always goes on the floor. If all 7 slots are taken, the last
+/// tile makes room for it and goes to the lid.
+///
+/// # Returns
+///
+/// Color of the tile bumped to the lid, if any
+pub fn place_first_player_token(floor_line: &mut FloorLine, lid: &mut TileMultiset) -> Option<TileColor> {
+    let mut bumped = None;
+    if floor_slots_used(floor_line) >= FLOOR_LINE_SLOTS {
+        bumped = floor_line.tiles.pop();
+        if let Some(color) = bumped {
+            lid.add(color, 1);
+        }
+    }
+    floor_line.has_first_player_token = true;
+    bumped
+}
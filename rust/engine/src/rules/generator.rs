@@ -8,10 +8,14 @@ use crate::rules::{
     DraftPolicy,
     RandomPolicy,
     GreedyPolicy,
+    LookaheadPolicy,
+    ProfilePolicy,
+    GreedyWeights,
     ValidationError,
     FilterConfig,
     apply_quality_filters,
-    end_of_round::resolve_end_of_round,
+    check_drill_target,
+    end_of_round::resolve_end_of_round_with_rng,
 };
 use rand::Rng;
 use serde::{Deserialize, Serialize};
@@ -53,6 +57,10 @@ pub enum PolicyMix {
     AllGreedy,
     /// Mix policies with specified greedy ratio (0.0-1.0)
     Mixed { greedy_ratio: f32 },
+    /// Mix greedy and one-ply lookahead with specified lookahead ratio (0.0-1.0)
+    GreedyLookahead { lookahead_ratio: f32 },
+    /// Play like a particular person (see `ProfilePolicy`)
+    Profile { weights: GreedyWeights },
 }
 
 impl Default for PolicyMix {
@@ -68,10 +76,18 @@ pub struct GeneratorParams {
     pub target_game_stage: GameStage,
     /// Target round stage (Start/Mid/End of round) - optional
     pub target_round_stage: Option<RoundStage>,
+    /// Exact round number to sample from (1-based) - optional
+    ///
+    /// Rounds are completed until this round is reached; seeds whose game
+    /// stage no longer matches by then are rejected like any other miss.
+    pub target_round: Option<u32>,
     /// Random seed for reproducibility
     pub seed: u64,
     /// Policy mix for play-forward
     pub policy_mix: PolicyMix,
+    /// Fail with `MaxAttemptsExceeded` instead of falling back to a state
+    /// that matches the stage but not the quality filters
+    pub strict: bool,
 }
 
 /// JSON-serializable parameters for WASM API
@@ -84,6 +100,9 @@ pub struct GeneratorParamsJson {
     pub target_game_stage: Option<GameStage>,
     /// Target round stage: "START", "MID", "END", or null for any
     pub target_round_stage: Option<RoundStage>,
+    /// Exact round number to sample from, or null for any
+    #[serde(default)]
+    pub target_round: Option<u32>,
     /// Legacy alias for target_game_stage (backward compatibility)
     #[serde(alias = "targetPhase")]
     pub target_phase: Option<GameStage>,
@@ -93,6 +112,9 @@ pub struct GeneratorParamsJson {
     pub policy_mix: Option<String>,
     /// Filter configuration, or null for defaults
     pub filter_config: Option<FilterConfig>,
+    /// Reject instead of falling back when no attempt passes the filters
+    #[serde(default)]
+    pub strict: bool,
 }
 
 impl GeneratorParamsJson {
@@ -142,8 +164,10 @@ impl GeneratorParamsJson {
         let params = GeneratorParams {
             target_game_stage,
             target_round_stage,
+            target_round: self.target_round,
             seed,
             policy_mix,
+            strict: self.strict,
         };
         
         let filter_config = self.filter_config.clone().unwrap_or_default();
@@ -157,8 +181,10 @@ impl Default for GeneratorParams {
         Self {
             target_game_stage: GameStage::Mid,
             target_round_stage: None,
+            target_round: None,
             seed: 0,
             policy_mix: PolicyMix::default(),
+            strict: false,
         }
     }
 }
@@ -225,6 +251,8 @@ fn calculate_generation_strategy<R: Rng>(target_stage: GameStage, rng: &mut R) -
 enum PolicySelector {
     Random(RandomPolicy),
     Greedy(GreedyPolicy),
+    Lookahead(LookaheadPolicy),
+    Profile(ProfilePolicy),
 }
 
 impl PolicySelector {
@@ -237,6 +265,8 @@ impl PolicySelector {
         match self {
             PolicySelector::Random(p) => p.select_action(state, legal_actions, rng),
             PolicySelector::Greedy(p) => p.select_action(state, legal_actions, rng),
+            PolicySelector::Lookahead(p) => p.select_action(state, legal_actions, rng),
+            PolicySelector::Profile(p) => p.select_action(state, legal_actions, rng),
         }
     }
 }
@@ -257,15 +287,24 @@ fn select_policy<R: Rng>(
 ) -> PolicySelector {
     match policy_mix {
         PolicyMix::AllRandom => PolicySelector::Random(RandomPolicy),
-        PolicyMix::AllGreedy => PolicySelector::Greedy(GreedyPolicy),
+        PolicyMix::AllGreedy => PolicySelector::Greedy(GreedyPolicy::new()),
         PolicyMix::Mixed { greedy_ratio } => {
             let r: f32 = rng.gen();
             if r < *greedy_ratio {
-                PolicySelector::Greedy(GreedyPolicy)
+                PolicySelector::Greedy(GreedyPolicy::new())
             } else {
                 PolicySelector::Random(RandomPolicy)
             }
         }
+        PolicyMix::GreedyLookahead { lookahead_ratio } => {
+            let r: f32 = rng.gen();
+            if r < *lookahead_ratio {
+                PolicySelector::Lookahead(LookaheadPolicy)
+            } else {
+                PolicySelector::Greedy(GreedyPolicy::new())
+            }
+        }
+        PolicyMix::Profile { weights } => PolicySelector::Profile(ProfilePolicy { weights: *weights }),
     }
 }
 
@@ -280,7 +319,7 @@ fn select_policy<R: Rng>(
 /// # Returns
 ///
 /// Round stage (Start/Mid/End)
-fn compute_round_stage(state: &State) -> RoundStage {
+pub(crate) fn compute_round_stage(state: &State) -> RoundStage {
     // Count total tiles in factories and center
     let mut total_in_play = 0u32;
     
@@ -313,7 +352,7 @@ fn compute_round_stage(state: &State) -> RoundStage {
 /// # Returns
 ///
 /// Game stage (Early/Mid/Late)
-fn compute_game_stage(state: &State) -> GameStage {
+pub(crate) fn compute_game_stage(state: &State) -> GameStage {
     // Count wall tiles for both players (use max for stage classification)
     let mut max_wall_tiles = 0u32;
     let mut near_completion = false;
@@ -399,11 +438,66 @@ impl SnapshotCandidate {
     #[allow(dead_code)]  // Reserved for future use
     fn matches_target(&self, target_game_stage: GameStage, target_round_stage: Option<RoundStage>) -> bool {
         let game_match = self.game_stage == target_game_stage;
-        let round_match = target_round_stage.map_or(true, |target| self.round_stage == target);
+        let round_match = target_round_stage.is_none_or(|target| self.round_stage == target);
         game_match && round_match
     }
 }
 
+/// Last round the generator plays before giving up on a seed
+const MAX_GENERATION_ROUND: u8 = 10;
+
+/// Whether a seed can no longer reach the Late stage before the round limit
+///
+/// Each wall row gains at most one tile per round, so a player can add at
+/// most five tiles a round and take a row no further than one tile closer to
+/// completion. If no player can get to 18 wall tiles or a row of four within
+/// the rounds left, playing on is wasted work.
+fn cannot_reach_late(state: &State) -> bool {
+    let rounds_left = (MAX_GENERATION_ROUND + 1).saturating_sub(state.round_number) as usize;
+    
+    !state.players.iter().any(|player| {
+        let row_counts: Vec<usize> = player.wall.iter()
+            .map(|row| row.iter().filter(|&&occupied| occupied).count())
+            .collect();
+        let wall_tiles: usize = row_counts.iter().sum();
+        
+        wall_tiles + 5 * rounds_left >= 18
+            || row_counts.iter().any(|&count| count + rounds_left >= 4)
+    })
+}
+
+/// Draft until the table is empty, then resolve the round
+///
+/// Late in the game the bag and lid together may hold fewer than 20 tiles,
+/// so the round starts with partially filled (or empty) factories. That is
+/// still a complete round: every draft takes at least one tile off the
+/// table, so the loop ends within as many decisions as there are tiles.
+fn play_out_round<R: Rng>(
+    mut state: State,
+    policy_mix: &PolicyMix,
+    rng: &mut R,
+) -> Result<State, GeneratorError> {
+    loop {
+        let legal_actions = list_legal_actions(&state, state.active_player_id);
+        
+        if legal_actions.is_empty() {
+            // Round complete - resolve it
+            return resolve_end_of_round_with_rng(&state, rng)
+                .map_err(GeneratorError::ApplyActionFailed);
+        }
+        
+        // Select policy and action
+        let policy = select_policy(policy_mix, rng);
+        let action = policy
+            .select_action(&state, &legal_actions, rng)
+            .ok_or(GeneratorError::NoPolicyAction)?;
+        
+        // Apply action
+        state = apply_action(&state, &action)
+            .map_err(GeneratorError::ApplyActionFailed)?;
+    }
+}
+
 /// Generate a scenario by playing forward and sampling snapshots
 ///
 /// Uses policy bots to simulate gameplay, recording snapshots at decision points.
@@ -433,39 +527,24 @@ pub fn generate_scenario(params: GeneratorParams) -> Result<State, GeneratorErro
         GameStage::Late => 18,      // Need at least 18 tiles
     };
     
-    // Phase 1: Complete rounds until we have enough wall tiles
-    // This guarantees the right game stage before sampling
-    while compute_game_stage(&state) != params.target_game_stage {
-        // Safety check - don't run forever
-        if state.round_number > 10 {
+    let before_target_round = |state: &State| {
+        params.target_round.is_some_and(|round| (state.round_number as u32) < round)
+    };
+    
+    // Phase 1: Complete rounds until we have enough wall tiles (and reach the
+    // target round, if any). This guarantees the right game stage before sampling
+    while compute_game_stage(&state) != params.target_game_stage || before_target_round(&state) {
+        // Safety check - don't run forever (or past the end of the game)
+        if state.round_number > MAX_GENERATION_ROUND || state.is_terminal {
             return Err(GeneratorError::NoPolicyAction);
         }
         
         // Complete one full round
-        loop {
-            let legal_actions = list_legal_actions(&state, state.active_player_id);
-            
-            if legal_actions.is_empty() {
-                // Round complete - resolve it
-                state = resolve_end_of_round(&state)
-                    .map_err(GeneratorError::ApplyActionFailed)?;
-                break;
-            }
-            
-            // Select policy and action
-            let policy = select_policy(&params.policy_mix, &mut rng);
-            let action = policy
-                .select_action(&state, &legal_actions, &mut rng)
-                .ok_or(GeneratorError::NoPolicyAction)?;
-            
-            // Apply action
-            state = apply_action(&state, &action)
-                .map_err(GeneratorError::ApplyActionFailed)?;
-        }
+        state = play_out_round(state, &params.policy_mix, &mut rng)?;
         
         // Check if we've reached target stage
         let current_stage = compute_game_stage(&state);
-        if current_stage == params.target_game_stage {
+        if current_stage == params.target_game_stage && !before_target_round(&state) {
             break;
         }
         
@@ -479,6 +558,16 @@ pub fn generate_scenario(params: GeneratorParams) -> Result<State, GeneratorErro
             // Overshot the target by too much, this seed won't work
             return Err(GeneratorError::NoPolicyAction);
         }
+        
+        // Too far behind to reach Late in time, so move on to the next seed early
+        if params.target_game_stage == GameStage::Late && cannot_reach_late(&state) {
+            return Err(GeneratorError::NoPolicyAction);
+        }
+    }
+    
+    // A game that ended before the target round cannot sample it
+    if params.target_round.is_some_and(|round| state.round_number as u32 != round) {
+        return Err(GeneratorError::NoPolicyAction);
     }
     
     // Phase 2: Play forward in sampling round and collect snapshots
@@ -584,7 +673,8 @@ fn unique_destination_count(actions: &[DraftAction]) -> usize {
 ///
 /// # Returns
 ///
-/// * `Ok(State)` - Valid scenario that passed all filters
+/// * `Ok(State)` - Valid scenario that passed all filters, or, unless
+///   `params.strict` is set, the last stage-matching state if none did
 /// * `Err(GeneratorError)` - Failed to generate valid scenario after max attempts
 pub fn generate_scenario_with_filters(
     params: GeneratorParams,
@@ -611,8 +701,12 @@ pub fn generate_scenario_with_filters(
         // Keep track of the last valid stage-matching state as fallback
         best_stage_matching_state = Some(state.clone());
         
-        // Now check quality filters
-        if apply_quality_filters(&state, &filter_config).is_ok() {
+        // Now check quality filters, then the (costlier) drill target
+        let drills_target = || match filter_config.drill_target {
+            Some(target) => check_drill_target(&state, target).is_ok(),
+            None => true,
+        };
+        if apply_quality_filters(&state, &filter_config).is_ok() && drills_target() {
             return Ok(state);  // Perfect! Stage matches AND filters pass
         }
         
@@ -620,8 +714,9 @@ pub fn generate_scenario_with_filters(
     }
 
     // Hard fallback: return the last stage-matching state even if filters didn't pass
-    // This ensures the UI never fails, while still guaranteeing correct game stage
-    if let Some(state) = best_stage_matching_state {
+    // This ensures the UI never fails, while still guaranteeing correct game stage.
+    // Strict callers (curated packs) would rather have no scenario than a subpar one.
+    if let Some(state) = best_stage_matching_state.filter(|_| !params.strict) {
         return Ok(state);
     }
 
@@ -668,21 +763,21 @@ mod tests {
         for _ in 0..20 {
             let (rounds, picks) = calculate_generation_strategy(GameStage::Early, &mut rng);
             assert_eq!(rounds, 0, "Early should complete 0 rounds, got {}", rounds);
-            assert!(picks >= 3 && picks <= 8, "Early picks should be 3-8, got {}", picks);
+            assert!((3..=8).contains(&picks), "Early picks should be 3-8, got {}", picks);
         }
         
         // Test Mid: (1 round complete, 3-10 picks)
         for _ in 0..20 {
             let (rounds, picks) = calculate_generation_strategy(GameStage::Mid, &mut rng);
             assert_eq!(rounds, 1, "Mid should complete 1 round, got {}", rounds);
-            assert!(picks >= 3 && picks <= 10, "Mid picks should be 3-10, got {}", picks);
+            assert!((3..=10).contains(&picks), "Mid picks should be 3-10, got {}", picks);
         }
         
         // Test Late: (2 rounds complete, 2-8 picks)
         for _ in 0..20 {
             let (rounds, picks) = calculate_generation_strategy(GameStage::Late, &mut rng);
             assert_eq!(rounds, 2, "Late should complete 2 rounds, got {}", rounds);
-            assert!(picks >= 2 && picks <= 8, "Late picks should be 2-8, got {}", picks);
+            assert!((2..=8).contains(&picks), "Late picks should be 2-8, got {}", picks);
         }
     }
 
@@ -766,7 +861,9 @@ mod tests {
             // Verify it's Random variant
             match policy {
                 PolicySelector::Random(_) => {}, // Good!
-                PolicySelector::Greedy(_) => panic!("Expected Random policy"),
+                PolicySelector::Greedy(_) | PolicySelector::Lookahead(_) | PolicySelector::Profile(_) => {
+                    panic!("Expected Random policy")
+                }
             }
         }
     }
@@ -782,7 +879,9 @@ mod tests {
             // Verify it's Greedy variant
             match policy {
                 PolicySelector::Greedy(_) => {}, // Good!
-                PolicySelector::Random(_) => panic!("Expected Greedy policy"),
+                PolicySelector::Random(_) | PolicySelector::Lookahead(_) | PolicySelector::Profile(_) => {
+                    panic!("Expected Greedy policy")
+                }
             }
         }
     }
@@ -791,9 +890,9 @@ mod tests {
     fn test_generate_scenario_deterministic() {
         let params1 = GeneratorParams {
             target_game_stage: GameStage::Early,
-            target_round_stage: None,
             seed: 12345,
             policy_mix: PolicyMix::AllRandom,
+            ..GeneratorParams::default()
         };
         
         let params2 = params1.clone();
@@ -810,9 +909,9 @@ mod tests {
     fn test_generate_scenario_stores_seed() {
         let params = GeneratorParams {
             target_game_stage: GameStage::Early,  // Early is more reliably generated
-            target_round_stage: None,
             seed: 99999,
             policy_mix: PolicyMix::default(),
+            ..GeneratorParams::default()
         };
         
         let filter_config = FilterConfig::default();
@@ -822,13 +921,103 @@ mod tests {
         assert!(state.scenario_seed.is_some(), "Should have scenario_seed");
     }
 
+    #[test]
+    fn test_generate_scenario_reports_target_round() {
+        for (target_game_stage, target_round) in [(GameStage::Early, 2), (GameStage::Mid, 4)] {
+            for seed in 0..3 {
+                let params = GeneratorParams {
+                    target_game_stage,
+                    target_round: Some(target_round),
+                    seed: 2124 + seed,
+                    policy_mix: PolicyMix::AllGreedy,
+                    ..GeneratorParams::default()
+                };
+                
+                let state = generate_scenario_with_filters(params, FilterConfig::default(), 50)
+                    .expect("Generation should succeed");
+                
+                assert_eq!(state.round_number as u32, target_round);
+                assert_eq!(state.scenario_game_stage, Some(target_game_stage));
+            }
+        }
+    }
+
+    #[test]
+    fn test_play_out_round_with_partial_factories() {
+        use crate::rules::check_tile_conservation;
+        
+        // Only 6 tiles on the table; everything else is in the lid
+        let mut state = State::new_test_state();
+        state.factories[0].insert(TileColor::Blue, 4);
+        state.factories[1].insert(TileColor::Red, 2);
+        for color in ALL_COLORS {
+            let on_table = state.factories.iter().map(|f| f.get(&color).copied().unwrap_or(0)).sum::<u8>();
+            state.lid.insert(color, TILES_PER_COLOR - on_table);
+        }
+        assert!(check_tile_conservation(&state).is_ok());
+        
+        let mut rng = StdRng::seed_from_u64(2133);
+        let next = play_out_round(state, &PolicyMix::AllRandom, &mut rng).unwrap();
+        
+        assert_eq!(next.round_number, 2);
+        assert!(check_tile_conservation(&next).is_ok());
+        let dealt: u32 = next.factories.iter().flat_map(|f| f.values()).map(|&c| c as u32).sum();
+        assert_eq!(dealt, 20, "Next round deals from the refilled bag");
+    }
+
+    #[test]
+    fn test_generate_late_scenario_from_depleted_bag() {
+        use crate::rules::check_tile_conservation;
+        
+        // Seed 3 reaches Late in round 5 with the bag empty: the next refill
+        // must drain the lid.
+        let params = GeneratorParams {
+            target_game_stage: GameStage::Late,
+            seed: 3,
+            policy_mix: PolicyMix::AllRandom,
+            ..GeneratorParams::default()
+        };
+        
+        let state = generate_scenario(params.clone()).unwrap();
+        assert_eq!(generate_scenario(params).unwrap(), state, "Refills should follow the seed");
+        
+        assert_eq!(state.bag.values().map(|&c| c as u32).sum::<u32>(), 0);
+        assert_eq!(state.scenario_game_stage, Some(GameStage::Late));
+        assert!(check_tile_conservation(&state).is_ok());
+        assert!(!list_legal_actions(&state, state.active_player_id).is_empty());
+    }
+
+    #[test]
+    fn test_late_generation_succeeds_within_attempt_budget() {
+        let params = GeneratorParams {
+            target_game_stage: GameStage::Late,
+            seed: 2147,
+            policy_mix: PolicyMix::AllGreedy,
+            ..GeneratorParams::default()
+        };
+        
+        let state = generate_scenario_with_filters(params, FilterConfig::default(), 20)
+            .expect("Late generation should succeed within 20 attempts");
+        assert_eq!(compute_game_stage(&state), GameStage::Late);
+        
+        // Round 9 with a single tile per wall can no longer reach Late
+        let mut stalled = create_initial_state(&mut StdRng::seed_from_u64(2147));
+        stalled.round_number = 9;
+        stalled.players[0].wall[0][0] = true;
+        stalled.players[1].wall[2][3] = true;
+        assert!(cannot_reach_late(&stalled));
+        
+        stalled.round_number = 8;
+        assert!(!cannot_reach_late(&stalled), "A row of four is still in reach");
+    }
+
     #[test]
     fn test_generate_scenario_early_phase() {
         let params = GeneratorParams {
             target_game_stage: GameStage::Early,
-            target_round_stage: None,
             seed: 12345,
             policy_mix: PolicyMix::AllRandom,
+            ..GeneratorParams::default()
         };
         
         let state = generate_scenario(params).unwrap();
@@ -842,16 +1031,16 @@ mod tests {
     fn test_generate_scenario_different_seeds_differ() {
         let params1 = GeneratorParams {
             target_game_stage: GameStage::Early,  // Early is more reliable
-            target_round_stage: None,
             seed: 11111,
             policy_mix: PolicyMix::AllRandom,
+            ..GeneratorParams::default()
         };
         
         let params2 = GeneratorParams {
             target_game_stage: GameStage::Early,
-            target_round_stage: None,
             seed: 22222,
             policy_mix: PolicyMix::AllRandom,
+            ..GeneratorParams::default()
         };
         
         let filter_config = FilterConfig::default();
@@ -868,9 +1057,9 @@ mod tests {
     fn test_generate_scenario_with_filters_passes() {
         let params = GeneratorParams {
             target_game_stage: GameStage::Early,  // Early is more reliable
-            target_round_stage: None,
             seed: 12345,
             policy_mix: PolicyMix::AllGreedy,  // Greedy produces more consistent results
+            ..GeneratorParams::default()
         };
         
         let filter_config = FilterConfig::default();
@@ -885,9 +1074,9 @@ mod tests {
     fn test_generate_scenario_with_filters_retries_on_failure() {
         let params = GeneratorParams {
             target_game_stage: GameStage::Early,
-            target_round_stage: None,
             seed: 99999,
             policy_mix: PolicyMix::AllRandom,
+            ..GeneratorParams::default()
         };
         
         // Very strict filters that might require retries
@@ -898,6 +1087,7 @@ mod tests {
             max_floor_ratio: 0.5,
             min_value_gap: None,
             max_value_gap: None,
+            drill_target: None,
         };
         
         let result = generate_scenario_with_filters(params, filter_config, 50);
@@ -910,9 +1100,9 @@ mod tests {
     fn test_generate_scenario_with_filters_max_attempts() {
         let params = GeneratorParams {
             target_game_stage: GameStage::Early,
-            target_round_stage: None,
             seed: 12345,
             policy_mix: PolicyMix::AllRandom,
+            ..GeneratorParams::default()
         };
         
         // Impossible filters
@@ -923,6 +1113,7 @@ mod tests {
             max_floor_ratio: 0.5,
             min_value_gap: None,
             max_value_gap: None,
+            drill_target: None,
         };
         
         let result = generate_scenario_with_filters(params, filter_config, 5);
@@ -932,15 +1123,65 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_floor_drill_scenarios_have_minimal_unavoidable_floor_cost() {
+        use crate::rules::{evaluate_best_move, DrillTarget};
+        use crate::rules::filters::drill_evaluator_params;
+        
+        let params = GeneratorParams {
+            target_game_stage: GameStage::Mid,
+            seed: 2194,
+            policy_mix: PolicyMix::AllGreedy,
+            strict: true,
+            ..GeneratorParams::default()
+        };
+        let filter_config = FilterConfig {
+            drill_target: Some(DrillTarget::FloorDamageControl),
+            ..FilterConfig::default()
+        };
+        
+        let state = generate_scenario_with_filters(params, filter_config, 40).unwrap();
+        let result = evaluate_best_move(&state, state.active_player_id, &drill_evaluator_params())
+            .unwrap();
+        let best = result.best_features.expected_floor_penalty;
+        let others: Vec<f64> = result.candidates.unwrap().iter()
+            .filter(|c| c.action != result.best_action)
+            .map(|c| c.features.expected_floor_penalty)
+            .collect();
+        
+        assert!(best < 0.0, "best move should cost floor points, got {}", best);
+        assert!(others.iter().all(|&penalty| penalty < 0.0));
+        assert!(others.iter().all(|&penalty| best >= penalty), "{} vs {:?}", best, others);
+    }
+
+    #[test]
+    fn test_strict_generation_rejects_impossible_filters() {
+        let params = GeneratorParams {
+            target_game_stage: GameStage::Early,
+            seed: 12345,
+            policy_mix: PolicyMix::AllRandom,
+            strict: true,
+            ..GeneratorParams::default()
+        };
+        
+        let filter_config = FilterConfig {
+            min_legal_actions: 1000,
+            ..FilterConfig::default()
+        };
+        
+        let result = generate_scenario_with_filters(params, filter_config, 5);
+        assert!(matches!(result, Err(GeneratorError::MaxAttemptsExceeded)));
+    }
+
     #[test]
     #[ignore]  // Probabilistic - snapshot sampling may not always find exact stage match
     fn test_mid_game_has_filled_walls() {
         // Mid game should complete 1 round, which should fill some walls
         let params = GeneratorParams {
             target_game_stage: GameStage::Mid,
-            target_round_stage: None,
             seed: 54321,
             policy_mix: PolicyMix::AllGreedy,
+            ..GeneratorParams::default()
         };
         
         let filter_config = FilterConfig::default();
@@ -966,7 +1207,7 @@ mod tests {
         let total_wall_tiles = player0_wall_tiles + player1_wall_tiles;
         
         // Mid game should have 9-17 wall tiles total (per our definition)
-        assert!(total_wall_tiles >= 9 && total_wall_tiles <= 17, 
+        assert!((9..=17).contains(&total_wall_tiles), 
             "Mid game should have 9-17 wall tiles, but found {}. Player 0: {}, Player 1: {}",
             total_wall_tiles, player0_wall_tiles, player1_wall_tiles);
         
@@ -981,9 +1222,9 @@ mod tests {
         // Late game should complete 2 rounds, walls should be more filled
         let params = GeneratorParams {
             target_game_stage: GameStage::Late,
-            target_round_stage: None,
             seed: 11111,
             policy_mix: PolicyMix::AllGreedy,
+            ..GeneratorParams::default()
         };
         
         let filter_config = FilterConfig::default();
@@ -1038,6 +1279,7 @@ mod tests {
                     target_round_stage,
                     seed: 50000 + i,
                     policy_mix: PolicyMix::AllGreedy,
+                    ..GeneratorParams::default()
                 };
                 
                 let state = generate_scenario(params).expect("Generation should succeed");
@@ -1103,6 +1345,7 @@ mod tests {
                     target_round_stage,
                     seed: 60000 + i,
                     policy_mix: PolicyMix::AllGreedy,
+                    ..GeneratorParams::default()
                 };
                 
                 let state = generate_scenario(params).expect("Generation should succeed");
@@ -1125,9 +1368,9 @@ mod tests {
         // Test that scenarios respect minimum legal actions filter
         let params = GeneratorParams {
             target_game_stage: GameStage::Mid,
-            target_round_stage: None,
             seed: 70000,
             policy_mix: PolicyMix::AllGreedy,
+            ..GeneratorParams::default()
         };
         
         let filter_config = FilterConfig {
@@ -1137,6 +1380,7 @@ mod tests {
             max_floor_ratio: 0.5,
             min_value_gap: None,
             max_value_gap: None,
+            drill_target: None,
         };
         
         // This should either succeed with a state meeting criteria or fail gracefully
@@ -0,0 +1,176 @@
+use crate::model::{PlayerBoard, State, TileColor, TileMultiset, TileMultisetExt};
+use super::constants::{ALL_COLORS, TILES_PER_COLOR, TOTAL_TILES};
+use super::refill::draw_random_tile_from_bag;
+use super::wall_utils::get_wall_color;
+use rand::Rng;
+use std::collections::HashMap;
+
+/// Hide the bag contents of a state
+///
+/// At the table the bag is opaque: a player can count how many tiles are
+/// left in it but not which colors. The returned state has an empty `bag`
+/// and `hidden_bag` set; the bag size is implied by tile conservation (see
+/// `hidden_bag_total`). Everything else, including the lid, is unchanged.
+pub fn redact_bag(state: &State) -> State {
+    let mut redacted = state.clone();
+    redacted.bag.clear();
+    redacted.hidden_bag = true;
+    redacted
+}
+
+/// Number of tiles in the bag, counting a redacted bag by conservation
+///
+/// For a redacted state this is every tile not visible elsewhere; otherwise
+/// it is simply the size of `bag`.
+pub fn hidden_bag_total(state: &State) -> u32 {
+    if !state.hidden_bag {
+        return state.bag.total();
+    }
+
+    let visible: u32 = count_tiles_outside_bag(
+        &state.lid,
+        &state.factories,
+        &state.center.tiles,
+        &state.players,
+    ).values().sum();
+    (TOTAL_TILES as u32).saturating_sub(visible)
+}
+
+/// Sample concrete bag contents for a redacted state
+///
+/// Models a player who knows the bag size but has not tracked which colors
+/// went to the lid: the unseen pool is 20 per color minus the tiles on the
+/// table and player boards. A bag of `hidden_bag_total` tiles is drawn
+/// uniformly from that pool and the remainder becomes the lid, so the lid
+/// keeps its size and the sample satisfies tile conservation.
+///
+/// States whose bag is not hidden are returned unchanged.
+pub fn sample_hidden_bag<R: Rng>(state: &State, rng: &mut R) -> State {
+    let mut sampled = state.clone();
+    if !state.hidden_bag {
+        return sampled;
+    }
+
+    let bag_total = hidden_bag_total(state);
+
+    let mut on_board = count_tiles_outside_bag(
+        &state.lid,
+        &state.factories,
+        &state.center.tiles,
+        &state.players,
+    );
+    for (&color, &count) in &state.lid {
+        if let Some(entry) = on_board.get_mut(&color) {
+            *entry -= count as u32;
+        }
+    }
+
+    let mut pool: TileMultiset = HashMap::new();
+    for color in ALL_COLORS {
+        let used = on_board.get(&color).copied().unwrap_or(0);
+        pool.add(color, (TILES_PER_COLOR as u32).saturating_sub(used) as u8);
+    }
+
+    let mut bag: TileMultiset = HashMap::new();
+    for _ in 0..bag_total {
+        match draw_random_tile_from_bag(&mut pool, rng) {
+            Some(color) => bag.add(color, 1),
+            None => break,
+        }
+    }
+
+    sampled.bag = bag;
+    sampled.lid = pool;
+    sampled.hidden_bag = false;
+    sampled
+}
+
+/// Rebuild a state from `State::to_lean_json`
+///
+/// The unseen tiles (20 per color minus those on the table and boards) are
+/// split into a bag and lid of the recorded sizes, filling the bag in
+/// canonical color order. The split is a placeholder: totals and tile
+/// conservation are right, but which colors sit in the bag versus the lid
+/// is not, so refilling from the result does not reproduce the real game.
+///
+/// # Errors
+///
+/// Returns an error if the JSON is not a lean state.
+pub fn from_lean_json(json: &str) -> Result<State, serde_json::Error> {
+    let mut value: serde_json::Value = serde_json::from_str(json)?;
+    let take_total = |value: &mut serde_json::Value, key: &str| {
+        value.as_object_mut()
+            .and_then(|fields| fields.remove(key))
+            .and_then(|total| total.as_u64())
+            .unwrap_or(0) as u32
+    };
+    let bag_total = take_total(&mut value, "bag_total");
+    take_total(&mut value, "lid_total");
+    if let Some(fields) = value.as_object_mut() {
+        fields.insert("bag".to_string(), serde_json::json!({}));
+        fields.insert("lid".to_string(), serde_json::json!({}));
+    }
+    let mut state: State = serde_json::from_value(value)?;
+
+    let in_play = count_tiles_outside_bag(
+        &state.lid,
+        &state.factories,
+        &state.center.tiles,
+        &state.players,
+    );
+    let mut remaining_for_bag = bag_total;
+    for color in ALL_COLORS {
+        let used = in_play.get(&color).copied().unwrap_or(0);
+        let unseen = (TILES_PER_COLOR as u32).saturating_sub(used);
+        let in_bag = unseen.min(remaining_for_bag);
+        remaining_for_bag -= in_bag;
+        state.bag.add(color, in_bag as u8);
+        state.lid.add(color, (unseen - in_bag) as u8);
+    }
+
+    Ok(state)
+}
+
+/// Count tiles of each color in the lid, on the table and on player boards
+///
+/// Takes the parts rather than a `State` so a `PartialState` being imported
+/// can be counted too.
+pub(crate) fn count_tiles_outside_bag(
+    lid: &TileMultiset,
+    factories: &[TileMultiset],
+    center: &TileMultiset,
+    players: &[PlayerBoard],
+) -> HashMap<TileColor, u32> {
+    let mut counts: HashMap<TileColor, u32> = HashMap::new();
+
+    let table = std::iter::once(lid)
+        .chain(factories.iter())
+        .chain(std::iter::once(center));
+    for tiles in table {
+        for (&color, &count) in tiles {
+            *counts.entry(color).or_insert(0) += count as u32;
+        }
+    }
+
+    for player in players {
+        for line in &player.pattern_lines {
+            if let Some(color) = line.color {
+                *counts.entry(color).or_insert(0) += line.count_filled as u32;
+            }
+        }
+
+        for (row, wall_row) in player.wall.iter().enumerate() {
+            for (col, &filled) in wall_row.iter().enumerate() {
+                if filled {
+                    *counts.entry(get_wall_color(row, col)).or_insert(0) += 1;
+                }
+            }
+        }
+
+        for &color in &player.floor_line.tiles {
+            *counts.entry(color).or_insert(0) += 1;
+        }
+    }
+
+    counts
+}
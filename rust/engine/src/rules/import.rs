@@ -0,0 +1,334 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::model::{
+    State, TileColor, TileMultiset, TileMultisetExt, CenterArea, PlayerBoard, RoundStage, GameStage,
+};
+use super::constants::{ALL_COLORS, TILES_PER_COLOR, FACTORY_COUNT_2P, TILES_PER_FACTORY};
+use super::generator::{compute_round_stage, compute_game_stage};
+use super::hidden::{count_tiles_outside_bag, hidden_bag_total};
+use super::invariants::check_tile_conservation;
+
+/// A position transcribed from a physical game
+///
+/// Mirrors `State` but leaves out the bag, which is almost never visible at
+/// the table. The lid is optional and defaults to empty. Any `bag` field in
+/// the incoming JSON is ignored: the bag is always inferred so that the
+/// imported position satisfies tile conservation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PartialState {
+    #[serde(default)]
+    pub active_player_id: u8,
+    #[serde(default = "default_round_number")]
+    pub round_number: u8,
+    #[serde(default, serialize_with = "crate::model::serialize_tile_multiset")]
+    pub lid: TileMultiset,
+    #[serde(serialize_with = "crate::model::serialize_tile_multisets")]
+    pub factories: Vec<TileMultiset>,
+    pub center: CenterArea,
+    pub players: [PlayerBoard; 2],
+}
+
+fn default_round_number() -> u8 {
+    1
+}
+
+/// Errors that prevent a position from being imported
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportError {
+    /// Active player is not 0 or 1
+    InvalidPlayer(u8),
+    /// Wrong number of factories for a 2-player game
+    InvalidFactoryCount(usize),
+    /// A factory holds more than 4 tiles
+    FactoryOverfilled { factory: usize, count: u32 },
+    /// A pattern line breaks its invariants
+    InvalidPatternLine { player: usize, row: usize, reason: String },
+    /// More than 20 tiles of a color are accounted for outside the bag
+    ColorOverflow { color: TileColor, count: u32 },
+}
+
+impl std::fmt::Display for ImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportError::InvalidPlayer(id) => write!(f, "Invalid active player ID: {}", id),
+            ImportError::InvalidFactoryCount(n) => write!(
+                f, "Expected {} factories, found {}", FACTORY_COUNT_2P, n
+            ),
+            ImportError::FactoryOverfilled { factory, count } => write!(
+                f, "Factory {} holds {} tiles (max {})", factory, count, TILES_PER_FACTORY
+            ),
+            ImportError::InvalidPatternLine { player, row, reason } => write!(
+                f, "Player {} pattern line {} is invalid: {}", player, row, reason
+            ),
+            ImportError::ColorOverflow { color, count } => write!(
+                f, "{} {:?} tiles in play (max {})", count, color, TILES_PER_COLOR
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+/// Problems with a hand-authored puzzle position
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthoringError {
+    /// Tile totals do not add up (see `check_tile_conservation`)
+    Conservation(String),
+    /// `draft_phase_progress` disagrees with the tiles left on the table
+    RoundStageMismatch { declared: RoundStage, actual: RoundStage },
+    /// `scenario_game_stage` disagrees with the walls
+    GameStageMismatch { declared: GameStage, actual: GameStage },
+    /// Bag and lid together cannot refill every factory
+    InsufficientSupply { available: u32, needed: u32 },
+}
+
+impl std::fmt::Display for AuthoringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthoringError::Conservation(msg) => write!(f, "{}", msg),
+            AuthoringError::RoundStageMismatch { declared, actual } => write!(
+                f, "Round stage declared {:?} but the table is at {:?}", declared, actual
+            ),
+            AuthoringError::GameStageMismatch { declared, actual } => write!(
+                f, "Game stage declared {:?} but the walls are at {:?}", declared, actual
+            ),
+            AuthoringError::InsufficientSupply { available, needed } => write!(
+                f, "Bag and lid hold {} tiles, a full refill needs {}", available, needed
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AuthoringError {}
+
+/// Import a transcribed position, inferring the bag
+///
+/// Unlike validation, which rejects any state whose tile totals are off, this
+/// repairs the supply: the bag receives every tile not accounted for by the
+/// lid, table and player boards (20 per color minus tiles in play). Everything
+/// else must already be consistent.
+///
+/// # Errors
+///
+/// Returns `ImportError` if the board itself is malformed (bad player ID,
+/// factory layout, pattern line contents) or more than 20 tiles of a color
+/// are in play, in which case no bag can restore conservation.
+pub fn import_position(partial: PartialState) -> Result<State, ImportError> {
+    if partial.active_player_id > 1 {
+        return Err(ImportError::InvalidPlayer(partial.active_player_id));
+    }
+
+    if partial.factories.len() != FACTORY_COUNT_2P {
+        return Err(ImportError::InvalidFactoryCount(partial.factories.len()));
+    }
+
+    for (factory_idx, factory) in partial.factories.iter().enumerate() {
+        let count = factory.total();
+        if count > TILES_PER_FACTORY as u32 {
+            return Err(ImportError::FactoryOverfilled { factory: factory_idx, count });
+        }
+    }
+
+    for (player_idx, player) in partial.players.iter().enumerate() {
+        for (row, line) in player.pattern_lines.iter().enumerate() {
+            let reason = if line.capacity as usize != row + 1 {
+                Some(format!("capacity {} (expected {})", line.capacity, row + 1))
+            } else if line.count_filled > line.capacity {
+                Some(format!("{} tiles exceed capacity {}", line.count_filled, line.capacity))
+            } else if line.count_filled > 0 && line.color.is_none() {
+                Some("tiles present without a color".to_string())
+            } else if line.count_filled == 0 && line.color.is_some() {
+                Some("color set on an empty line".to_string())
+            } else {
+                None
+            };
+
+            if let Some(reason) = reason {
+                return Err(ImportError::InvalidPatternLine { player: player_idx, row, reason });
+            }
+        }
+    }
+
+    let in_play = count_tiles_outside_bag(
+        &partial.lid,
+        &partial.factories,
+        &partial.center.tiles,
+        &partial.players,
+    );
+    let mut bag: TileMultiset = HashMap::new();
+    for color in ALL_COLORS {
+        let count = in_play.get(&color).copied().unwrap_or(0);
+        if count > TILES_PER_COLOR as u32 {
+            return Err(ImportError::ColorOverflow { color, count });
+        }
+        bag.add(color, (TILES_PER_COLOR as u32 - count) as u8);
+    }
+
+    let mut state = State {
+        state_version: crate::version::STATE_VERSION,
+        ruleset_id: crate::version::RULESET_ID.to_string(),
+        scenario_seed: None,
+        active_player_id: partial.active_player_id,
+        round_number: partial.round_number,
+        draft_phase_progress: crate::model::RoundStage::Start,
+        scenario_game_stage: None,
+        scenario_theme: None,
+        bag,
+        lid: partial.lid,
+        factories: partial.factories,
+        center: partial.center,
+        players: partial.players,
+        hidden_bag: false,
+        is_terminal: false,
+        drafted_counts: Default::default(),
+    };
+    state.draft_phase_progress = compute_round_stage(&state);
+
+    debug_assert!(check_tile_conservation(&state).is_ok());
+
+    Ok(state)
+}
+
+/// Check a hand-authored position before using it as a puzzle
+///
+/// Authors set `bag` and `lid` explicitly to predetermine the next refill,
+/// so unlike `import_position` nothing is inferred. The position must
+/// conserve tiles, any declared stages must match the board, and the bag
+/// and lid together must hold enough tiles to refill every factory.
+///
+/// # Errors
+///
+/// Returns the first `AuthoringError` found, in the order listed above.
+pub fn validate_authored_state(state: &State) -> Result<(), AuthoringError> {
+    check_tile_conservation(state).map_err(AuthoringError::Conservation)?;
+
+    let actual = compute_round_stage(state);
+    if state.draft_phase_progress != actual {
+        return Err(AuthoringError::RoundStageMismatch {
+            declared: state.draft_phase_progress,
+            actual,
+        });
+    }
+
+    if let Some(declared) = state.scenario_game_stage {
+        let actual = compute_game_stage(state);
+        if declared != actual {
+            return Err(AuthoringError::GameStageMismatch { declared, actual });
+        }
+    }
+
+    let available = hidden_bag_total(state) + state.lid.total();
+    let needed = (state.factories.len() * TILES_PER_FACTORY) as u32;
+    if available < needed {
+        return Err(AuthoringError::InsufficientSupply { available, needed });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_partial() -> PartialState {
+        PartialState {
+            active_player_id: 0,
+            round_number: 1,
+            lid: HashMap::new(),
+            factories: vec![HashMap::new(); FACTORY_COUNT_2P],
+            center: CenterArea {
+                tiles: HashMap::new(),
+                has_first_player_token: true,
+            },
+            players: [PlayerBoard::new(), PlayerBoard::new()],
+        }
+    }
+
+    #[test]
+    fn test_import_without_bag_achieves_conservation() {
+        let mut partial = empty_partial();
+        partial.round_number = 3;
+        partial.factories[0].add(TileColor::Blue, 2);
+        partial.factories[0].add(TileColor::Red, 2);
+        partial.center.tiles.add(TileColor::Yellow, 3);
+        partial.lid.add(TileColor::Black, 4);
+
+        partial.players[0].wall[0][0] = true; // Blue
+        partial.players[0].pattern_lines[2].color = Some(TileColor::Red);
+        partial.players[0].pattern_lines[2].count_filled = 2;
+        partial.players[1].floor_line.tiles.push(TileColor::Blue);
+
+        let state = import_position(partial).unwrap();
+
+        assert!(check_tile_conservation(&state).is_ok());
+        assert_eq!(state.bag.count(TileColor::Blue), 16);
+        assert_eq!(state.bag.count(TileColor::Red), 16);
+        assert_eq!(state.bag.count(TileColor::Yellow), 17);
+        assert_eq!(state.bag.count(TileColor::Black), 16);
+        assert_eq!(state.bag.count(TileColor::White), 20);
+        assert_eq!(state.round_number, 3);
+    }
+
+    #[test]
+    fn test_import_ignores_inconsistent_bag_in_json() {
+        let mut json = serde_json::to_value(empty_partial()).unwrap();
+        json["bag"] = serde_json::json!({"Red": 3});
+
+        let partial: PartialState = serde_json::from_value(json).unwrap();
+        let state = import_position(partial).unwrap();
+
+        assert!(check_tile_conservation(&state).is_ok());
+        assert_eq!(state.bag.count(TileColor::Red), 20);
+    }
+
+    #[test]
+    fn test_import_rejects_color_overflow() {
+        let mut partial = empty_partial();
+        partial.lid.add(TileColor::White, 21);
+
+        assert_eq!(
+            import_position(partial),
+            Err(ImportError::ColorOverflow { color: TileColor::White, count: 21 })
+        );
+    }
+
+    #[test]
+    fn test_import_rejects_overfilled_pattern_line() {
+        let mut partial = empty_partial();
+        partial.players[1].pattern_lines[0].color = Some(TileColor::Blue);
+        partial.players[1].pattern_lines[0].count_filled = 2;
+
+        assert!(matches!(
+            import_position(partial),
+            Err(ImportError::InvalidPatternLine { player: 1, row: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_authored_state_with_tiny_bag_flags_insufficient_supply() {
+        let mut state = State::new_test_state();
+        for (factory, color) in state.factories.iter_mut().zip(ALL_COLORS) {
+            factory.add(color, TILES_PER_FACTORY as u8);
+        }
+        state.bag.add(TileColor::Blue, 80);
+        state.draft_phase_progress = compute_round_stage(&state);
+        assert_eq!(validate_authored_state(&state), Ok(()));
+
+        // Late game: walls full, floors full, only 3 + 2 tiles left to draw
+        state.bag.clear();
+        state.bag.add(TileColor::Yellow, 3);
+        state.lid.add(TileColor::White, 2);
+        state.center.tiles.add(TileColor::Red, 11);
+        for player in &mut state.players {
+            player.wall = [[true; 5]; 5];
+            player.floor_line.tiles = vec![TileColor::Black; 7];
+        }
+        state.draft_phase_progress = compute_round_stage(&state);
+
+        assert_eq!(
+            validate_authored_state(&state),
+            Err(AuthoringError::InsufficientSupply { available: 5, needed: 20 })
+        );
+    }
+}
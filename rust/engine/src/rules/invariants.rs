@@ -1,5 +1,7 @@
-use crate::State;
-use super::constants::TOTAL_TILES;
+use crate::{State, PlayerBoard, TileMultisetExt};
+use super::constants::{TOTAL_TILES, TILES_PER_FACTORY, FLOOR_LINE_SLOTS};
+use super::floor::floor_slots_used;
+use super::wall_utils::get_wall_column_for_color;
 
 /// Check that the total number of tiles in the game equals TOTAL_TILES (100)
 ///
@@ -10,7 +12,9 @@ use super::constants::TOTAL_TILES;
 ///
 /// # Returns
 ///
-/// Ok(()) if conservation holds, Err(message) otherwise
+/// Ok(()) if conservation holds, Err(message) otherwise. For a state with a
+/// redacted bag (`hidden_bag`), conservation holds if the visible tiles do not
+/// exceed TOTAL_TILES, the rest being implicitly in the bag.
 ///
 /// # Example
 ///
@@ -27,6 +31,34 @@ use super::constants::TOTAL_TILES;
 /// assert!(check_tile_conservation(&state).is_ok());
 /// ```
 pub fn check_tile_conservation(state: &State) -> Result<(), String> {
+    let total = count_tiles(state);
+    
+    // A redacted bag holds whatever is not visible elsewhere
+    if state.hidden_bag {
+        if !state.bag.is_empty() || total > TOTAL_TILES as u32 {
+            return Err(format!(
+                "Tile conservation violated: hidden bag with {} visible tiles (max {})",
+                total, TOTAL_TILES
+            ));
+        }
+        return Ok(());
+    }
+    
+    if total != TOTAL_TILES as u32 {
+        return Err(format!(
+            "Tile conservation violated: expected {}, found {}",
+            TOTAL_TILES, total
+        ));
+    }
+    
+    Ok(())
+}
+
+/// Count every tile in the state, wherever it is
+///
+/// Covers the bag, lid, factories, center and player boards (pattern lines,
+/// wall, floor line). A redacted bag is empty, so only visible tiles count.
+pub(crate) fn count_tiles(state: &State) -> u32 {
     let mut total = 0u32;
     
     // Count tiles in bag
@@ -71,10 +103,126 @@ pub fn check_tile_conservation(state: &State) -> Result<(), String> {
         total += player.floor_line.tiles.len() as u32;
     }
     
-    if total != TOTAL_TILES as u32 {
+    total
+}
+
+/// Check that no pattern line is filling toward an occupied wall cell
+///
+/// Placement rules forbid starting a line in a color its wall row already
+/// has, so such a line can only come from a hand-built or imported state.
+/// It could never resolve: the tile would land on an occupied cell.
+///
+/// # Returns
+///
+/// Ok(()) if every started pattern line targets an empty wall cell,
+/// Err(message) naming the first line that does not
+///
+/// # Example
+///
+/// ```
+/// use engine::{PlayerBoard, TileColor, check_wall_pattern_coherence};
+///
+/// let mut player = PlayerBoard::new();
+/// player.pattern_lines[2].color = Some(TileColor::Blue);
+/// player.pattern_lines[2].count_filled = 1;
+/// assert!(check_wall_pattern_coherence(&player).is_ok());
+/// ```
+pub fn check_wall_pattern_coherence(player: &PlayerBoard) -> Result<(), String> {
+    for (row, pattern_line) in player.pattern_lines.iter().enumerate() {
+        let Some(color) = pattern_line.color else {
+            continue;
+        };
+        if pattern_line.count_filled == 0 {
+            continue;
+        }
+        
+        let col = get_wall_column_for_color(row, color);
+        if player.wall[row][col] {
+            return Err(format!(
+                "Wall/pattern incoherence: pattern line {} holds {:?} but wall [{}, {}] is already filled",
+                row, color, row, col
+            ));
+        }
+    }
+    
+    Ok(())
+}
+
+/// Check that no factory holds more than 4 tiles
+///
+/// Refills deal at most 4 tiles per factory and drafting only removes
+/// tiles, so an over-full factory means a hand-built or corrupted state.
+///
+/// # Returns
+///
+/// Ok(()) if every factory is within capacity, Err(message) naming the
+/// first one that is not
+pub fn check_factory_capacity(state: &State) -> Result<(), String> {
+    for (idx, factory) in state.factories.iter().enumerate() {
+        let count = factory.total();
+        if count > TILES_PER_FACTORY as u32 {
+            return Err(format!(
+                "Factory capacity violated: factory {} holds {} tiles (max {})",
+                idx, count, TILES_PER_FACTORY
+            ));
+        }
+    }
+    
+    Ok(())
+}
+
+/// Check that no floor line holds more than 7 slots' worth
+///
+/// Tiles that do not fit on a full floor go to the lid, and the token bumps
+/// a tile off a full floor, so more than 7 slots in use (counting the
+/// token) means a hand-built or corrupted state.
+///
+/// # Returns
+///
+/// Ok(()) if every floor line is within capacity, Err(message) naming the
+/// first player whose floor is not
+pub fn check_floor_capacity(state: &State) -> Result<(), String> {
+    for (idx, player) in state.players.iter().enumerate() {
+        let used = floor_slots_used(&player.floor_line);
+        if used > FLOOR_LINE_SLOTS {
+            return Err(format!(
+                "Floor capacity violated: player {} uses {} floor slots (max {})",
+                idx, used, FLOOR_LINE_SLOTS
+            ));
+        }
+    }
+    
+    Ok(())
+}
+
+/// Check that at most one place holds the first-player token
+///
+/// The token sits in the center until someone drafts from it, then on that
+/// player's floor until end-of-round resolution returns it. A token claimed
+/// by both the center and a floor (or by both floors) can only come from a
+/// hand-built or imported state. `resolve_end_of_round` treats a floor claim
+/// as the real one: that player pays for the token and starts next round.
+///
+/// # Returns
+///
+/// Ok(()) if the token has at most one holder, Err(message) listing them
+/// otherwise
+pub fn check_first_player_token_unique(state: &State) -> Result<(), String> {
+    let mut holders = Vec::new();
+    if state.center.has_first_player_token {
+        holders.push("center".to_string());
+    }
+    for (idx, player) in state.players.iter().enumerate() {
+        if player.floor_line.has_first_player_token {
+            holders.push(format!("player {} floor", idx));
+        }
+    }
+    
+    if holders.len() > 1 {
         return Err(format!(
-            "Tile conservation violated: expected {}, found {}",
-            TOTAL_TILES, total
+            "First-player token held in {} places: {}",
+            holders.len(),
+            holders.join(", ")
         ));
     }
     
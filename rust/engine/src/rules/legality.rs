@@ -1,6 +1,9 @@
-use crate::{State, DraftAction, ActionSource, Destination, PlayerBoard, TileColor};
+use crate::{State, DraftAction, ActionSource, Destination, PlayerBoard, TileColor, TileMultiset, TileMultisetExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use super::wall_utils::get_wall_column_for_color;
-use super::constants::ALL_COLORS;
+use super::constants::{ALL_COLORS, FLOOR_PENALTIES, TILES_PER_COLOR};
+use super::floor::floor_slots_used;
 
 /// List all legal draft actions for the given player in the given state
 ///
@@ -11,6 +14,11 @@ use super::constants::ALL_COLORS;
 /// - Pattern line constraints (capacity, color consistency, wall conflicts)
 ///
 /// The floor destination is always legal, ensuring at least one action per color/source.
+/// A terminal state (`is_terminal`) has no legal actions, and neither does
+/// a `player_id` that names no seat.
+///
+/// Actions are returned in canonical order (see `DraftAction`),
+/// so callers may rely on the order being stable across calls and builds.
 ///
 /// # Arguments
 ///
@@ -19,7 +27,7 @@ use super::constants::ALL_COLORS;
 ///
 /// # Returns
 ///
-/// A vector of all legal draft actions for the player
+/// A vector of all legal draft actions for the player, in canonical order
 ///
 /// # Example
 ///
@@ -32,13 +40,21 @@ use super::constants::ALL_COLORS;
 /// ```
 pub fn list_legal_actions(state: &State, player_id: u8) -> Vec<DraftAction> {
     let mut actions = Vec::new();
-    let player = &state.players[player_id as usize];
     
-    // Check all factories
+    // Nothing is legal once the game has ended
+    if state.is_terminal {
+        return actions;
+    }
+    
+    let Some(player) = state.players.get(player_id as usize) else {
+        return actions;
+    };
+    
+    // Emit in canonical order: factories by index, then center; within a
+    // source, colors in canonical order; within a color, rows then floor
     for (factory_idx, factory) in state.factories.iter().enumerate() {
         for &color in &ALL_COLORS {
-            let count = factory.get(&color).copied().unwrap_or(0);
-            if count > 0 {
+            if factory.count(color) > 0 {
                 // Try placing in each pattern line
                 for row in 0..5 {
                     if can_place_in_pattern_line(player, row, color) {
@@ -62,8 +78,7 @@ pub fn list_legal_actions(state: &State, player_id: u8) -> Vec<DraftAction> {
     
     // Check center
     for &color in &ALL_COLORS {
-        let count = state.center.tiles.get(&color).copied().unwrap_or(0);
-        if count > 0 {
+        if state.center.tiles.count(color) > 0 {
             // Try placing in each pattern line
             for row in 0..5 {
                 if can_place_in_pattern_line(player, row, color) {
@@ -128,3 +143,497 @@ pub(crate) fn can_place_in_pattern_line(player: &PlayerBoard, row: usize, color:
     
     true
 }
+
+/// Outcome of placing tiles into a pattern line, before any state change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PlacementPreview {
+    /// Tiles that fit in the pattern line
+    pub placed: u8,
+    /// Tiles that spill onto the floor line
+    pub overflow: u8,
+    /// Whether the pattern line is full after placement
+    pub completes_line: bool,
+}
+
+/// Preview placing `tile_count` tiles of `color` into pattern line `row`
+///
+/// Mirrors the overflow rule in `apply_action`: tiles beyond the line's
+/// remaining capacity go to the floor.
+///
+/// # Returns
+///
+/// `None` if the placement is illegal, otherwise the resulting split
+pub fn preview_placement(
+    player: &PlayerBoard,
+    row: usize,
+    color: TileColor,
+    tile_count: u8,
+) -> Option<PlacementPreview> {
+    if !can_place_in_pattern_line(player, row, color) {
+        return None;
+    }
+    
+    let pattern_line = &player.pattern_lines[row];
+    let space = pattern_line.capacity - pattern_line.count_filled;
+    let placed = tile_count.min(space);
+    
+    Some(PlacementPreview {
+        placed,
+        overflow: tile_count - placed,
+        completes_line: tile_count >= space,
+    })
+}
+
+/// Why an action is or is not legal for a player
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum PlacementLegality {
+    /// The action can be applied
+    Legal,
+    /// Factory index is out of bounds
+    InvalidSource { factory: usize },
+    /// Source has no tiles of the requested color
+    SourceEmpty { color: TileColor },
+    /// Pattern line index is out of bounds
+    InvalidRow { row: usize },
+    /// Pattern line is already full
+    LineFull { row: usize },
+    /// Pattern line already holds a different color
+    ColorMismatch { row: usize, existing: TileColor, attempted: TileColor },
+    /// The wall row already has this color
+    WallConflict { row: usize, col: usize, color: TileColor },
+    /// The player does not exist
+    InvalidPlayer { player_id: u8 },
+    /// The game has ended, so no action is legal
+    GameOver,
+}
+
+impl PlacementLegality {
+    /// Whether the action can be applied
+    pub fn is_legal(&self) -> bool {
+        matches!(self, PlacementLegality::Legal)
+    }
+}
+
+impl std::fmt::Display for PlacementLegality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlacementLegality::Legal => write!(f, "This move is legal"),
+            PlacementLegality::InvalidSource { factory } => write!(
+                f, "Factory {} does not exist", factory + 1
+            ),
+            PlacementLegality::SourceEmpty { color } => write!(
+                f, "That source has no {} tiles", color.label()
+            ),
+            PlacementLegality::InvalidRow { row } => write!(
+                f, "Pattern line {} does not exist", row + 1
+            ),
+            PlacementLegality::LineFull { row } => write!(
+                f, "Pattern line {} is already full", row + 1
+            ),
+            PlacementLegality::ColorMismatch { row, existing, attempted } => write!(
+                f,
+                "Pattern line {} already holds {}, so it cannot take {}",
+                row + 1, existing.label(), attempted.label()
+            ),
+            PlacementLegality::WallConflict { row, color, .. } => write!(
+                f,
+                "Wall row {} already has a {} tile, so pattern line {} cannot take {}",
+                row + 1, color.label(), row + 1, color.label()
+            ),
+            PlacementLegality::InvalidPlayer { player_id } => write!(
+                f, "Player {} does not exist", player_id
+            ),
+            PlacementLegality::GameOver => write!(f, "The game is over"),
+        }
+    }
+}
+
+/// Explain whether `player_id` may take `action`, and if not, why
+///
+/// Checks the same rules as `list_legal_actions` (an action is legal exactly
+/// when it appears in that list) but names the first rule that fails, for
+/// showing to a student. Turn order is not checked.
+///
+/// # Panics
+///
+/// Panics if `player_id` is not 0 or 1.
+pub fn explain_placement(state: &State, player_id: u8, action: &DraftAction) -> PlacementLegality {
+    let available = match action.source {
+        ActionSource::Factory(idx) => match state.factories.get(idx) {
+            Some(factory) => factory.count(action.color),
+            None => return PlacementLegality::InvalidSource { factory: idx },
+        },
+        ActionSource::Center => state.center.tiles.count(action.color),
+    };
+    if available == 0 {
+        return PlacementLegality::SourceEmpty { color: action.color };
+    }
+    
+    let row = match action.destination {
+        Destination::PatternLine(row) => row,
+        Destination::Floor => return PlacementLegality::Legal,
+    };
+    let player = &state.players[player_id as usize];
+    let Some(pattern_line) = player.pattern_lines.get(row) else {
+        return PlacementLegality::InvalidRow { row };
+    };
+    
+    if pattern_line.count_filled == pattern_line.capacity {
+        return PlacementLegality::LineFull { row };
+    }
+    
+    if pattern_line.count_filled > 0 {
+        if let Some(existing) = pattern_line.color {
+            if existing != action.color {
+                return PlacementLegality::ColorMismatch {
+                    row,
+                    existing,
+                    attempted: action.color,
+                };
+            }
+        }
+    }
+    
+    let col = get_wall_column_for_color(row, action.color);
+    if player.wall[row][col] {
+        return PlacementLegality::WallConflict { row, col, color: action.color };
+    }
+    
+    PlacementLegality::Legal
+}
+
+/// Check whether `player_id` may take `action`, without listing every move
+///
+/// Agrees with `list_legal_actions(state, player_id).contains(action)`,
+/// but costs one `explain_placement` instead of enumerating all actions.
+/// Unlike `explain_placement`, it also rejects a missing player and a
+/// finished game rather than panicking or answering `Legal`. Turn order is
+/// not checked.
+///
+/// # Errors
+///
+/// Returns the first rule the action breaks.
+pub fn is_action_legal(
+    state: &State,
+    player_id: u8,
+    action: &DraftAction,
+) -> Result<(), PlacementLegality> {
+    if state.players.get(player_id as usize).is_none() {
+        return Err(PlacementLegality::InvalidPlayer { player_id });
+    }
+    if state.is_terminal {
+        return Err(PlacementLegality::GameOver);
+    }
+    match explain_placement(state, player_id, action) {
+        PlacementLegality::Legal => Ok(()),
+        illegal => Err(illegal),
+    }
+}
+
+/// Colors that can be taken from some factory or the center this turn
+///
+/// Cheaper than `list_legal_actions` when only the palette matters: every
+/// color on the table is draftable because the floor always accepts it.
+///
+/// # Example
+///
+/// ```
+/// use engine::{State, TileColor, draftable_colors};
+///
+/// let mut state = State::new_test_state();
+/// state.factories[2].insert(TileColor::White, 4);
+/// assert!(draftable_colors(&state).contains(&TileColor::White));
+/// ```
+pub fn draftable_colors(state: &State) -> HashSet<TileColor> {
+    state.factories
+        .iter()
+        .chain(std::iter::once(&state.center.tiles))
+        .flat_map(|tiles| tiles.iter())
+        .filter(|(_, &count)| count > 0)
+        .map(|(&color, _)| color)
+        .collect()
+}
+
+/// Draftable tiles on the table (factories and center), by color
+///
+/// Counts only what can be taken this round; the bag and lid are left out.
+/// Colors with no tiles on the table have no entry.
+///
+/// # Example
+///
+/// ```
+/// use engine::{State, TileColor, table_color_histogram};
+///
+/// let mut state = State::new_test_state();
+/// state.factories[0].insert(TileColor::Blue, 3);
+/// state.center.tiles.insert(TileColor::Blue, 2);
+/// assert_eq!(table_color_histogram(&state)[&TileColor::Blue], 5);
+/// ```
+pub fn table_color_histogram(state: &State) -> TileMultiset {
+    let mut histogram = TileMultiset::new();
+    for tiles in state.factories.iter().chain(std::iter::once(&state.center.tiles)) {
+        for (&color, &count) in tiles {
+            histogram.add(color, count);
+        }
+    }
+    histogram
+}
+
+/// Number of tiles left in a factory, for rendering its slots
+///
+/// Returns 0 for an empty factory or an out-of-range index.
+pub fn factory_tile_count(state: &State, idx: usize) -> u32 {
+    state.factories
+        .get(idx)
+        .map_or(0, |factory| factory.total())
+}
+
+/// A started pattern line the opponent could finish on their next turn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Threat {
+    /// Pattern line index (0-4)
+    pub row: usize,
+    /// Color already committed to the line
+    pub color: TileColor,
+    /// Tiles still needed to fill the line
+    pub tiles_needed: u8,
+}
+
+/// Tiles still needed to fill a player's pattern line
+pub(crate) fn pattern_line_needs(player: &PlayerBoard, row: usize) -> u8 {
+    let line = &player.pattern_lines[row];
+    line.capacity - line.count_filled
+}
+
+/// Pattern lines `opponent_id` could complete with tiles on the table now
+///
+/// Only lines that already hold tiles are considered, and a line counts as
+/// threatened when a single factory or the center has enough of its color
+/// to fill it in one draft. Returns an empty list if `opponent_id` is not 0
+/// or 1.
+pub fn opponent_threats(state: &State, opponent_id: u8) -> Vec<Threat> {
+    let Some(player) = state.players.get(opponent_id as usize) else {
+        return Vec::new();
+    };
+    let available = draftable_colors(state);
+    
+    (0..player.pattern_lines.len())
+        .filter_map(|row| {
+            let color = player.pattern_lines[row].color?;
+            let tiles_needed = pattern_line_needs(player, row);
+            if tiles_needed == 0 || !available.contains(&color) {
+                return None;
+            }
+            
+            let largest_source = state.factories
+                .iter()
+                .chain(std::iter::once(&state.center.tiles))
+                .map(|tiles| tiles.count(color))
+                .max()
+                .unwrap_or(0);
+            (largest_source >= tiles_needed).then_some(Threat { row, color, tiles_needed })
+        })
+        .collect()
+}
+
+/// Started pattern lines that can never be finished
+///
+/// A line is unfinishable when fewer tiles of its color remain uncommitted
+/// than it still needs. Uncommitted means anywhere but a wall or a pattern
+/// line: the table, bag, lid, and floors (which go to the lid). Counting by
+/// conservation works for a redacted bag too. Tiles waiting in other pattern
+/// lines count as committed, so the result only flags lines that are dead
+/// as the boards stand.
+///
+/// # Panics
+///
+/// Panics if `player_id` is not 0 or 1.
+pub fn unfinishable_lines(state: &State, player_id: u8) -> Vec<usize> {
+    let committed = |color: TileColor| -> u32 {
+        state.players
+            .iter()
+            .map(|board| {
+                let on_wall = board.wall
+                    .iter()
+                    .enumerate()
+                    .filter(|(row, cells)| cells[get_wall_column_for_color(*row, color)])
+                    .count() as u32;
+                let in_lines: u32 = board.pattern_lines
+                    .iter()
+                    .filter(|line| line.color == Some(color))
+                    .map(|line| line.count_filled as u32)
+                    .sum();
+                on_wall + in_lines
+            })
+            .sum()
+    };
+    
+    let player = &state.players[player_id as usize];
+    (0..player.pattern_lines.len())
+        .filter(|&row| {
+            let Some(color) = player.pattern_lines[row].color else {
+                return false;
+            };
+            let needs = pattern_line_needs(player, row) as u32;
+            let remaining = (TILES_PER_COLOR as u32).saturating_sub(committed(color));
+            needs > remaining
+        })
+        .collect()
+}
+
+/// Overflow, in tiles, at which a pattern-line move counts as overcommitting
+pub const TRAP_OVERFLOW_TILES: u8 = 2;
+
+/// Floor penalty, in points, at which a line completion counts as costly
+pub const TRAP_COMPLETION_PENALTY: i32 = 3;
+
+/// Why a position leaves the player no comfortable move
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TrapKind {
+    /// No pattern line can take any color on the table
+    OnlyFloor,
+    /// Every pattern-line move sends at least `TRAP_OVERFLOW_TILES` tiles
+    /// to the floor; `min_overflow` is the smallest overflow on offer
+    Overcommit { min_overflow: u8 },
+    /// Every move that completes a line costs at least
+    /// `TRAP_COMPLETION_PENALTY` points on the floor; `min_penalty` is the
+    /// cheapest of them
+    CostlyCompletions { min_penalty: i32 },
+}
+
+/// Floor points a move would cost, counting overflow and the token
+fn added_floor_penalty(player: &PlayerBoard, added_slots: usize) -> i32 {
+    let used = floor_slots_used(&player.floor_line);
+    -FLOOR_PENALTIES.iter().skip(used).take(added_slots).sum::<i32>()
+}
+
+/// Label positions where every reasonable move hurts, for coaching
+///
+/// Checked in order: no pattern-line move at all (`OnlyFloor`), every
+/// pattern-line move overflowing by `TRAP_OVERFLOW_TILES` or more
+/// (`Overcommit`), then every line completion costing
+/// `TRAP_COMPLETION_PENALTY` or more on the floor (`CostlyCompletions`).
+///
+/// # Returns
+///
+/// `None` when the player has a comfortable move or no legal moves at all
+pub fn is_trap_position(state: &State, player_id: u8) -> Option<TrapKind> {
+    let actions = list_legal_actions(state, player_id);
+    let player = state.players.get(player_id as usize)?;
+    
+    // (overflow, floor penalty, completes line) per pattern-line move
+    let placements: Vec<(u8, i32, bool)> = actions
+        .iter()
+        .filter_map(|action| {
+            let Destination::PatternLine(row) = action.destination else {
+                return None;
+            };
+            let tile_count = source_tile_count(state, action);
+            let token = action.source == ActionSource::Center && state.center.has_first_player_token;
+            let preview = preview_placement(player, row, action.color, tile_count)?;
+            let added_slots = preview.overflow as usize + usize::from(token);
+            Some((
+                preview.overflow,
+                added_floor_penalty(player, added_slots),
+                preview.completes_line,
+            ))
+        })
+        .collect();
+    
+    if actions.is_empty() {
+        return None;
+    }
+    let Some(min_overflow) = placements.iter().map(|&(overflow, _, _)| overflow).min() else {
+        return Some(TrapKind::OnlyFloor);
+    };
+    if min_overflow >= TRAP_OVERFLOW_TILES {
+        return Some(TrapKind::Overcommit { min_overflow });
+    }
+    
+    let min_penalty = placements
+        .iter()
+        .filter(|&&(_, _, completes)| completes)
+        .map(|&(_, penalty, _)| penalty)
+        .min()?;
+    (min_penalty >= TRAP_COMPLETION_PENALTY)
+        .then_some(TrapKind::CostlyCompletions { min_penalty })
+}
+
+/// Find moves that complete a pattern line with nothing going to the floor
+///
+/// A clean completion fills its pattern line exactly, with no overflow. Taking
+/// from the center while the first-player token is still there is excluded,
+/// since the token occupies a floor slot.
+///
+/// # Arguments
+///
+/// * `state` - The current game state
+/// * `player_id` - The player to check (0 or 1)
+///
+/// # Returns
+///
+/// The subset of legal actions that are clean completions
+pub fn find_clean_completions(state: &State, player_id: u8) -> Vec<DraftAction> {
+    list_legal_actions(state, player_id)
+        .into_iter()
+        .filter(|action| is_clean_completion(state, player_id, action))
+        .collect()
+}
+
+/// List legal actions whose tiles all fit in their pattern line
+///
+/// Keeps pattern-line moves with no overflow, for a "safe moves only"
+/// filter. Floor dumps are kept only when `include_floor` is set. Taking the
+/// first-player token is not overflow, so center moves that only add the
+/// token to the floor still count. Canonical order, like
+/// `list_legal_actions`.
+pub fn list_non_overflow_actions(
+    state: &State,
+    player_id: u8,
+    include_floor: bool,
+) -> Vec<DraftAction> {
+    let Some(player) = state.players.get(player_id as usize) else {
+        return Vec::new();
+    };
+    list_legal_actions(state, player_id)
+        .into_iter()
+        .filter(|action| match action.destination {
+            Destination::Floor => include_floor,
+            Destination::PatternLine(row) => {
+                preview_placement(player, row, action.color, source_tile_count(state, action))
+                    .is_some_and(|preview| preview.overflow == 0)
+            }
+        })
+        .collect()
+}
+
+/// Tiles of the action's color in its source
+fn source_tile_count(state: &State, action: &DraftAction) -> u8 {
+    match action.source {
+        ActionSource::Factory(idx) => state.factories[idx].count(action.color),
+        ActionSource::Center => state.center.tiles.count(action.color),
+    }
+}
+
+/// Check whether a single action is a clean completion for `player_id`
+pub(crate) fn is_clean_completion(state: &State, player_id: u8, action: &DraftAction) -> bool {
+    let Destination::PatternLine(row) = action.destination else {
+        return false;
+    };
+    let tile_count = match action.source {
+        ActionSource::Factory(idx) => state.factories[idx].count(action.color),
+        ActionSource::Center => {
+            if state.center.has_first_player_token {
+                return false;
+            }
+            state.center.tiles.count(action.color)
+        }
+    };
+    
+    preview_placement(&state.players[player_id as usize], row, action.color, tile_count)
+        .is_some_and(|preview| preview.completes_line && preview.overflow == 0)
+}
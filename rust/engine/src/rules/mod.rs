@@ -6,6 +6,8 @@ mod invariants;
 mod apply;
 mod resolution;
 mod scoring;
+mod ruleset;
+mod floor;
 mod refill;
 mod end_of_round;
 mod rng;
@@ -15,8 +17,18 @@ mod filters;
 mod rollout;
 mod evaluator;
 mod feedback;
+mod import;
+mod hidden;
+mod theme;
+mod canonical;
+mod snapshot;
+mod report;
+mod describe;
+mod trace;
+mod board_code;
 
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests;
 
 pub use constants::*;
@@ -27,6 +39,8 @@ pub use invariants::*;
 pub use apply::*;
 pub use resolution::*;
 pub use scoring::*;
+pub use ruleset::*;
+pub use floor::*;
 pub use refill::*;
 pub use end_of_round::*;
 pub use rng::*;
@@ -36,3 +50,12 @@ pub use filters::*;
 pub use rollout::*;
 pub use evaluator::*;
 pub use feedback::*;
+pub use import::*;
+pub use hidden::*;
+pub use theme::*;
+pub use canonical::*;
+pub use snapshot::*;
+pub use report::*;
+pub use describe::*;
+pub use trace::*;
+pub use board_code::*;
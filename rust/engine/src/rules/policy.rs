@@ -1,6 +1,10 @@
 use crate::model::{State, DraftAction, Destination};
+use crate::rules::apply::apply_action;
+use crate::rules::legality::list_legal_actions;
+use crate::rules::scoring::projected_round_score;
 use rand::Rng;
 use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
 
 /// Trait for selecting draft actions during scenario generation
 ///
@@ -49,12 +53,29 @@ impl DraftPolicy for RandomPolicy {
 /// 1. Prefer pattern line placements over floor
 /// 2. For pattern lines: prefer rows with more empty spaces
 /// 3. Prefer taking more tiles (maximize acquisition)
-/// 4. Break ties randomly
+/// 4. Break ties randomly, or by canonical action order for
+///    `GreedyPolicy::deterministic()`
 ///
 /// This creates more realistic game states than pure random selection.
-pub struct GreedyPolicy;
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GreedyPolicy {
+    deterministic: bool,
+}
 
 impl GreedyPolicy {
+    /// Greedy policy that breaks ties randomly (the default)
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Greedy policy that never consults the RNG
+    ///
+    /// Ties go to the smallest action in `DraftAction`'s canonical ordering,
+    /// so play-forward is reproducible without a seed.
+    pub fn deterministic() -> Self {
+        Self { deterministic: true }
+    }
+    
     /// Score an action based on greedy heuristics (higher is better)
     fn score_action(state: &State, action: &DraftAction) -> i32 {
         let mut score = 0;
@@ -114,11 +135,205 @@ impl DraftPolicy for GreedyPolicy {
             .map(|(_, action)| *action)
             .collect();
         
+        if self.deterministic {
+            return best_actions.into_iter().min().cloned();
+        }
+        
         // Break ties randomly
         best_actions.choose(rng).map(|&action| action.clone())
     }
 }
 
+/// One-ply lookahead policy that plays for immediate round value
+///
+/// Applies each legal action and scores the result with
+/// `projected_round_score` (wall points from complete lines minus floor
+/// penalties if the round ended now). Ties fall back to the greedy
+/// heuristics, then to random choice.
+///
+/// Stronger than `GreedyPolicy` because it sees adjacency points and
+/// overflow penalties, at the cost of applying every candidate move.
+pub struct LookaheadPolicy;
+
+impl DraftPolicy for LookaheadPolicy {
+    fn select_action<R: Rng>(
+        &self,
+        state: &State,
+        legal_actions: &[DraftAction],
+        rng: &mut R,
+    ) -> Option<DraftAction> {
+        if legal_actions.is_empty() {
+            return None;
+        }
+        
+        let player_id = state.active_player_id;
+        let scored_actions: Vec<((i32, i32), &DraftAction)> = legal_actions
+            .iter()
+            .map(|action| {
+                let projected = apply_action(state, action)
+                    .ok()
+                    .and_then(|next| projected_round_score(&next, player_id).ok())
+                    .unwrap_or(i32::MIN);
+                ((projected, GreedyPolicy::score_action(state, action)), action)
+            })
+            .collect();
+        
+        let max_score = scored_actions.iter().map(|(score, _)| *score).max().unwrap();
+        
+        let best_actions: Vec<&DraftAction> = scored_actions
+            .iter()
+            .filter(|(score, _)| *score == max_score)
+            .map(|(_, action)| *action)
+            .collect();
+        
+        best_actions.choose(rng).map(|&action| action.clone())
+    }
+}
+
+/// Weights for the features greedy play scores a move on
+///
+/// The defaults reproduce `GreedyPolicy`'s heuristics, which ignore
+/// overflow. `ProfilePolicy` plays by a set of weights, and
+/// `fit_greedy_weights` derives them from a player's moves.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct GreedyWeights {
+    /// Per tile taken
+    pub tiles_taken: f32,
+    /// For placing in a pattern line rather than on the floor
+    pub pattern_line: f32,
+    /// Per empty space in the chosen pattern line
+    pub empty_spaces: f32,
+    /// For adding to a line that already holds the color
+    pub continue_line: f32,
+    /// Per tile sent to the floor, by overflow or a floor dump (subtracted)
+    pub floor_avoidance: f32,
+}
+
+impl Default for GreedyWeights {
+    fn default() -> Self {
+        Self {
+            tiles_taken: 10.0,
+            pattern_line: 100.0,
+            empty_spaces: 5.0,
+            continue_line: 15.0,
+            floor_avoidance: 0.0,
+        }
+    }
+}
+
+impl GreedyWeights {
+    /// Score an action's features (higher is better)
+    fn score(&self, features: &[f32; 5]) -> f32 {
+        let weights = [
+            self.tiles_taken,
+            self.pattern_line,
+            self.empty_spaces,
+            self.continue_line,
+            -self.floor_avoidance,
+        ];
+        weights.iter().zip(features).map(|(w, f)| w * f).sum()
+    }
+    
+    /// Shift the weights so `toward` scores higher relative to `away`
+    fn nudge(&mut self, toward: &[f32; 5], away: &[f32; 5]) {
+        let delta = |i: usize| toward[i] - away[i];
+        self.tiles_taken += delta(0);
+        self.pattern_line += delta(1);
+        self.empty_spaces += delta(2);
+        self.continue_line += delta(3);
+        self.floor_avoidance -= delta(4);
+    }
+}
+
+/// Features of an action in the order `GreedyWeights::score` weighs them
+fn greedy_features(state: &State, action: &DraftAction) -> [f32; 5] {
+    let tile_count = count_tiles_in_source(state, action);
+    match action.destination {
+        Destination::PatternLine(row) => {
+            let pattern_line = &state.players[state.active_player_id as usize].pattern_lines[row];
+            let empty_spaces = pattern_line.capacity - pattern_line.count_filled;
+            let continues = pattern_line.count_filled > 0 && pattern_line.color == Some(action.color);
+            [
+                tile_count as f32,
+                1.0,
+                empty_spaces as f32,
+                if continues { 1.0 } else { 0.0 },
+                tile_count.saturating_sub(empty_spaces) as f32,
+            ]
+        }
+        Destination::Floor => [tile_count as f32, 0.0, 0.0, 0.0, tile_count as f32],
+    }
+}
+
+/// Greedy policy with fitted weights, to play like a particular person
+///
+/// Scores each move with `weights` and picks the best, breaking ties
+/// randomly. As a rollout opponent it makes EVs reflect how that person
+/// actually plays rather than how the stock greedy bot does.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProfilePolicy {
+    pub weights: GreedyWeights,
+}
+
+impl DraftPolicy for ProfilePolicy {
+    fn select_action<R: Rng>(
+        &self,
+        state: &State,
+        legal_actions: &[DraftAction],
+        rng: &mut R,
+    ) -> Option<DraftAction> {
+        let scored_actions: Vec<(f32, &DraftAction)> = legal_actions
+            .iter()
+            .map(|action| (self.weights.score(&greedy_features(state, action)), action))
+            .collect();
+        
+        let max_score = scored_actions.iter().map(|(score, _)| *score).fold(f32::MIN, f32::max);
+        
+        let best_actions: Vec<&DraftAction> = scored_actions
+            .iter()
+            .filter(|(score, _)| *score == max_score)
+            .map(|(_, action)| *action)
+            .collect();
+        
+        best_actions.choose(rng).map(|&action| action.clone())
+    }
+}
+
+/// Passes over the history `fit_greedy_weights` makes
+const FIT_EPOCHS: usize = 10;
+
+/// Fit greedy weights to a history of a player's moves
+///
+/// A simple perceptron: starting from the default weights, each move the
+/// weights would not have picked (ties going to the smallest action) pulls
+/// them toward the features of the move played and away from the predicted
+/// one. Entries whose move is not legal in their position are skipped.
+pub fn fit_greedy_weights(history: &[(State, DraftAction)]) -> GreedyWeights {
+    let mut weights = GreedyWeights::default();
+    for _ in 0..FIT_EPOCHS {
+        for (state, played) in history {
+            let legal_actions = list_legal_actions(state, state.active_player_id);
+            if !legal_actions.contains(played) {
+                continue;
+            }
+            let predicted = legal_actions
+                .iter()
+                .map(|action| (weights.score(&greedy_features(state, action)), action))
+                .fold(None, |best: Option<(f32, &DraftAction)>, (score, action)| match best {
+                    Some((best_score, _)) if best_score >= score => best,
+                    _ => Some((score, action)),
+                })
+                .map(|(_, action)| action)
+                .expect("legal_actions contains the played move");
+            if predicted != played {
+                weights.nudge(&greedy_features(state, played), &greedy_features(state, predicted));
+            }
+        }
+    }
+    weights
+}
+
 /// Count how many tiles are being taken in this action
 fn count_tiles_in_source(state: &State, action: &DraftAction) -> u8 {
     match &action.source {
@@ -195,7 +410,7 @@ mod tests {
             },
         ];
         
-        let policy = GreedyPolicy;
+        let policy = GreedyPolicy::new();
         let selected = policy.select_action(&state, &actions, &mut rng).unwrap();
         
         // Should prefer pattern line over floor
@@ -228,7 +443,7 @@ mod tests {
             },
         ];
         
-        let policy = GreedyPolicy;
+        let policy = GreedyPolicy::new();
         let selected = policy.select_action(&state, &actions, &mut rng).unwrap();
         
         // Should prefer taking 3 tiles over 1 tile
@@ -239,12 +454,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_lookahead_policy_avoids_overflow() {
+        let mut state = State::new_test_state();
+        let mut rng = StdRng::seed_from_u64(12345);
+        
+        // 3 Blue: row 0 overflows 2 tiles to the floor, row 2 fits exactly.
+        // Greedy prefers row 4 (most empty spaces); lookahead completes row 2.
+        state.factories[0].insert(TileColor::Blue, 3);
+        for color in crate::rules::ALL_COLORS {
+            state.bag.insert(color, if color == TileColor::Blue { 17 } else { 20 });
+        }
+        let actions: Vec<DraftAction> = [0, 2, 4].iter()
+            .map(|&row| DraftAction {
+                source: ActionSource::Factory(0),
+                color: TileColor::Blue,
+                destination: Destination::PatternLine(row),
+            })
+            .collect();
+        
+        let selected = LookaheadPolicy.select_action(&state, &actions, &mut rng).unwrap();
+        
+        assert_eq!(selected.destination, Destination::PatternLine(2));
+    }
+
+    #[test]
+    fn test_fit_greedy_weights_learns_floor_avoidance() {
+        let mut rng = StdRng::seed_from_u64(2192);
+        
+        // Rows 2-4 hold other colors. Greedy takes the 4 Blue into row 1
+        // and spills 2; this player takes the lone Red instead.
+        let mut state = State::new_test_state();
+        state.factories[0].insert(TileColor::Blue, 4);
+        state.factories[1].insert(TileColor::Red, 1);
+        for row in 2..5 {
+            state.players[0].pattern_lines[row].color = Some(TileColor::Yellow);
+            state.players[0].pattern_lines[row].count_filled = 1;
+        }
+        let played = DraftAction {
+            source: ActionSource::Factory(1),
+            color: TileColor::Red,
+            destination: Destination::PatternLine(1),
+        };
+        let legal_actions = list_legal_actions(&state, 0);
+        
+        let greedy = ProfilePolicy { weights: GreedyWeights::default() };
+        assert_ne!(greedy.select_action(&state, &legal_actions, &mut rng), Some(played.clone()));
+        
+        let weights = fit_greedy_weights(&[(state.clone(), played.clone())]);
+        assert!(weights.floor_avoidance > 0.0);
+        let profile = ProfilePolicy { weights };
+        assert_eq!(profile.select_action(&state, &legal_actions, &mut rng), Some(played));
+    }
+
     #[test]
     fn test_greedy_policy_returns_none_for_empty_list() {
         let state = State::new_test_state();
         let mut rng = StdRng::seed_from_u64(12345);
         
-        let policy = GreedyPolicy;
+        let policy = GreedyPolicy::new();
         let selected = policy.select_action(&state, &[], &mut rng);
         
         assert!(selected.is_none());
@@ -271,7 +539,7 @@ mod tests {
             },
         ];
         
-        let policy = GreedyPolicy;
+        let policy = GreedyPolicy::new();
         
         // Run multiple times with different seeds to verify randomness
         let mut selected_factory_0 = 0;
@@ -292,4 +560,32 @@ mod tests {
         assert!(selected_factory_0 > 0, "Factory 0 should be selected sometimes");
         assert!(selected_factory_1 > 0, "Factory 1 should be selected sometimes");
     }
+
+    #[test]
+    fn test_deterministic_greedy_ignores_seed() {
+        let mut state = State::new_test_state();
+        state.factories[0].insert(TileColor::Blue, 2);
+        state.factories[1].insert(TileColor::Blue, 2);
+        
+        // Listed out of canonical order so a "first in list" tiebreak would differ
+        let actions = vec![
+            DraftAction {
+                source: ActionSource::Factory(1),
+                color: TileColor::Blue,
+                destination: Destination::PatternLine(2),
+            },
+            DraftAction {
+                source: ActionSource::Factory(0),
+                color: TileColor::Blue,
+                destination: Destination::PatternLine(2),
+            },
+        ];
+        
+        let policy = GreedyPolicy::deterministic();
+        for seed in 0..50 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let selected = policy.select_action(&state, &actions, &mut rng).unwrap();
+            assert_eq!(selected, actions[1], "Seed {} changed the selection", seed);
+        }
+    }
 }
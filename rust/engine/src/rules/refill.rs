@@ -1,6 +1,10 @@
-use crate::model::{State, TileMultiset, TileColor};
-use crate::rules::constants::{ALL_COLORS, FACTORY_COUNT_2P, TILES_PER_FACTORY};
+use crate::model::{State, TileMultiset, TileMultisetExt, TileColor};
+use crate::rules::constants::{ALL_COLORS, FACTORY_COUNT_2P, TILES_PER_FACTORY, TILES_PER_COLOR};
+use crate::rules::rng::create_rng_from_seed;
+use crate::rules::trace::{trace_tile_move, TileLocation};
 use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Draw a random tile from the bag and remove it.
 ///
@@ -8,9 +12,9 @@ use rand::Rng;
 /// Iterates over colors in a fixed order to ensure determinism.
 ///
 /// Returns None if bag is empty.
-fn draw_random_tile_from_bag<R: Rng>(bag: &mut TileMultiset, rng: &mut R) -> Option<TileColor> {
-    // Calculate total tiles
-    let total: u8 = bag.values().sum();
+pub(crate) fn draw_random_tile_from_bag<R: Rng>(bag: &mut TileMultiset, rng: &mut R) -> Option<TileColor> {
+    // Calculate total tiles (at most 100, so u8 is sufficient)
+    let total = bag.total() as u8;
     
     if total == 0 {
         return None;
@@ -22,7 +26,7 @@ fn draw_random_tile_from_bag<R: Rng>(bag: &mut TileMultiset, rng: &mut R) -> Opt
     // Iterate in a fixed, rules-defined order for determinism.
     // This avoids HashMap iteration order affecting reproducibility.
     for &color in &ALL_COLORS {
-        let count = bag.get(&color).copied().unwrap_or(0);
+        let count = bag.count(color);
         if count == 0 {
             continue;
         }
@@ -43,11 +47,6 @@ fn draw_random_tile_from_bag<R: Rng>(bag: &mut TileMultiset, rng: &mut R) -> Opt
     None
 }
 
-/// Count total tiles in a multiset
-fn count_tiles_in_multiset(multiset: &TileMultiset) -> u8 {
-    multiset.values().sum()
-}
-
 /// Refill factories from bag, transferring lid to bag if needed.
 ///
 /// Clears all factories and center, then attempts to place 4 tiles in each of 5 factories.
@@ -66,13 +65,14 @@ pub fn refill_factories_with_rng<R: Rng>(state: &mut State, rng: &mut R) {
     state.center.tiles.clear();
     
     // Check if we need to refill bag from lid
-    let bag_count = count_tiles_in_multiset(&state.bag);
-    let total_needed = (FACTORY_COUNT_2P * TILES_PER_FACTORY) as u8;
+    let bag_count = state.bag.total();
+    let total_needed = (FACTORY_COUNT_2P * TILES_PER_FACTORY) as u32;
     
     if bag_count < total_needed {
         // Transfer all lid tiles to bag
         for (color, count) in state.lid.drain() {
-            *state.bag.entry(color).or_insert(0) += count;
+            trace_tile_move(TileLocation::Lid, TileLocation::Bag, color, count);
+            state.bag.add(color, count);
         }
     }
     
@@ -80,7 +80,8 @@ pub fn refill_factories_with_rng<R: Rng>(state: &mut State, rng: &mut R) {
     for factory_idx in 0..FACTORY_COUNT_2P {
         for _ in 0..TILES_PER_FACTORY {
             if let Some(color) = draw_random_tile_from_bag(&mut state.bag, rng) {
-                *state.factories[factory_idx].entry(color).or_insert(0) += 1;
+                trace_tile_move(TileLocation::Bag, TileLocation::Factory(factory_idx), color, 1);
+                state.factories[factory_idx].add(color, 1);
             } else {
                 // Bag empty - factory partially filled (legal)
                 break;
@@ -104,3 +105,92 @@ pub fn refill_factories(state: &mut State) {
     let mut rng = rand::thread_rng();
     refill_factories_with_rng(state, &mut rng);
 }
+
+/// Tiles the next refill will draw from: the bag, plus the lid if the bag
+/// alone cannot fill every factory (mirrors `refill_factories_with_rng`).
+fn refill_pool(state: &State) -> TileMultiset {
+    let mut pool = state.bag.clone();
+    if pool.total() < (FACTORY_COUNT_2P * TILES_PER_FACTORY) as u32 {
+        for (&color, &count) in &state.lid {
+            pool.add(color, count);
+        }
+    }
+    pool
+}
+
+/// Probability that any single tile placed by the next refill is each color.
+///
+/// Analytic counterpart to `simulate_refill_outcomes`. The expected number of
+/// tiles of a color across all factories is this probability times the tiles
+/// drawn (20, or fewer if bag and lid together run short). Colors absent from
+/// the pool map to 0.0; an empty pool gives all zeros.
+pub fn refill_color_probabilities(state: &State) -> HashMap<TileColor, f64> {
+    let pool = refill_pool(state);
+    let total = pool.total();
+    
+    ALL_COLORS
+        .iter()
+        .map(|&color| {
+            let p = if total == 0 { 0.0 } else { pool.count(color) as f64 / total as f64 };
+            (color, p)
+        })
+        .collect()
+}
+
+/// Empirical distribution of one color's count across all refilled factories
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ColorRefillDistribution {
+    pub color: TileColor,
+    /// `histogram[k]` = number of samples with exactly `k` tiles of this color
+    pub histogram: Vec<usize>,
+    /// Mean tiles of this color per refill
+    pub mean: f64,
+}
+
+/// Result of sampling many factory refills from the same supply
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct RefillSimulation {
+    pub samples: usize,
+    /// Tiles placed per refill (20 unless the supply runs short)
+    pub tiles_drawn: u32,
+    pub colors: Vec<ColorRefillDistribution>,
+}
+
+/// Monte Carlo over factory refills from the current bag and lid.
+///
+/// Each sample refills a copy of the state with `refill_factories_with_rng`,
+/// so draws follow the real refill rules (including the lid top-up). All
+/// samples share one RNG seeded from `seed`, making results reproducible.
+pub fn simulate_refill_outcomes(state: &State, samples: usize, seed: u64) -> RefillSimulation {
+    let mut rng = create_rng_from_seed(seed);
+    let max_count = TILES_PER_COLOR as usize;
+    let mut histograms: HashMap<TileColor, Vec<usize>> = ALL_COLORS
+        .iter()
+        .map(|&color| (color, vec![0; max_count + 1]))
+        .collect();
+    let tiles_drawn = refill_pool(state).total().min((FACTORY_COUNT_2P * TILES_PER_FACTORY) as u32);
+    
+    for _ in 0..samples {
+        let mut sample = state.clone();
+        refill_factories_with_rng(&mut sample, &mut rng);
+        
+        for &color in &ALL_COLORS {
+            let count: usize = sample.factories.iter().map(|f| f.count(color) as usize).sum();
+            histograms.get_mut(&color).unwrap()[count.min(max_count)] += 1;
+        }
+    }
+    
+    let colors = ALL_COLORS
+        .iter()
+        .map(|&color| {
+            let histogram = histograms.remove(&color).unwrap();
+            let weighted: usize = histogram.iter().enumerate().map(|(k, &n)| k * n).sum();
+            let mean = if samples == 0 { 0.0 } else { weighted as f64 / samples as f64 };
+            ColorRefillDistribution { color, histogram, mean }
+        })
+        .collect();
+    
+    RefillSimulation { samples, tiles_drawn, colors }
+}
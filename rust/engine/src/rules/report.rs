@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use crate::model::{State, DraftAction, GameStage, RoundStage};
+use super::evaluator::{evaluate_best_move, CandidateAction, EvaluationMetadata, EvaluatorError, EvaluatorParams};
+use super::generator::{compute_game_stage, compute_round_stage};
+use super::scoring::{calculate_end_game_bonus, projected_round_score};
+
+/// One player's score picture at the analyzed position
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ScoreBreakdown {
+    /// Score banked so far
+    pub score: i32,
+    /// Points the round would add if it ended now (wall tiling minus floor)
+    pub projected_round_points: i32,
+    /// End-game bonus the current wall already earns
+    pub wall_bonus: i32,
+}
+
+/// Full analysis of a position, ready to share
+///
+/// Bundles the evaluator's output with the position it was run on so the
+/// report can be reopened without re-running rollouts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AnalysisReport {
+    /// Position that was analyzed
+    pub position: State,
+    /// Player the analysis is for
+    pub player_id: u8,
+    pub game_stage: GameStage,
+    pub round_stage: RoundStage,
+    pub best_action: DraftAction,
+    pub best_action_ev: f64,
+    /// Evaluated candidates, highest EV first
+    pub candidates: Vec<CandidateAction>,
+    /// Score breakdown for both players, indexed by player ID
+    pub score_breakdown: [ScoreBreakdown; 2],
+    pub metadata: EvaluationMetadata,
+}
+
+impl AnalysisReport {
+    /// Serialize the report to JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("AnalysisReport serialization cannot fail")
+    }
+}
+
+/// Analyze a position for export
+///
+/// Runs `evaluate_best_move` and packages its result with the stage
+/// classification and each player's score breakdown.
+///
+/// # Errors
+///
+/// Returns `EvaluatorError` if the evaluation fails.
+pub fn export_analysis(
+    state: &State,
+    player_id: u8,
+    params: &EvaluatorParams,
+) -> Result<AnalysisReport, EvaluatorError> {
+    let result = evaluate_best_move(state, player_id, params)?;
+
+    let mut candidates = result.candidates.unwrap_or_default();
+    candidates.sort_by(|a, b| b.ev.total_cmp(&a.ev));
+
+    let breakdown = |id: u8| -> Result<ScoreBreakdown, EvaluatorError> {
+        let player = &state.players[id as usize];
+        Ok(ScoreBreakdown {
+            score: player.score,
+            projected_round_points: projected_round_score(state, id)
+                .map_err(|e| EvaluatorError::ActionFailed(e.message))?,
+            wall_bonus: calculate_end_game_bonus(&player.wall),
+        })
+    };
+
+    Ok(AnalysisReport {
+        position: state.clone(),
+        player_id,
+        game_stage: compute_game_stage(state),
+        round_stage: compute_round_stage(state),
+        best_action: result.best_action,
+        best_action_ev: result.best_action_ev,
+        candidates,
+        score_breakdown: [breakdown(0)?, breakdown(1)?],
+        metadata: result.metadata,
+    })
+}
@@ -1,6 +1,8 @@
-use crate::model::State;
+use crate::model::{State, TileColor};
 use crate::rules::wall_utils::get_wall_column_for_color;
 use crate::rules::scoring::calculate_wall_tile_score;
+use crate::rules::error::ValidationError;
+use crate::rules::trace::{trace_tile_move, TileLocation};
 
 /// Resolve all complete pattern lines for both players.
 ///
@@ -10,14 +12,27 @@ use crate::rules::scoring::calculate_wall_tile_score;
 /// 2. Discards remaining (capacity - 1) tiles to the lid
 /// 3. Resets the pattern line to empty
 ///
+/// # Resolution order
+///
+/// Players resolve in seat order and, within a player, rows resolve strictly
+/// top to bottom (row 0 first). Each placement is scored against the wall as
+/// it stands after the rows above it were placed, so a line completed lower
+/// down can score adjacency created earlier in the same resolution.
+///
 /// # Arguments
 ///
 /// * `state` - Mutable reference to game state
 ///
+/// # Errors
+///
+/// Returns a `WALL_CELL_OCCUPIED` error if a complete pattern line targets a
+/// wall cell that is already filled. This cannot happen under legal play but
+/// can in corrupt or hand-edited states. All lines are checked before anything
+/// is placed, so the state is left untouched on error.
+///
 /// # Invariants
 ///
 /// - Complete pattern lines must have a color set
-/// - Wall positions should not already be filled (checked in debug mode)
 /// - Tile conservation is maintained (all tiles accounted for)
 ///
 /// # Example
@@ -32,7 +47,7 @@ use crate::rules::scoring::calculate_wall_tile_score;
 ///     count_filled: 3,
 /// };
 ///
-/// resolve_pattern_lines(&mut state);
+/// resolve_pattern_lines(&mut state).unwrap();
 ///
 /// // Blue tile placed at row 2, col 2
 /// assert!(state.players[0].wall[2][2]);
@@ -41,50 +56,105 @@ use crate::rules::scoring::calculate_wall_tile_score;
 /// // Pattern line reset
 /// assert_eq!(state.players[0].pattern_lines[2].count_filled, 0);
 /// ```
-pub fn resolve_pattern_lines(state: &mut State) {
+pub fn resolve_pattern_lines(state: &mut State) -> Result<(), ValidationError> {
+    resolve_pattern_lines_with(state, |_, _, _, _| {})
+}
+
+/// Resolve all complete pattern lines, reporting each placement
+///
+/// Same order and errors as `resolve_pattern_lines`. `on_place` receives
+/// the player, row, placement and the state right after it.
+pub(crate) fn resolve_pattern_lines_with(
+    state: &mut State,
+    mut on_place: impl FnMut(usize, usize, LinePlacement, &State),
+) -> Result<(), ValidationError> {
+    check_pattern_line_targets(state)?;
+    
     for player_idx in 0..2 {
-        let player = &mut state.players[player_idx];
-        
+        // Top to bottom: later rows see tiles placed by earlier rows
         for row in 0..5 {
-            let pattern_line = &mut player.pattern_lines[row];
-            
-            // Check if pattern line is complete
+            if let Some(placed) = resolve_pattern_line(state, player_idx, row) {
+                on_place(player_idx, row, placed, state);
+            }
+        }
+    }
+    
+    Ok(())
+}
+
+/// Reject complete pattern lines whose wall cell is already filled
+///
+/// Run before placing anything so we never double-place a tile.
+fn check_pattern_line_targets(state: &State) -> Result<(), ValidationError> {
+    for (player_idx, player) in state.players.iter().enumerate() {
+        for (row, pattern_line) in player.pattern_lines.iter().enumerate() {
             if pattern_line.count_filled == pattern_line.capacity {
-                // Extract color (must exist if filled)
-                let color = pattern_line.color.expect(
-                    "Complete pattern line must have a color"
-                );
-                
-                // Determine wall position using existing utility
-                let col = get_wall_column_for_color(row, color);
-                
-                // Skip if wall position already filled (should not happen in normal gameplay)
-                // This can occur if state was manually edited incorrectly
-                if player.wall[row][col] {
-                    // Wall position already filled - skip this pattern line
-                    // Just clear the pattern line without placing tile
-                    pattern_line.count_filled = 0;
-                    pattern_line.color = None;
-                    continue;
-                }
-                
-                // Place one tile on wall
-                player.wall[row][col] = true;
-                
-                // Calculate and add score for this placement (Sprint 03B)
-                let points = calculate_wall_tile_score(&player.wall, row, col);
-                player.score += points;
-                
-                // Discard excess tiles to lid
-                let tiles_to_discard = pattern_line.capacity - 1;
-                if tiles_to_discard > 0 {
-                    *state.lid.entry(color).or_insert(0) += tiles_to_discard;
+                if let Some(color) = pattern_line.color {
+                    let col = get_wall_column_for_color(row, color);
+                    if player.wall[row][col] {
+                        return Err(ValidationError::wall_cell_occupied(player_idx, row, col));
+                    }
                 }
-                
-                // Reset pattern line to empty state
-                pattern_line.count_filled = 0;
-                pattern_line.color = None;
             }
         }
     }
+    
+    Ok(())
+}
+
+/// A tile moved from a complete pattern line to the wall
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LinePlacement {
+    pub col: usize,
+    pub color: TileColor,
+    pub points: i32,
+}
+
+/// Resolve one pattern line if it is complete
+///
+/// Places one tile on the wall, scores it, discards the rest to the lid and
+/// resets the line. The target cell must be free (see
+/// `check_pattern_line_targets`).
+fn resolve_pattern_line(
+    state: &mut State,
+    player_idx: usize,
+    row: usize,
+) -> Option<LinePlacement> {
+    let player = &mut state.players[player_idx];
+    let pattern_line = &mut player.pattern_lines[row];
+    
+    // Check if pattern line is complete
+    if pattern_line.count_filled != pattern_line.capacity {
+        return None;
+    }
+    
+    // Extract color (must exist if filled)
+    let color = pattern_line.color.expect(
+        "Complete pattern line must have a color"
+    );
+    
+    // Determine wall position using existing utility
+    let col = get_wall_column_for_color(row, color);
+    
+    // Place one tile on wall
+    player.wall[row][col] = true;
+    let line = TileLocation::PatternLine { player: player_idx, row };
+    trace_tile_move(line, TileLocation::Wall { player: player_idx, row }, color, 1);
+    
+    // Calculate and add score for this placement (Sprint 03B)
+    let points = calculate_wall_tile_score(&player.wall, row, col);
+    player.score += points;
+    
+    // Discard excess tiles to lid
+    let tiles_to_discard = pattern_line.capacity - 1;
+    trace_tile_move(line, TileLocation::Lid, color, tiles_to_discard);
+    if tiles_to_discard > 0 {
+        *state.lid.entry(color).or_insert(0) += tiles_to_discard;
+    }
+    
+    // Reset pattern line to empty state
+    pattern_line.count_filled = 0;
+    pattern_line.color = None;
+    
+    Some(LinePlacement { col, color, points })
 }
@@ -1,12 +1,16 @@
-use crate::model::{State, DraftAction};
+use crate::model::{State, DraftAction, FloorLine};
 use crate::rules::{
     list_legal_actions,
-    apply_action,
+    apply_action_in_place,
     resolve_end_of_round,
+    resolve_end_of_round_with_rng,
+    max_achievable_score,
     create_rng_from_seed,
     DraftPolicy,
     RandomPolicy,
     GreedyPolicy,
+    LookaheadPolicy,
+    ProfilePolicy,
     PolicyMix,
 };
 use rand::Rng;
@@ -63,18 +67,37 @@ fn default_max_actions() -> usize {
 pub struct RolloutResult {
     /// Final state after end-of-round resolution
     pub final_state: State,
-    /// Player 0's final score
+    /// Player 0's final score (with end-game bonuses if the game ended)
     pub player_0_score: i32,
-    /// Player 1's final score
+    /// Player 1's final score (with end-game bonuses if the game ended)
     pub player_1_score: i32,
     /// Number of drafting actions simulated (before resolution)
     pub actions_simulated: usize,
     /// Whether the round ended normally (true) or hit max_actions (false)
     pub completed_normally: bool,
+    /// Player 0's score change from end-of-round resolution (placements,
+    /// floor penalties and any end-game bonuses)
+    #[serde(default)]
+    pub player_0_round_delta: i32,
+    /// Player 1's score change from end-of-round resolution
+    #[serde(default)]
+    pub player_1_round_delta: i32,
+    /// Whether the players finished level on score
+    #[serde(default)]
+    pub tied: bool,
+    /// Each player's floor line as drafting ended, before resolution
+    /// scored and cleared it
+    #[serde(default)]
+    pub drafted_floors: [FloorLine; 2],
+}
+
+/// Both players' floor lines, for `RolloutResult::drafted_floors`
+fn floor_lines(state: &State) -> [FloorLine; 2] {
+    [state.players[0].floor_line.clone(), state.players[1].floor_line.clone()]
 }
 
 /// Check if the drafting round is complete (all factories and center empty)
-fn is_round_complete(state: &State) -> bool {
+pub(crate) fn is_round_complete(state: &State) -> bool {
     // Check all factories are empty
     for factory in &state.factories {
         if !factory.is_empty() {
@@ -102,24 +125,90 @@ fn select_action_with_policy<R: Rng>(
             RandomPolicy.select_action(state, legal_actions, rng)
         }
         PolicyMix::AllGreedy => {
-            GreedyPolicy.select_action(state, legal_actions, rng)
+            GreedyPolicy::new().select_action(state, legal_actions, rng)
         }
         PolicyMix::Mixed { greedy_ratio } => {
             let use_greedy = rng.gen::<f32>() < greedy_ratio;
             if use_greedy {
-                GreedyPolicy.select_action(state, legal_actions, rng)
+                GreedyPolicy::new().select_action(state, legal_actions, rng)
             } else {
                 RandomPolicy.select_action(state, legal_actions, rng)
             }
         }
+        PolicyMix::GreedyLookahead { lookahead_ratio } => {
+            let use_lookahead = rng.gen::<f32>() < lookahead_ratio;
+            if use_lookahead {
+                LookaheadPolicy.select_action(state, legal_actions, rng)
+            } else {
+                GreedyPolicy::new().select_action(state, legal_actions, rng)
+            }
+        }
+        PolicyMix::Profile { weights } => {
+            ProfilePolicy { weights }.select_action(state, legal_actions, rng)
+        }
+    }
+}
+
+/// Play policy moves until every factory and the center are empty
+///
+/// Counts moves into `actions_simulated` and fails once it reaches
+/// `config.max_actions`.
+pub(crate) fn draft_until_round_complete<R: Rng>(
+    state: &mut State,
+    config: &RolloutConfig,
+    rng: &mut R,
+    actions_simulated: &mut usize,
+) -> Result<(), RolloutError> {
+    loop {
+        // Check termination: round complete
+        if is_round_complete(state) {
+            break;
+        }
+        
+        // Check termination: safety limit
+        if *actions_simulated >= config.max_actions {
+            return Err(RolloutError::MaxActionsExceeded);
+        }
+        
+        // Get legal actions for current player
+        let legal_actions = list_legal_actions(state, state.active_player_id);
+        if legal_actions.is_empty() {
+            return Err(RolloutError::Deadlock(
+                format!("No legal actions but round not complete (player {})", 
+                    state.active_player_id)
+            ));
+        }
+        
+        // Select action via policy
+        let current_player = state.active_player_id;
+        let policy_mix = if current_player == 0 {
+            config.active_player_policy
+        } else {
+            config.opponent_policy
+        };
+        
+        let action = select_action_with_policy(state, &legal_actions, policy_mix, rng)
+            .ok_or_else(|| RolloutError::PolicyFailure(
+                format!("Policy returned no action for player {}", current_player)
+            ))?;
+        
+        // Apply action
+        apply_action_in_place(state, &action)
+            .map_err(|e| RolloutError::IllegalAction(e.message.clone()))?;
+        
+        *actions_simulated += 1;
     }
+    
+    Ok(())
 }
 
 /// Simulate game from current state to end of round
 ///
 /// Takes a game state in the middle of a drafting round and simulates
 /// play using policy bots until all factories and center are empty.
-/// Then resolves end-of-round scoring.
+/// Then resolves end-of-round scoring. If that round ends the game, both
+/// players' end-game bonuses are included in the returned scores, so a
+/// bonus the opponent claims counts against the active player's utility.
 ///
 /// # Arguments
 ///
@@ -157,47 +246,11 @@ pub fn simulate_rollout(
     let mut actions_simulated = 0;
     
     // 2. Simulate drafting phase
-    loop {
-        // Check termination: round complete
-        if is_round_complete(&state) {
-            break;
-        }
-        
-        // Check termination: safety limit
-        if actions_simulated >= config.max_actions {
-            return Err(RolloutError::MaxActionsExceeded);
-        }
-        
-        // Get legal actions for current player
-        let legal_actions = list_legal_actions(&state, state.active_player_id);
-        if legal_actions.is_empty() {
-            return Err(RolloutError::Deadlock(
-                format!("No legal actions but round not complete (player {})", 
-                    state.active_player_id)
-            ));
-        }
-        
-        // Select action via policy
-        let current_player = state.active_player_id;
-        let policy_mix = if current_player == 0 {
-            config.active_player_policy
-        } else {
-            config.opponent_policy
-        };
-        
-        let action = select_action_with_policy(&state, &legal_actions, policy_mix, &mut rng)
-            .ok_or_else(|| RolloutError::PolicyFailure(
-                format!("Policy returned no action for player {}", current_player)
-            ))?;
-        
-        // Apply action
-        state = apply_action(&state, &action)
-            .map_err(|e| RolloutError::IllegalAction(e.message.clone()))?;
-        
-        actions_simulated += 1;
-    }
+    draft_until_round_complete(&mut state, config, &mut rng, &mut actions_simulated)?;
     
-    // 3. Resolve end of round
+    // 3. Resolve end of round, snapshotting scores to attribute the change
+    let scores_before = [state.players[0].score, state.players[1].score];
+    let drafted_floors = floor_lines(&state);
     state = resolve_end_of_round(&state)
         .map_err(|e| RolloutError::IllegalAction(e.message.clone()))?;
     
@@ -208,5 +261,89 @@ pub fn simulate_rollout(
         player_1_score: state.players[1].score,
         actions_simulated,
         completed_normally: true,
+        player_0_round_delta: state.players[0].score - scores_before[0],
+        player_1_round_delta: state.players[1].score - scores_before[1],
+        tied: state.players[0].score == state.players[1].score,
+        drafted_floors,
+    })
+}
+
+/// Rounds a full-game rollout plays before giving up on the game ending
+const MAX_GAME_ROLLOUT_ROUNDS: usize = 20;
+
+/// Whether one player's score already exceeds the other's best possible score
+///
+/// Compares each player's current score with the other's
+/// `max_achievable_score` ceiling. Like that ceiling, this ignores floor
+/// penalties still to come.
+pub(crate) fn is_outcome_decided(state: &State) -> bool {
+    let exceeds = |player: usize, rival: u8| {
+        max_achievable_score(state, rival).is_ok_and(|ceiling| state.players[player].score > ceiling)
+    };
+    exceeds(0, 1) || exceeds(1, 0)
+}
+
+/// Simulate from the current state to the end of the game
+///
+/// Plays rounds as `simulate_rollout` does, refilling factories from the
+/// rollout's seed between rounds, until the game ends. Stops early at a
+/// round boundary once the outcome is decided (see `is_outcome_decided`),
+/// returning the scores at that point with `completed_normally` set; the
+/// final state is then not terminal. `max_actions` applies to each round.
+/// If the game has not ended after 20 rounds, returns the position reached
+/// with `completed_normally` false.
+///
+/// The round deltas and drafted floors in the result are those of the last
+/// round played.
+///
+/// The evaluator does not call this yet; its rollouts end with the current
+/// round.
+///
+/// # Errors
+///
+/// Returns `RolloutError` under the same conditions as `simulate_rollout`.
+pub fn simulate_game_rollout(
+    initial_state: &State,
+    config: &RolloutConfig,
+) -> Result<RolloutResult, RolloutError> {
+    let mut state = initial_state.clone();
+    let mut rng = create_rng_from_seed(config.seed);
+    let mut actions_simulated = 0;
+    
+    for _ in 0..MAX_GAME_ROLLOUT_ROUNDS {
+        let mut round_actions = 0;
+        draft_until_round_complete(&mut state, config, &mut rng, &mut round_actions)?;
+        actions_simulated += round_actions;
+        
+        let scores_before = [state.players[0].score, state.players[1].score];
+        let drafted_floors = floor_lines(&state);
+        state = resolve_end_of_round_with_rng(&state, &mut rng)
+            .map_err(|e| RolloutError::IllegalAction(e.message.clone()))?;
+        
+        if state.is_terminal || is_outcome_decided(&state) {
+            return Ok(RolloutResult {
+                final_state: state.clone(),
+                player_0_score: state.players[0].score,
+                player_1_score: state.players[1].score,
+                actions_simulated,
+                completed_normally: true,
+                player_0_round_delta: state.players[0].score - scores_before[0],
+                player_1_round_delta: state.players[1].score - scores_before[1],
+                tied: state.players[0].score == state.players[1].score,
+                drafted_floors,
+            });
+        }
+    }
+    
+    Ok(RolloutResult {
+        final_state: state.clone(),
+        player_0_score: state.players[0].score,
+        player_1_score: state.players[1].score,
+        actions_simulated,
+        completed_normally: false,
+        player_0_round_delta: 0,
+        player_1_round_delta: 0,
+        tied: state.players[0].score == state.players[1].score,
+        drafted_floors: floor_lines(&state),
     })
 }
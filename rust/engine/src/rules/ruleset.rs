@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// How a tile placed on the wall scores for adjacent tiles
+///
+/// Standard Azul scores the length of the horizontal chain and the length of
+/// the vertical chain through the new tile, so a tile that joins both counts
+/// itself twice. An isolated tile scores 1 whatever the rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AdjacencyRule {
+    /// Count the new tile in both chains when it joins a row and a column
+    pub count_placed_tile_twice: bool,
+    /// Extra points when the new tile joins both a row and a column chain
+    pub cross_bonus: i32,
+}
+
+impl Default for AdjacencyRule {
+    fn default() -> Self {
+        Self {
+            count_placed_tile_twice: true,
+            cross_bonus: 0,
+        }
+    }
+}
+
+impl AdjacencyRule {
+    /// Points for a tile whose row and column chains (each including the
+    /// tile itself) have lengths `h_count` and `v_count`
+    pub fn score(&self, h_count: i32, v_count: i32) -> i32 {
+        match (h_count > 1, v_count > 1) {
+            (false, false) => 1,
+            (true, false) => h_count,
+            (false, true) => v_count,
+            (true, true) => {
+                let shared = if self.count_placed_tile_twice { 0 } else { 1 };
+                h_count + v_count - shared + self.cross_bonus
+            }
+        }
+    }
+}
+
+/// Scoring rules the engine can vary, for hosting variant experiments
+///
+/// `Ruleset::default()` is standard 2-player Azul (`RULESET_ID`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Ruleset {
+    #[serde(default)]
+    pub adjacency: AdjacencyRule,
+}
@@ -1,4 +1,9 @@
 use crate::model::{State, Wall, FloorLine};
+use crate::rules::constants::{ROW_BONUS, COLUMN_BONUS, COLOR_BONUS};
+use crate::rules::wall_utils::get_wall_column_for_color;
+use crate::rules::error::ValidationError;
+use crate::rules::floor::floor_slots_used;
+use crate::rules::ruleset::Ruleset;
 
 /// Calculate score for placing a tile on the wall.
 ///
@@ -42,6 +47,23 @@ use crate::model::{State, Wall, FloorLine};
 /// assert_eq!(calculate_wall_tile_score(&wall, 1, 1), 3);
 /// ```
 pub fn calculate_wall_tile_score(wall: &Wall, row: usize, col: usize) -> i32 {
+    calculate_wall_tile_score_with_ruleset(wall, row, col, &Ruleset::default())
+}
+
+/// Calculate score for placing a tile on the wall under a variant ruleset
+///
+/// Same chain counting as `calculate_wall_tile_score`, with the chain
+/// lengths scored by `ruleset.adjacency`.
+///
+/// # Panics
+///
+/// Panics in debug mode if `wall[row][col]` is false (tile not placed)
+pub fn calculate_wall_tile_score_with_ruleset(
+    wall: &Wall,
+    row: usize,
+    col: usize,
+    ruleset: &Ruleset,
+) -> i32 {
     debug_assert!(
         wall[row][col],
         "Tile must be placed at position [{}, {}]",
@@ -93,19 +115,7 @@ pub fn calculate_wall_tile_score(wall: &Wall, row: usize, col: usize) -> i32 {
     }
     
     // Calculate final score
-    if h_count == 1 && v_count == 1 {
-        // Isolated tile: no adjacent tiles in either direction
-        1
-    } else {
-        let mut score = 0;
-        if h_count > 1 {
-            score += h_count;
-        }
-        if v_count > 1 {
-            score += v_count;
-        }
-        score
-    }
+    ruleset.adjacency.score(h_count, v_count)
 }
 
 /// Calculate floor penalty for a player's floor line.
@@ -117,7 +127,9 @@ pub fn calculate_wall_tile_score(wall: &Wall, row: usize, col: usize) -> i32 {
 /// If the player has the first-player token, it occupies slot 0 and tiles
 /// start at slot 1. Otherwise, tiles start at slot 0.
 ///
-/// Tiles beyond the 7th slot incur no additional penalty.
+/// `apply_action` never lets a floor use more than 7 slots (extra tiles go
+/// to the lid), so in play every slot in use is penalized. Slots past the
+/// 7th on a hand-built floor are ignored.
 ///
 /// # Arguments
 ///
@@ -149,23 +161,8 @@ pub fn calculate_wall_tile_score(wall: &Wall, row: usize, col: usize) -> i32 {
 pub fn calculate_floor_penalty(floor_line: &FloorLine) -> i32 {
     use crate::rules::constants::FLOOR_PENALTIES;
     
-    let mut penalty = 0;
-    let mut slot = 0;
-    
-    // First-player token occupies slot 0
-    if floor_line.has_first_player_token {
-        penalty += FLOOR_PENALTIES[0];
-        slot = 1;
-    }
-    
-    // Apply penalties for floor tiles (only first 7 slots count)
-    let tiles_to_count = std::cmp::min(floor_line.tiles.len(), 7 - slot);
-    for _ in 0..tiles_to_count {
-        penalty += FLOOR_PENALTIES[slot];
-        slot += 1;
-    }
-    
-    penalty
+    // First-player token occupies slot 0, tiles fill the slots after it
+    FLOOR_PENALTIES.iter().take(floor_slots_used(floor_line)).sum()
 }
 
 /// Apply floor penalties to all players.
@@ -198,3 +195,140 @@ pub fn apply_floor_penalties(state: &mut State) {
         player.score = std::cmp::max(0, player.score + penalty);
     }
 }
+
+/// Calculate the end-of-game bonus for a wall.
+///
+/// Awards 2 points per complete row, 7 per complete column and 10 per color
+/// with all five tiles placed.
+///
+/// # Example
+///
+/// ```
+/// use engine::{calculate_end_game_bonus, Wall};
+///
+/// let mut wall: Wall = [[false; 5]; 5];
+/// wall[0] = [true; 5];
+/// assert_eq!(calculate_end_game_bonus(&wall), 2);
+/// ```
+pub fn calculate_end_game_bonus(wall: &Wall) -> i32 {
+    let complete_rows = wall.iter()
+        .filter(|row| row.iter().all(|&filled| filled))
+        .count() as i32;
+    
+    let complete_columns = (0..5)
+        .filter(|&col| wall.iter().all(|row| row[col]))
+        .count() as i32;
+    
+    let complete_colors = crate::rules::constants::ALL_COLORS.iter()
+        .filter(|&&color| (0..5).all(|row| wall[row][get_wall_column_for_color(row, color)]))
+        .count() as i32;
+    
+    complete_rows * ROW_BONUS + complete_columns * COLUMN_BONUS + complete_colors * COLOR_BONUS
+}
+
+/// Add end-of-game bonuses to every player's score.
+///
+/// Called once, after the final round has been scored.
+pub fn apply_end_game_bonuses(state: &mut State) {
+    for player in &mut state.players {
+        player.score += calculate_end_game_bonus(&player.wall);
+    }
+}
+
+/// Project a player's score change if the round ended right now.
+///
+/// Resolves complete pattern lines and applies floor penalties on a clone of
+/// the state, so the result matches what end-of-round scoring would award
+/// (including the clamp at zero). Deterministic: no refill or rollouts.
+///
+/// # Arguments
+///
+/// * `state` - Current game state (not modified)
+/// * `player_id` - Player to project (0 or 1)
+///
+/// # Returns
+///
+/// Projected score delta for the player, `INVALID_PLAYER` if `player_id`
+/// is not 0 or 1, or the error from `resolve_pattern_lines` if the state has
+/// a corrupt wall placement
+///
+/// # Example
+///
+/// ```
+/// use engine::{State, TileColor, PatternLine, projected_round_score};
+///
+/// let mut state = State::new_test_state();
+/// state.players[0].pattern_lines[0] = PatternLine {
+///     capacity: 1,
+///     color: Some(TileColor::Blue),
+///     count_filled: 1,
+/// };
+///
+/// assert_eq!(projected_round_score(&state, 0).unwrap(), 1);
+/// ```
+pub fn projected_round_score(state: &State, player_id: u8) -> Result<i32, ValidationError> {
+    use crate::rules::resolution::resolve_pattern_lines;
+    
+    if player_id > 1 {
+        return Err(ValidationError::invalid_player(player_id));
+    }
+    
+    let mut projected = state.clone();
+    resolve_pattern_lines(&mut projected)?;
+    apply_floor_penalties(&mut projected);
+    
+    let player_idx = player_id as usize;
+    Ok(projected.players[player_idx].score - state.players[player_idx].score)
+}
+
+/// Upper bound on the final score a player can still reach
+///
+/// A relaxed, admissible ceiling for teaching: assumes the player gets every
+/// tile they need, places them in the best order, never takes a floor
+/// penalty, and that the game lasts until the wall is full. The opponent is
+/// ignored. The real maximum is usually far lower.
+///
+/// A placed tile scores at most its row chain plus its column chain, and
+/// neither can exceed the tiles already in that row or column. So the j-th
+/// future placement in a row holding `n` tiles adds at most `n + j` for the
+/// row, and likewise for columns. Every end-game bonus not yet earned is
+/// counted as earned.
+///
+/// # Arguments
+///
+/// * `state` - Current game state
+/// * `player_id` - Player to bound (0 or 1)
+///
+/// # Example
+///
+/// ```
+/// use engine::{State, max_achievable_score};
+///
+/// // Empty wall: 150 from adjacency plus 10 + 35 + 50 in bonuses
+/// let state = State::new_test_state();
+/// assert_eq!(max_achievable_score(&state, 0).unwrap(), 245);
+/// ```
+///
+/// # Errors
+///
+/// Returns `INVALID_PLAYER` if `player_id` is not 0 or 1.
+pub fn max_achievable_score(state: &State, player_id: u8) -> Result<i32, ValidationError> {
+    let Some(player) = state.players.get(player_id as usize) else {
+        return Err(ValidationError::invalid_player(player_id));
+    };
+    let wall = &player.wall;
+    
+    // Sum of n+1..=5 for a line already holding n tiles
+    let line_ceiling = |filled: i32| (filled + 1..=5).sum::<i32>();
+    let row_points: i32 = wall.iter()
+        .map(|row| line_ceiling(row.iter().filter(|&&filled| filled).count() as i32))
+        .sum();
+    let column_points: i32 = (0..5)
+        .map(|col| line_ceiling(wall.iter().filter(|row| row[col]).count() as i32))
+        .sum();
+    
+    let all_bonuses = calculate_end_game_bonus(&[[true; 5]; 5]);
+    let unearned_bonuses = all_bonuses - calculate_end_game_bonus(wall);
+    
+    Ok(player.score + row_points + column_points + unearned_bonuses)
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use crate::model::State;
+use super::canonical::fingerprint;
+use super::end_of_round::resolve_end_of_round_with_rng;
+use super::error::ValidationError;
+use super::rng::create_rng_from_seed;
+
+/// A round's starting position plus the seed for its closing refill
+///
+/// Lets a drill replay the same round: the factories are in `state`, and
+/// resolving with `refill_seed` deals the same tiles for the next round no
+/// matter how the round was played, as long as the bag covers the refill.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct RoundSnapshot {
+    /// Position before the first draft of the round
+    pub state: State,
+    /// Seed for the refill at the end of the round
+    pub refill_seed: u64,
+}
+
+impl RoundSnapshot {
+    /// Resolve a round started from this snapshot, refilling from its seed
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError` if end-of-round resolution fails.
+    pub fn resolve_round(&self, played: &State) -> Result<State, ValidationError> {
+        let mut rng = create_rng_from_seed(self.refill_seed);
+        resolve_end_of_round_with_rng(played, &mut rng)
+    }
+}
+
+/// Capture a round's starting position for replay
+///
+/// The refill seed is the position's `fingerprint`, so snapshotting the same
+/// position twice gives the same draws.
+pub fn snapshot_round_start(state: &State) -> RoundSnapshot {
+    RoundSnapshot {
+        state: state.clone(),
+        refill_seed: fingerprint(state),
+    }
+}
+
+/// Start the snapshot's round over
+pub fn restore_round(snapshot: &RoundSnapshot) -> State {
+    snapshot.state.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::TileColor;
+    use crate::rules::{apply_action, list_legal_actions, refill_factories_with_rng};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn round_start() -> State {
+        let mut state = State::new_test_state();
+        for color in TileColor::all() {
+            state.bag.insert(color, 20);
+        }
+        refill_factories_with_rng(&mut state, &mut StdRng::seed_from_u64(2143));
+        state
+    }
+
+    /// Play the round out, taking the first (or last) legal action each turn
+    fn play_round(mut state: State, take_last: bool) -> State {
+        loop {
+            let actions = list_legal_actions(&state, state.active_player_id);
+            let action = if take_last { actions.last() } else { actions.first() };
+            let Some(action) = action else {
+                return state;
+            };
+            state = apply_action(&state, action).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_restored_round_replays_identical_refill() {
+        let snapshot = snapshot_round_start(&round_start());
+
+        let first = snapshot.resolve_round(&play_round(restore_round(&snapshot), false)).unwrap();
+        // The retry plays differently but draws the same tiles
+        let retry = snapshot.resolve_round(&play_round(restore_round(&snapshot), true)).unwrap();
+
+        assert_eq!(restore_round(&snapshot), snapshot.state);
+        assert_ne!(first.players, retry.players);
+        assert_eq!(first.factories, retry.factories);
+        assert_eq!(first.bag, retry.bag);
+        assert_eq!(snapshot_round_start(&snapshot.state).refill_seed, snapshot.refill_seed);
+    }
+}
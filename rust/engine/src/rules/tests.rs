@@ -26,13 +26,13 @@ mod tests {
         let actions = list_legal_actions(&state, 0);
         
         // Should have actions for colors in factories and center
-        assert!(actions.len() > 0);
+        assert!(!actions.is_empty());
         
         // Should have floor actions for each color
         let floor_actions: Vec<_> = actions.iter()
             .filter(|a| a.destination == Destination::Floor)
             .collect();
-        assert!(floor_actions.len() > 0);
+        assert!(!floor_actions.is_empty());
     }
 
     #[test]
@@ -121,7 +121,7 @@ mod tests {
             .filter(|a| a.source == ActionSource::Center)
             .collect();
         
-        assert!(center_actions.len() > 0, "Token should not block center actions");
+        assert!(!center_actions.is_empty(), "Token should not block center actions");
     }
 
     #[test]
@@ -250,6 +250,296 @@ mod tests {
         assert!(result, "Yellow should be allowed in row 0");
     }
 
+    #[test]
+    fn test_preview_placement_overflow() {
+        use crate::rules::preview_placement;
+        
+        let mut player = crate::PlayerBoard::new();
+        player.pattern_lines[2] = PatternLine {
+            capacity: 3,
+            color: Some(TileColor::Red),
+            count_filled: 1,
+        };
+        
+        let preview = preview_placement(&player, 2, TileColor::Red, 3).unwrap();
+        assert_eq!(preview.placed, 2);
+        assert_eq!(preview.overflow, 1);
+        assert!(preview.completes_line);
+        
+        let preview = preview_placement(&player, 4, TileColor::Red, 3).unwrap();
+        assert_eq!(preview.overflow, 0);
+        assert!(!preview.completes_line);
+        
+        assert!(preview_placement(&player, 2, TileColor::Blue, 1).is_none());
+    }
+
+    #[test]
+    fn test_find_clean_completions() {
+        use crate::rules::find_clean_completions;
+        
+        let mut state = State::new_test_state();
+        // Factory 0: 3 Blue fits row 2 exactly; too many for rows 0-1
+        state.factories[0].insert(TileColor::Blue, 3);
+        state.factories[0].insert(TileColor::Red, 1);
+        // Factory 1: 4 Yellow overflows rows 0-2 and only fills row 3
+        state.factories[1].insert(TileColor::Yellow, 4);
+        // Center has 2 Black but still holds the first-player token
+        state.center.tiles.insert(TileColor::Black, 2);
+        // Row 3 already has Red, so Yellow cannot go there
+        state.players[0].pattern_lines[3] = PatternLine {
+            capacity: 4,
+            color: Some(TileColor::Red),
+            count_filled: 1,
+        };
+        
+        let clean = find_clean_completions(&state, 0);
+        
+        assert!(clean.contains(&DraftAction {
+            source: ActionSource::Factory(0),
+            color: TileColor::Blue,
+            destination: Destination::PatternLine(2),
+        }));
+        assert!(clean.contains(&DraftAction {
+            source: ActionSource::Factory(0),
+            color: TileColor::Red,
+            destination: Destination::PatternLine(0),
+        }));
+        assert_eq!(clean.len(), 2, "Unexpected clean completions: {:?}", clean);
+    }
+
+    #[test]
+    fn test_draftable_colors_from_factories_and_center() {
+        use crate::rules::draftable_colors;
+        
+        let mut state = State::new_test_state();
+        state.factories[1].insert(TileColor::Blue, 3);
+        state.factories[4].insert(TileColor::Blue, 1);
+        state.center.tiles.insert(TileColor::Red, 2);
+        state.center.tiles.insert(TileColor::Yellow, 0);
+        
+        let colors = draftable_colors(&state);
+        
+        assert_eq!(colors, [TileColor::Blue, TileColor::Red].into_iter().collect());
+    }
+
+    #[test]
+    fn test_table_color_histogram_counts_factories_and_center() {
+        use crate::rules::table_color_histogram;
+        
+        let mut state = State::new_test_state();
+        state.factories[0].insert(TileColor::Blue, 3);
+        state.factories[0].insert(TileColor::Red, 1);
+        state.factories[3].insert(TileColor::Blue, 2);
+        state.center.tiles.insert(TileColor::Blue, 1);
+        state.center.tiles.insert(TileColor::Red, 2);
+        state.center.has_first_player_token = true;
+        // Bag and lid tiles are not on the table
+        state.bag.insert(TileColor::White, 20);
+        state.lid.insert(TileColor::Black, 4);
+        
+        let histogram = table_color_histogram(&state);
+        
+        assert_eq!(
+            histogram,
+            [(TileColor::Blue, 6), (TileColor::Red, 3)].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn test_opponent_threat_when_last_tile_is_in_factory() {
+        use crate::rules::{opponent_threats, Threat};
+        
+        let mut state = State::new_test_state();
+        state.players[1].pattern_lines[2].color = Some(TileColor::Red);
+        state.players[1].pattern_lines[2].count_filled = 2;
+        state.players[1].pattern_lines[3].color = Some(TileColor::Black);
+        state.players[1].pattern_lines[3].count_filled = 1;
+        state.factories[3].insert(TileColor::Red, 1);
+        state.factories[3].insert(TileColor::Black, 2);
+        
+        let threats = opponent_threats(&state, 1);
+        
+        assert_eq!(threats, vec![Threat { row: 2, color: TileColor::Red, tiles_needed: 1 }]);
+        assert!(opponent_threats(&state, 0).is_empty());
+        assert!(opponent_threats(&state, 2).is_empty());
+    }
+
+    #[test]
+    fn test_is_action_legal_names_the_broken_rule() {
+        use crate::rules::{is_action_legal, PlacementLegality};
+        
+        let mut state = State::new_test_state();
+        state.factories[0].insert(TileColor::Blue, 2);
+        let blue_col = get_wall_column_for_color(1, TileColor::Blue);
+        state.players[0].wall[1][blue_col] = true;
+        let action = |color, row| DraftAction {
+            source: ActionSource::Factory(0),
+            color,
+            destination: Destination::PatternLine(row),
+        };
+        
+        assert_eq!(is_action_legal(&state, 0, &action(TileColor::Blue, 0)), Ok(()));
+        assert_eq!(
+            is_action_legal(&state, 0, &action(TileColor::Red, 0)),
+            Err(PlacementLegality::SourceEmpty { color: TileColor::Red })
+        );
+        assert_eq!(
+            is_action_legal(&state, 0, &action(TileColor::Blue, 1)),
+            Err(PlacementLegality::WallConflict { row: 1, col: blue_col, color: TileColor::Blue })
+        );
+        
+        // Agrees with the full action list, including for a finished game
+        for legal in list_legal_actions(&state, 1) {
+            assert_eq!(is_action_legal(&state, 1, &legal), Ok(()));
+        }
+        state.is_terminal = true;
+        assert_eq!(
+            is_action_legal(&state, 0, &action(TileColor::Blue, 0)),
+            Err(PlacementLegality::GameOver)
+        );
+    }
+
+    #[test]
+    fn test_non_overflow_actions_exclude_spills() {
+        use crate::rules::list_non_overflow_actions;
+        
+        // 3 Blue in factory 0, 1 Red in factory 1; row 1 has room for 2
+        let mut state = State::new_test_state();
+        state.factories[0].insert(TileColor::Blue, 3);
+        state.factories[1].insert(TileColor::Red, 1);
+        let to_line = |idx, color, row| DraftAction {
+            source: ActionSource::Factory(idx),
+            color,
+            destination: Destination::PatternLine(row),
+        };
+        
+        let safe = list_non_overflow_actions(&state, 0, false);
+        assert!(!safe.contains(&to_line(0, TileColor::Blue, 1)));
+        assert!(safe.contains(&to_line(0, TileColor::Blue, 2)));
+        assert!(safe.contains(&to_line(1, TileColor::Red, 1)));
+        assert!(safe.iter().all(|a| a.destination != Destination::Floor));
+        
+        // Floor dumps come back on request
+        let with_floor = list_non_overflow_actions(&state, 0, true);
+        assert_eq!(with_floor.len(), safe.len() + 2);
+    }
+
+    #[test]
+    fn test_trap_position_when_every_line_overflows() {
+        use crate::rules::{is_trap_position, TrapKind};
+        
+        // Only 4 Blue on the table; rows 2-4 are committed to Red
+        let mut state = State::new_test_state();
+        state.center.has_first_player_token = false;
+        state.factories[0].insert(TileColor::Blue, 4);
+        for row in 2..5 {
+            state.players[0].pattern_lines[row].color = Some(TileColor::Red);
+            state.players[0].pattern_lines[row].count_filled = 1;
+        }
+        
+        // Rows 0 and 1 overflow by 3 and 2
+        assert_eq!(
+            is_trap_position(&state, 0),
+            Some(TrapKind::Overcommit { min_overflow: 2 })
+        );
+        
+        // With rows 0 and 1 blocked too, only the floor is left
+        state.players[0].wall[0][get_wall_column_for_color(0, TileColor::Blue)] = true;
+        state.players[0].pattern_lines[1].color = Some(TileColor::Yellow);
+        state.players[0].pattern_lines[1].count_filled = 1;
+        assert_eq!(is_trap_position(&state, 0), Some(TrapKind::OnlyFloor));
+        
+        // An empty board can take the Blue in row 3 without spilling
+        assert_eq!(is_trap_position(&state, 1), None);
+    }
+
+    #[test]
+    fn test_unfinishable_line_flagged_when_color_runs_out() {
+        use crate::rules::unfinishable_lines;
+        
+        // Player 0's row 4 holds 2 Red and needs 3 more
+        let mut state = State::new_test_state();
+        state.players[0].pattern_lines[4].color = Some(TileColor::Red);
+        state.players[0].pattern_lines[4].count_filled = 2;
+        state.players[0].pattern_lines[1].color = Some(TileColor::Blue);
+        state.players[0].pattern_lines[1].count_filled = 1;
+        
+        // 17 more Red are locked on walls and lines; 1 is still in a factory
+        for row in 0..4 {
+            let col = get_wall_column_for_color(row, TileColor::Red);
+            state.players[0].wall[row][col] = true;
+            state.players[1].wall[row][col] = row < 2;
+        }
+        state.players[1].pattern_lines[2].color = Some(TileColor::Red);
+        state.players[1].pattern_lines[2].count_filled = 2;
+        state.players[1].pattern_lines[4].color = Some(TileColor::Red);
+        state.players[1].pattern_lines[4].count_filled = 5;
+        state.players[1].pattern_lines[3].color = Some(TileColor::Red);
+        state.players[1].pattern_lines[3].count_filled = 4;
+        state.factories[0].insert(TileColor::Red, 1);
+        
+        assert_eq!(unfinishable_lines(&state, 0), vec![4]);
+        assert!(unfinishable_lines(&state, 1).is_empty());
+        
+        // One more Red back in play is still not enough; two more are
+        state.players[1].pattern_lines[3].count_filled = 3;
+        assert_eq!(unfinishable_lines(&state, 0), vec![4]);
+        state.players[1].pattern_lines[3].count_filled = 2;
+        assert!(unfinishable_lines(&state, 0).is_empty());
+    }
+
+    #[test]
+    fn test_legal_actions_emitted_in_canonical_order() {
+        use crate::TileMultisetExt;
+        
+        // Every factory and the center hold several colors; some rows are blocked
+        let mut state = State::new_test_state();
+        for (idx, factory) in state.factories.iter_mut().enumerate() {
+            factory.add(TileColor::from_index(idx).unwrap(), 2);
+            factory.add(TileColor::from_index((idx + 2) % 5).unwrap(), 2);
+        }
+        for color in TileColor::all() {
+            state.center.tiles.add(color, 1);
+        }
+        state.players[0].wall[0][get_wall_column_for_color(0, TileColor::Red)] = true;
+        state.players[0].pattern_lines[3].color = Some(TileColor::Blue);
+        state.players[0].pattern_lines[3].count_filled = 2;
+        
+        let actions = list_legal_actions(&state, 0);
+        assert!(actions.len() > 50);
+        
+        assert!(actions.windows(2).all(|pair| pair[0] < pair[1]));
+        
+        // Center sorts after every factory, floor after pattern line 4
+        let floor = |source| DraftAction { source, color: TileColor::Blue, destination: Destination::Floor };
+        let line = |source, row| DraftAction { source, color: TileColor::Blue, destination: Destination::PatternLine(row) };
+        assert!(floor(ActionSource::Factory(4)) < line(ActionSource::Center, 0));
+        assert!(line(ActionSource::Factory(0), 4) < floor(ActionSource::Factory(0)));
+    }
+
+    #[test]
+    fn test_lean_state_enumerates_same_actions() {
+        use crate::rules::from_lean_json;
+        use crate::TileMultisetExt;
+        
+        let state: State =
+            serde_json::from_str(include_str!("../../tests/fixtures/mid_game_state.json")).unwrap();
+        assert!(check_tile_conservation(&state).is_ok());
+        
+        let lean = state.to_lean_json();
+        let value: serde_json::Value = serde_json::from_str(&lean).unwrap();
+        assert!(value.get("bag").is_none() && value.get("lid").is_none());
+        assert_eq!(value["bag_total"], state.bag.total());
+        
+        let rebuilt = from_lean_json(&lean).unwrap();
+        assert!(check_tile_conservation(&rebuilt).is_ok());
+        assert_eq!(rebuilt.bag.total(), state.bag.total());
+        assert_eq!(rebuilt.lid.total(), state.lid.total());
+        for player_id in 0..2 {
+            assert_eq!(list_legal_actions(&rebuilt, player_id), list_legal_actions(&state, player_id));
+        }
+    }
+
     // ============================================================
     // apply_action tests
     // ============================================================
@@ -379,6 +669,41 @@ mod tests {
         assert_eq!(new_state.players[0].pattern_lines[4].count_filled, 2);
     }
 
+    #[test]
+    fn test_taking_last_center_tiles_with_token_completes_round() {
+        use crate::rules::resolve_end_of_round;
+        use crate::rules::rollout::is_round_complete;
+        
+        // Factories are empty; the center holds only Red and the token
+        let mut state = State::new_test_state();
+        state.active_player_id = 1;
+        state.center.has_first_player_token = true;
+        state.center.tiles.insert(TileColor::Red, 3);
+        state.bag.insert(TileColor::Blue, 20);
+        state.bag.insert(TileColor::Yellow, 20);
+        state.bag.insert(TileColor::Red, 17);
+        state.bag.insert(TileColor::Black, 20);
+        state.bag.insert(TileColor::White, 20);
+        
+        let action = DraftAction {
+            source: ActionSource::Center,
+            color: TileColor::Red,
+            destination: Destination::PatternLine(2),
+        };
+        let new_state = apply_action(&state, &action).unwrap();
+        
+        // The token moved with the last tiles instead of being left behind
+        assert!(new_state.center.tiles.is_empty());
+        assert!(!new_state.center.has_first_player_token);
+        assert!(new_state.players[1].floor_line.has_first_player_token);
+        assert!(is_round_complete(&new_state));
+        
+        // The round resolves and the token holder starts the next one
+        let next_round = resolve_end_of_round(&new_state).unwrap();
+        assert_eq!(next_round.active_player_id, 1);
+        assert!(next_round.center.has_first_player_token);
+    }
+
     #[test]
     fn test_all_tiles_to_floor() {
         let mut state = State::new_test_state();
@@ -482,6 +807,73 @@ mod tests {
         assert!(check_tile_conservation(&new_state).is_ok());
     }
 
+    #[test]
+    fn test_wall_pattern_incoherence_detected() {
+        use crate::rules::check_wall_pattern_coherence;
+        use crate::PlayerBoard;
+        
+        let mut player = PlayerBoard::new();
+        player.pattern_lines[3].color = Some(TileColor::Red);
+        player.pattern_lines[3].count_filled = 2;
+        assert!(check_wall_pattern_coherence(&player).is_ok());
+        
+        // Red already sits on wall row 3, so the line could never resolve
+        player.wall[3][get_wall_column_for_color(3, TileColor::Red)] = true;
+        let err = check_wall_pattern_coherence(&player).unwrap_err();
+        assert!(err.contains("pattern line 3"), "{}", err);
+        
+        // The same wall tile in another row is fine
+        let mut other = PlayerBoard::new();
+        other.pattern_lines[3] = player.pattern_lines[3].clone();
+        other.wall[2][get_wall_column_for_color(2, TileColor::Red)] = true;
+        assert!(check_wall_pattern_coherence(&other).is_ok());
+    }
+
+    #[test]
+    fn test_describe_position_names_active_player_and_factories() {
+        use crate::rules::describe_position;
+        
+        let mut state = create_test_state_with_tiles();
+        state.active_player_id = 1;
+        state.factories[2].insert(TileColor::Red, 3);
+        state.factories[2].insert(TileColor::Black, 1);
+        state.players[0].pattern_lines[2].color = Some(TileColor::Blue);
+        state.players[0].pattern_lines[2].count_filled = 2;
+        
+        let description = describe_position(&state, 0);
+        
+        assert_eq!(description.active_player_id, 1);
+        assert_eq!(description.turn, "Player 2 to move (opponent's turn).");
+        assert_eq!(description.factory_count, 5);
+        assert_eq!(description.factories.len(), 5);
+        assert_eq!(description.factories[2], "Factory 3: 3 Red tiles and 1 Black tile.");
+        assert_eq!(description.factories[0], "Factory 1: empty.");
+        assert_eq!(description.pattern_lines[2], "Pattern line 3: 2 of 3 Blue.");
+        assert_eq!(description.floor, "Floor: empty.");
+    }
+
+    #[test]
+    fn test_overfilled_factory_is_flagged() {
+        use crate::rules::{check_factory_capacity, factory_tile_count};
+        
+        let mut state = State::new_test_state();
+        state.factories[1].insert(TileColor::Red, 3);
+        state.factories[1].insert(TileColor::Blue, 1);
+        assert_eq!(factory_tile_count(&state, 1), 4);
+        assert!(check_factory_capacity(&state).is_ok());
+        
+        state.factories[1].insert(TileColor::White, 1);
+        assert_eq!(factory_tile_count(&state, 1), 5);
+        assert_eq!(factory_tile_count(&state, 9), 0);
+        let err = check_factory_capacity(&state).unwrap_err();
+        assert!(err.contains("factory 1 holds 5"), "{}", err);
+        
+        // Counts past u8 range don't wrap
+        state.factories[1].insert(TileColor::Red, 200);
+        state.factories[1].insert(TileColor::Blue, 100);
+        assert_eq!(factory_tile_count(&state, 1), 301);
+    }
+
     #[test]
     fn test_error_invalid_source() {
         let state = State::new_test_state();
@@ -692,7 +1084,44 @@ mod tests {
     }
 
     #[test]
-    fn test_floor_line_not_capped_at_7() {
+    fn test_center_take_with_token_and_overflow() {
+        use crate::rules::scoring::calculate_floor_penalty;
+        
+        let mut state = State::new_test_state();
+        state.center.has_first_player_token = true;
+        state.center.tiles.insert(TileColor::Red, 4);
+        // Add tiles for conservation: 4 in center + 96 in bag = 100
+        state.bag.insert(TileColor::Blue, 20);
+        state.bag.insert(TileColor::Yellow, 20);
+        state.bag.insert(TileColor::Red, 16);
+        state.bag.insert(TileColor::Black, 20);
+        state.bag.insert(TileColor::White, 20);
+        
+        let action = DraftAction {
+            source: ActionSource::Center,
+            color: TileColor::Red,
+            destination: Destination::PatternLine(0),
+        };
+        
+        let new_state = apply_action(&state, &action).unwrap();
+        let player = &new_state.players[0];
+        
+        // 1 tile placed, the other 3 overflow behind the token
+        assert_eq!(player.pattern_lines[0].count_filled, 1);
+        assert_eq!(player.pattern_lines[0].color, Some(TileColor::Red));
+        assert!(!new_state.center.has_first_player_token);
+        assert!(player.floor_line.has_first_player_token);
+        assert_eq!(player.floor_line.tiles, vec![TileColor::Red; 3]);
+        assert!(new_state.center.tiles.is_empty());
+        
+        // Token takes the -1 slot, overflow takes -1, -2, -2
+        assert_eq!(calculate_floor_penalty(&player.floor_line), -6);
+    }
+
+    #[test]
+    fn test_floor_line_capped_at_7_with_rest_to_lid() {
+        use crate::rules::{calculate_floor_penalty, check_floor_capacity, FLOOR_PENALTIES};
+        
         let mut state = State::new_test_state();
         state.factories[0].insert(TileColor::Black, 10);
         // Add tiles for conservation: 10 in factory + 90 in bag = 100
@@ -710,75 +1139,356 @@ mod tests {
         
         let new_state = apply_action(&state, &action).unwrap();
         
-        // All 10 tiles should be in floor (not capped)
-        assert_eq!(new_state.players[0].floor_line.tiles.len(), 10);
-    }
-
-    // ============================================================
-    // Wall tile scoring golden tests (Sprint 03B)
-    // ============================================================
-
-    #[test]
-    fn test_scoring_isolated_tile() {
-        use crate::rules::scoring::calculate_wall_tile_score;
-        use crate::Wall;
-        
-        let mut wall: Wall = [[false; 5]; 5];
-        wall[2][2] = true; // Isolated tile
+        // 7 tiles fill the floor, the other 3 go back to the lid
+        let floor = &new_state.players[0].floor_line;
+        assert_eq!(floor.tiles.len(), 7);
+        assert_eq!(new_state.lid.get(&TileColor::Black), Some(&3));
+        assert!(check_floor_capacity(&new_state).is_ok());
+        assert!(check_tile_conservation(&new_state).is_ok());
+        assert_eq!(calculate_floor_penalty(floor), FLOOR_PENALTIES.iter().sum::<i32>());
         
-        let score = calculate_wall_tile_score(&wall, 2, 2);
-        assert_eq!(score, 1, "Isolated tile should score 1 point");
+        // Taking the token onto a full floor bumps a tile to the lid
+        let mut state = new_state;
+        state.active_player_id = 0;
+        state.center.tiles.insert(TileColor::Red, 1);
+        *state.bag.get_mut(&TileColor::Red).unwrap() -= 1;
+        let take_token = DraftAction {
+            source: ActionSource::Center,
+            color: TileColor::Red,
+            destination: Destination::Floor,
+        };
+        let after = apply_action(&state, &take_token).unwrap();
+        let floor = &after.players[0].floor_line;
+        assert!(floor.has_first_player_token);
+        assert_eq!(floor.tiles.len(), 6);
+        assert_eq!(after.lid.get(&TileColor::Black), Some(&4));
+        assert_eq!(after.lid.get(&TileColor::Red), Some(&1));
+        assert_eq!(calculate_floor_penalty(floor), -14);
     }
 
     #[test]
-    fn test_scoring_horizontal_2() {
-        use crate::rules::scoring::calculate_wall_tile_score;
-        use crate::Wall;
+    fn test_drafting_blue_twice_accumulates_drafted_count() {
+        use crate::TileMultisetExt;
         
-        let mut wall: Wall = [[false; 5]; 5];
-        wall[0][0] = true;
-        wall[0][1] = true; // Extends to 2 tiles
+        let mut state = State::new_test_state();
+        state.factories[0].insert(TileColor::Blue, 2);
+        state.factories[0].insert(TileColor::Red, 2);
+        state.factories[1].insert(TileColor::Blue, 3);
+        state.factories[1].insert(TileColor::Yellow, 1);
+        state.bag.insert(TileColor::Blue, 15);
+        state.bag.insert(TileColor::Yellow, 19);
+        state.bag.insert(TileColor::Red, 18);
+        state.bag.insert(TileColor::Black, 20);
+        state.bag.insert(TileColor::White, 20);
         
-        let score = calculate_wall_tile_score(&wall, 0, 1);
-        assert_eq!(score, 2, "Horizontal chain of 2 should score 2 points");
-    }
-
-    #[test]
-    fn test_scoring_horizontal_5() {
-        use crate::rules::scoring::calculate_wall_tile_score;
-        use crate::Wall;
+        let moves = [
+            DraftAction {
+                source: ActionSource::Factory(0),
+                color: TileColor::Blue,
+                destination: Destination::PatternLine(1),
+            },
+            DraftAction {
+                source: ActionSource::Center,
+                color: TileColor::Red,
+                destination: Destination::Floor,
+            },
+            DraftAction {
+                source: ActionSource::Factory(1),
+                color: TileColor::Blue,
+                destination: Destination::PatternLine(4),
+            },
+        ];
+        for action in &moves {
+            state = apply_action(&state, action).unwrap();
+        }
         
-        let mut wall: Wall = [[false; 5]; 5];
-        wall[1][0] = true;
-        wall[1][1] = true;
-        wall[1][2] = true; // New tile
-        wall[1][3] = true;
-        wall[1][4] = true;
+        assert_eq!(state.drafted_counts[0].count(TileColor::Blue), 5);
+        assert_eq!(state.drafted_counts[0].count(TileColor::Red), 0);
+        assert_eq!(state.drafted_counts[1].count(TileColor::Red), 2);
+        assert!(check_tile_conservation(&state).is_ok());
         
-        let score = calculate_wall_tile_score(&wall, 1, 2);
-        assert_eq!(score, 5, "Complete horizontal row should score 5 points");
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: State = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, state);
+        
+        // Fresh states leave the field out of JSON entirely
+        let fresh = serde_json::to_string(&State::new_test_state()).unwrap();
+        assert!(!fresh.contains("drafted_counts"));
     }
 
     #[test]
-    fn test_scoring_vertical_3() {
-        use crate::rules::scoring::calculate_wall_tile_score;
-        use crate::Wall;
+    fn test_round_one_center_fills_from_first_factory_pick() {
+        let mut state = State::new_test_state();
+        let factory_tiles: [&[(TileColor, u8)]; 5] = [
+            &[(TileColor::Blue, 2), (TileColor::Red, 1), (TileColor::Yellow, 1)],
+            &[(TileColor::Black, 2), (TileColor::White, 2)],
+            &[(TileColor::Red, 3), (TileColor::Yellow, 1)],
+            &[(TileColor::White, 4)],
+            &[(TileColor::Yellow, 2), (TileColor::Black, 2)],
+        ];
+        for (factory, tiles) in state.factories.iter_mut().zip(factory_tiles) {
+            factory.extend(tiles.iter().copied());
+        }
+        for (color, count) in [
+            (TileColor::Blue, 18), (TileColor::Yellow, 16), (TileColor::Red, 16),
+            (TileColor::Black, 16), (TileColor::White, 14),
+        ] {
+            state.bag.insert(color, count);
+        }
+        assert!(check_tile_conservation(&state).is_ok());
         
-        let mut wall: Wall = [[false; 5]; 5];
-        wall[0][2] = true;
-        wall[1][2] = true;
-        wall[2][2] = true; // New tile
+        // Round start: center holds only the token, so no center moves exist
+        assert!(state.center.tiles.is_empty());
+        assert!(state.center.has_first_player_token);
+        assert!(list_legal_actions(&state, 0).iter().all(|a| a.source != ActionSource::Center));
         
-        let score = calculate_wall_tile_score(&wall, 2, 2);
-        assert_eq!(score, 3, "Vertical chain of 3 should score 3 points");
+        // First pick from a factory pushes its remnants into the center
+        let state = apply_action(&state, &DraftAction {
+            source: ActionSource::Factory(0),
+            color: TileColor::Blue,
+            destination: Destination::PatternLine(1),
+        }).unwrap();
+        assert_eq!(state.center.tiles.get(&TileColor::Red), Some(&1));
+        assert_eq!(state.center.tiles.get(&TileColor::Yellow), Some(&1));
+        assert!(state.center.has_first_player_token, "Token stays until someone takes from center");
+        
+        // Next player can now draft from the center
+        assert_eq!(state.active_player_id, 1);
+        let center_red = DraftAction {
+            source: ActionSource::Center,
+            color: TileColor::Red,
+            destination: Destination::PatternLine(0),
+        };
+        assert!(list_legal_actions(&state, 1).contains(&center_red));
+        
+        // A second factory pick keeps the token in the center
+        let state = apply_action(&state, &DraftAction {
+            source: ActionSource::Factory(1),
+            color: TileColor::Black,
+            destination: Destination::PatternLine(1),
+        }).unwrap();
+        assert_eq!(state.center.tiles.get(&TileColor::White), Some(&2));
+        assert!(state.center.has_first_player_token);
+        
+        // First center pick takes the token onto the floor
+        let state = apply_action(&state, &center_red).unwrap();
+        assert!(!state.center.has_first_player_token);
+        assert!(state.players[0].floor_line.has_first_player_token);
+        assert!(!state.players[1].floor_line.has_first_player_token);
+        assert!(check_tile_conservation(&state).is_ok());
     }
 
     #[test]
-    fn test_scoring_vertical_5() {
-        use crate::rules::scoring::calculate_wall_tile_score;
-        use crate::Wall;
+    fn test_state_diff_round_trips_legal_moves() {
+        use crate::rules::{create_rng_from_seed, refill_factories_with_rng};
+        use crate::StateDiff;
+        use rand::seq::SliceRandom;
         
-        let mut wall: Wall = [[false; 5]; 5];
+        for seed in 0..20u64 {
+            let mut rng = create_rng_from_seed(seed);
+            let mut state = State::new_test_state();
+            for color in crate::rules::ALL_COLORS {
+                state.bag.insert(color, 20);
+            }
+            refill_factories_with_rng(&mut state, &mut rng);
+            
+            loop {
+                let actions = list_legal_actions(&state, state.active_player_id);
+                let Some(action) = actions.choose(&mut rng) else {
+                    break;
+                };
+                let next = apply_action(&state, action).unwrap();
+                
+                let diff = state.diff(&next);
+                assert_eq!(state.apply_diff(&diff), next, "seed {} action {:?}", seed, action);
+                
+                // A single move touches at most one factory and one player
+                // (none, if a full floor sends every tile to the lid)
+                assert!(diff.factories.len() <= 1);
+                assert!(diff.players.len() <= 1);
+                assert!(diff.bag.is_none());
+                if diff.lid.is_some() {
+                    let mover = &next.players[state.active_player_id as usize];
+                    assert_eq!(crate::rules::floor_slots_used(&mover.floor_line), 7,
+                        "Only a full floor sends tiles to the lid");
+                }
+                
+                let json = serde_json::to_string(&diff).unwrap();
+                let restored: StateDiff = serde_json::from_str(&json).unwrap();
+                assert_eq!(state.apply_diff(&restored), next);
+                
+                state = next;
+            }
+        }
+    }
+
+    /// Assert `list_legal_actions` and `apply_action` agree for `player_id`
+    ///
+    /// Tries every source/color/destination combination: an action must be
+    /// listed exactly when it applies. A non-active `player_id` is checked as
+    /// if it were their turn.
+    fn assert_legal_actions_applicable(state: &State, player_id: u8) {
+        let legal = list_legal_actions(state, player_id);
+        let mut mover = state.clone();
+        mover.active_player_id = player_id;
+        
+        let sources = (0..state.factories.len())
+            .map(ActionSource::Factory)
+            .chain(std::iter::once(ActionSource::Center));
+        let mut applicable = 0;
+        for source in sources {
+            for color in crate::rules::ALL_COLORS {
+                let destinations = (0..5)
+                    .map(Destination::PatternLine)
+                    .chain(std::iter::once(Destination::Floor));
+                for destination in destinations {
+                    let action = DraftAction { source: source.clone(), color, destination };
+                    let result = apply_action(&mover, &action);
+                    assert_eq!(legal.contains(&action), result.is_ok(),
+                        "player {} action {:?}: listed={} apply={:?}",
+                        player_id, action, legal.contains(&action), result.err());
+                    if let Ok(next) = result {
+                        assert!(check_tile_conservation(&next).is_ok(), "{:?}", action);
+                        applicable += 1;
+                    }
+                }
+            }
+        }
+        assert_eq!(applicable, legal.len(), "list_legal_actions has duplicates");
+    }
+
+    #[test]
+    fn test_legal_actions_apply_on_fixtures() {
+        let fixtures: Vec<State> = vec![
+            create_start_of_round_state(),
+            create_mid_round_state(),
+            create_nearly_complete_round(),
+            serde_json::from_str(include_str!("../../tests/fixtures/mid_game_state.json")).unwrap(),
+            serde_json::from_str(include_str!(
+                "../../tests/fixtures/evaluator_benchmark_state.json"
+            )).unwrap(),
+        ];
+        for state in &fixtures {
+            assert_legal_actions_applicable(state, 0);
+            assert_legal_actions_applicable(state, 1);
+        }
+    }
+
+    #[test]
+    fn test_no_action_applies_after_game_over() {
+        let mut state = create_start_of_round_state();
+        state.is_terminal = true;
+        
+        assert_legal_actions_applicable(&state, 0);
+        let action = DraftAction {
+            source: ActionSource::Factory(0),
+            color: TileColor::Blue,
+            destination: Destination::Floor,
+        };
+        assert_eq!(apply_action(&state, &action).unwrap_err().code, "GAME_OVER");
+    }
+
+    #[test]
+    fn test_legal_actions_apply_on_generated_states() {
+        use crate::model::GameStage;
+        use crate::rules::{create_rng_from_seed, generate_scenario, GeneratorParams, PolicyMix};
+        use rand::seq::SliceRandom;
+        
+        let stages = [GameStage::Early, GameStage::Mid, GameStage::Late];
+        let mut generated = 0;
+        for seed in 0..24u64 {
+            let params = GeneratorParams {
+                target_game_stage: stages[seed as usize % 3],
+                seed,
+                policy_mix: PolicyMix::AllRandom,
+                ..GeneratorParams::default()
+            };
+            // Some seeds cannot reach the target stage; they prove nothing here
+            let Ok(mut state) = generate_scenario(params) else {
+                continue;
+            };
+            generated += 1;
+            
+            // Walk the rest of the round, checking every position on the way
+            let mut rng = create_rng_from_seed(seed);
+            loop {
+                assert_legal_actions_applicable(&state, 0);
+                assert_legal_actions_applicable(&state, 1);
+                let actions = list_legal_actions(&state, state.active_player_id);
+                let Some(action) = actions.choose(&mut rng) else {
+                    break;
+                };
+                state = apply_action(&state, action).unwrap();
+            }
+        }
+        assert!(generated >= 12, "only {} seeds generated a state", generated);
+    }
+
+    // ============================================================
+    // Wall tile scoring golden tests (Sprint 03B)
+    // ============================================================
+
+    #[test]
+    fn test_scoring_isolated_tile() {
+        use crate::rules::scoring::calculate_wall_tile_score;
+        use crate::Wall;
+        
+        let mut wall: Wall = [[false; 5]; 5];
+        wall[2][2] = true; // Isolated tile
+        
+        let score = calculate_wall_tile_score(&wall, 2, 2);
+        assert_eq!(score, 1, "Isolated tile should score 1 point");
+    }
+
+    #[test]
+    fn test_scoring_horizontal_2() {
+        use crate::rules::scoring::calculate_wall_tile_score;
+        use crate::Wall;
+        
+        let mut wall: Wall = [[false; 5]; 5];
+        wall[0][0] = true;
+        wall[0][1] = true; // Extends to 2 tiles
+        
+        let score = calculate_wall_tile_score(&wall, 0, 1);
+        assert_eq!(score, 2, "Horizontal chain of 2 should score 2 points");
+    }
+
+    #[test]
+    fn test_scoring_horizontal_5() {
+        use crate::rules::scoring::calculate_wall_tile_score;
+        use crate::Wall;
+        
+        let mut wall: Wall = [[false; 5]; 5];
+        wall[1][0] = true;
+        wall[1][1] = true;
+        wall[1][2] = true; // New tile
+        wall[1][3] = true;
+        wall[1][4] = true;
+        
+        let score = calculate_wall_tile_score(&wall, 1, 2);
+        assert_eq!(score, 5, "Complete horizontal row should score 5 points");
+    }
+
+    #[test]
+    fn test_scoring_vertical_3() {
+        use crate::rules::scoring::calculate_wall_tile_score;
+        use crate::Wall;
+        
+        let mut wall: Wall = [[false; 5]; 5];
+        wall[0][2] = true;
+        wall[1][2] = true;
+        wall[2][2] = true; // New tile
+        
+        let score = calculate_wall_tile_score(&wall, 2, 2);
+        assert_eq!(score, 3, "Vertical chain of 3 should score 3 points");
+    }
+
+    #[test]
+    fn test_scoring_vertical_5() {
+        use crate::rules::scoring::calculate_wall_tile_score;
+        use crate::Wall;
+        
+        let mut wall: Wall = [[false; 5]; 5];
         wall[0][3] = true;
         wall[1][3] = true;
         wall[2][3] = true; // New tile
@@ -832,6 +1542,58 @@ mod tests {
         assert_eq!(score, 10, "Full cross should score 5+5=10 points (maximum)");
     }
 
+    #[test]
+    fn test_scoring_cross_under_variant_adjacency_rules() {
+        use crate::rules::{calculate_wall_tile_score_with_ruleset, AdjacencyRule, Ruleset};
+        use crate::Wall;
+        
+        let mut wall: Wall = [[false; 5]; 5];
+        wall[2] = [true; 5];
+        for row in &mut wall {
+            row[2] = true;
+        }
+        
+        let standard = Ruleset::default();
+        assert_eq!(calculate_wall_tile_score_with_ruleset(&wall, 2, 2, &standard), 10);
+        
+        let cross_bonus = Ruleset {
+            adjacency: AdjacencyRule { cross_bonus: 1, ..AdjacencyRule::default() },
+        };
+        assert_eq!(calculate_wall_tile_score_with_ruleset(&wall, 2, 2, &cross_bonus), 11);
+        
+        let count_once = Ruleset {
+            adjacency: AdjacencyRule { count_placed_tile_twice: false, cross_bonus: 0 },
+        };
+        assert_eq!(calculate_wall_tile_score_with_ruleset(&wall, 2, 2, &count_once), 9);
+        
+        // Single-direction chains are unaffected by cross rules
+        assert_eq!(calculate_wall_tile_score_with_ruleset(&wall, 2, 0, &cross_bonus), 5);
+    }
+
+    #[test]
+    fn test_max_achievable_score_on_near_complete_wall() {
+        use crate::rules::max_achievable_score;
+        
+        let mut state = State::new_test_state();
+        state.players[0].score = 80;
+        state.players[0].wall = [[true; 5]; 5];
+        
+        // Nothing left to place: the bound is the current score
+        assert_eq!(max_achievable_score(&state, 0).unwrap(), 80);
+        
+        // One gap: at most 5 + 5 for the tile, plus row, column and color bonuses
+        state.players[0].wall[3][1] = false;
+        let bound = max_achievable_score(&state, 0).unwrap();
+        assert!(bound > state.players[0].score);
+        assert_eq!(bound, 80 + 10 + 2 + 7 + 10);
+        
+        // The opponent's empty board is bounded separately
+        assert_eq!(max_achievable_score(&state, 1).unwrap(), 245);
+        
+        let err = max_achievable_score(&state, 2).unwrap_err();
+        assert_eq!(err.code, "INVALID_PLAYER");
+    }
+
     #[test]
     fn test_scoring_l_shape() {
         use crate::rules::scoring::calculate_wall_tile_score;
@@ -1056,7 +1818,7 @@ mod tests {
             count_filled: 3,
         };
         
-        resolve_pattern_lines(&mut state);
+        resolve_pattern_lines(&mut state).unwrap();
         
         // Score should increase by 1 (isolated tile)
         assert_eq!(state.players[0].score, 11);
@@ -1087,7 +1849,7 @@ mod tests {
             count_filled: 2,
         };
         
-        resolve_pattern_lines(&mut state);
+        resolve_pattern_lines(&mut state).unwrap();
         
         // Each tile is isolated: +1 +1 = +2
         assert_eq!(state.players[0].score, 7);
@@ -1121,7 +1883,7 @@ mod tests {
         };
         
         // Resolve pattern lines (adds wall tile score)
-        resolve_pattern_lines(&mut state);
+        resolve_pattern_lines(&mut state).unwrap();
         // Score: 5 + 1 = 6
         
         // Apply floor penalties
@@ -1131,6 +1893,37 @@ mod tests {
         assert_eq!(state.players[0].score, 2);
     }
 
+    #[test]
+    fn test_projected_round_score_with_completion_and_floor() {
+        use crate::rules::scoring::projected_round_score;
+        
+        let mut state = State::new_test_state();
+        state.players[0].score = 10;
+        
+        // Adjacent to existing Blue at (0,0): Yellow at (0,1) scores 2
+        state.players[0].wall[0][0] = true;
+        state.players[0].pattern_lines[0] = PatternLine {
+            capacity: 1,
+            color: Some(TileColor::Yellow),
+            count_filled: 1,
+        };
+        // Incomplete line contributes nothing
+        state.players[0].pattern_lines[3] = PatternLine {
+            capacity: 4,
+            color: Some(TileColor::Red),
+            count_filled: 2,
+        };
+        // Token + 2 tiles: -1 -1 -2 = -4
+        state.players[0].floor_line.has_first_player_token = true;
+        state.players[0].floor_line.tiles = vec![TileColor::Black, TileColor::Black];
+        
+        let before = state.clone();
+        assert_eq!(projected_round_score(&state, 0).unwrap(), 2 - 4);
+        assert_eq!(projected_round_score(&state, 1).unwrap(), 0);
+        assert_eq!(projected_round_score(&state, 2).unwrap_err().code, "INVALID_PLAYER");
+        assert_eq!(state, before, "Projection must not modify the state");
+    }
+
     // ============================================================
     // resolve_pattern_lines tests (Sprint 03A)
     // ============================================================
@@ -1165,7 +1958,7 @@ mod tests {
             count_filled: 3,
         };
         
-        resolve_pattern_lines(&mut state);
+        resolve_pattern_lines(&mut state).unwrap();
         
         // Verify tile placed on wall (Blue at row 2, col 2)
         assert!(state.players[0].wall[2][2]);
@@ -1203,7 +1996,7 @@ mod tests {
             count_filled: 5,
         };
         
-        resolve_pattern_lines(&mut state);
+        resolve_pattern_lines(&mut state).unwrap();
         
         // Verify both tiles placed
         assert!(state.players[0].wall[0][0]); // Blue at row 0, col 0
@@ -1221,6 +2014,69 @@ mod tests {
         assert!(check_tile_conservation(&state).is_ok());
     }
 
+    #[test]
+    fn test_resolve_same_column_scores_top_to_bottom() {
+        use crate::rules::resolution::resolve_pattern_lines;
+        
+        let mut state = create_test_state_with_tiles();
+        
+        // Existing Blue at (1,1); complete Blue row 0 -> (0,0) and White row 1 -> (1,0)
+        *state.bag.get_mut(&TileColor::Blue).unwrap() -= 2;
+        *state.bag.get_mut(&TileColor::White).unwrap() -= 2;
+        state.players[0].wall[1][1] = true;
+        state.players[0].pattern_lines[0] = PatternLine {
+            capacity: 1,
+            color: Some(TileColor::Blue),
+            count_filled: 1,
+        };
+        state.players[0].pattern_lines[1] = PatternLine {
+            capacity: 2,
+            color: Some(TileColor::White),
+            count_filled: 2,
+        };
+        
+        resolve_pattern_lines(&mut state).unwrap();
+        
+        assert!(state.players[0].wall[0][0]);
+        assert!(state.players[0].wall[1][0]);
+        
+        // Top to bottom: (0,0) isolated = 1; (1,0) links horizontally with (1,1)
+        // and vertically with the just-placed (0,0) = 2 + 2 = 4. Total 5.
+        // Bottom to top would give (1,0) = 2, then (0,0) = 2, total 4.
+        assert_eq!(state.players[0].score, 5);
+        assert!(check_tile_conservation(&state).is_ok());
+    }
+
+    #[test]
+    fn test_resolve_rejects_occupied_wall_cell() {
+        use crate::rules::resolution::resolve_pattern_lines;
+        
+        let mut state = create_test_state_with_tiles();
+        
+        // Corrupt import: Red already on wall row 2, yet row 2 holds 3 Red
+        *state.bag.get_mut(&TileColor::Red).unwrap() -= 4;
+        let red_col = get_wall_column_for_color(2, TileColor::Red);
+        state.players[1].wall[2][red_col] = true;
+        state.players[1].pattern_lines[2] = PatternLine {
+            capacity: 3,
+            color: Some(TileColor::Red),
+            count_filled: 3,
+        };
+        // A valid completion for player 0 must not be applied either
+        *state.bag.get_mut(&TileColor::Blue).unwrap() -= 1;
+        state.players[0].pattern_lines[0] = PatternLine {
+            capacity: 1,
+            color: Some(TileColor::Blue),
+            count_filled: 1,
+        };
+        
+        let before = state.clone();
+        let err = resolve_pattern_lines(&mut state).unwrap_err();
+        
+        assert_eq!(err.code, "WALL_CELL_OCCUPIED");
+        assert_eq!(state, before, "State must be untouched on error");
+    }
+
     #[test]
     fn test_resolve_no_complete_pattern_lines() {
         use crate::rules::resolution::resolve_pattern_lines;
@@ -1241,7 +2097,7 @@ mod tests {
         let lid_before = state.lid.clone();
         let pattern_line_before = state.players[0].pattern_lines[2].clone();
         
-        resolve_pattern_lines(&mut state);
+        resolve_pattern_lines(&mut state).unwrap();
         
         // Verify no changes
         assert_eq!(state.players[0].wall, wall_before);
@@ -1274,7 +2130,7 @@ mod tests {
             count_filled: 4,
         };
         
-        resolve_pattern_lines(&mut state);
+        resolve_pattern_lines(&mut state).unwrap();
         
         // Verify player 0
         assert!(state.players[0].wall[1][0]); // White at row 1, col 0
@@ -1332,7 +2188,7 @@ mod tests {
         // Count tiles before
         assert!(check_tile_conservation(&state).is_ok());
         
-        resolve_pattern_lines(&mut state);
+        resolve_pattern_lines(&mut state).unwrap();
         
         // Count tiles after - should still be 100
         assert!(check_tile_conservation(&state).is_ok());
@@ -1353,7 +2209,7 @@ mod tests {
             count_filled: 1,
         };
         
-        resolve_pattern_lines(&mut state);
+        resolve_pattern_lines(&mut state).unwrap();
         
         // Verify tile on wall
         assert!(state.players[0].wall[0][1]); // Yellow at row 0, col 1
@@ -1407,7 +2263,7 @@ mod tests {
             count_filled: 5,
         };
         
-        resolve_pattern_lines(&mut state);
+        resolve_pattern_lines(&mut state).unwrap();
         
         // Verify all walls updated correctly
         assert!(state.players[0].wall[0][2]); // Red at row 0, col 2
@@ -1435,6 +2291,117 @@ mod tests {
     // End-of-round integration tests (Sprint 03C)
     // ============================================================
 
+    #[test]
+    fn test_end_of_round_steps_end_at_resolved_state() {
+        use crate::rules::{
+            end_of_round_steps_with_rng, refill_factories_with_rng, resolve_end_of_round_with_rng,
+            DraftPolicy, EndOfRoundEvent, GreedyPolicy,
+        };
+        use rand::SeedableRng;
+        
+        let mut rng = rand::rngs::StdRng::seed_from_u64(2155);
+        let mut state = create_test_state_with_tiles();
+        refill_factories_with_rng(&mut state, &mut rng);
+        let policy = GreedyPolicy::deterministic();
+        loop {
+            let actions = list_legal_actions(&state, state.active_player_id);
+            let Some(action) = policy.select_action(&state, &actions, &mut rng) else {
+                break;
+            };
+            state = apply_action(&state, &action).unwrap();
+        }
+        
+        let steps = end_of_round_steps_with_rng(
+            &state, &mut rand::rngs::StdRng::seed_from_u64(1)
+        ).unwrap();
+        let resolved = resolve_end_of_round_with_rng(
+            &state, &mut rand::rngs::StdRng::seed_from_u64(1)
+        ).unwrap();
+        assert_eq!(steps.last().unwrap().state, resolved);
+        
+        // Placements first, then penalties, cleanup and the refill
+        let placements: Vec<_> = steps.iter()
+            .filter(|s| matches!(s.event, EndOfRoundEvent::PlaceTile { .. }))
+            .collect();
+        assert!(!placements.is_empty(), "Greedy play completes some lines");
+        assert!(matches!(steps[0].event, EndOfRoundEvent::PlaceTile { .. }));
+        assert!(matches!(steps.last().unwrap().event, EndOfRoundEvent::Refill { round_number: 2 }));
+        let cleanup = steps.iter()
+            .position(|s| matches!(s.event, EndOfRoundEvent::ClearFloors { .. }))
+            .unwrap();
+        assert_eq!(cleanup, steps.len() - 2);
+        
+        // Each step's state carries its own transition
+        for step in &placements {
+            if let EndOfRoundEvent::PlaceTile { player, row, col, .. } = step.event {
+                assert!(step.state.players[player].wall[row][col]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conservation_at_every_step_of_a_round() {
+        use crate::rules::resolution::resolve_pattern_lines;
+        use crate::rules::{
+            apply_floor_penalties, clear_round_floors, refill_factories_with_rng,
+            DraftPolicy, GreedyPolicy,
+        };
+        use crate::TileMultisetExt;
+        use rand::SeedableRng;
+        
+        let mut rng = rand::rngs::StdRng::seed_from_u64(2139);
+        let assert_conserved = |state: &State, stage: &str| {
+            if let Err(e) = check_tile_conservation(state) {
+                panic!("After {}: {}", stage, e);
+            }
+        };
+        let table = |state: &State| -> u32 {
+            state.factories.iter().map(|f| f.total()).sum::<u32>() + state.center.tiles.total()
+        };
+        let floors = |state: &State| -> u32 {
+            state.players.iter().map(|p| p.floor_line.tiles.len() as u32).sum()
+        };
+        
+        // Deal: 20 tiles leave the bag for the factories (bag 80, table 20)
+        let mut state = create_test_state_with_tiles();
+        refill_factories_with_rng(&mut state, &mut rng);
+        assert_conserved(&state, "deal");
+        assert_eq!((state.bag.total(), table(&state)), (80, 20));
+        
+        // Draft: each move shifts tiles from the table to one player's board
+        let policy = GreedyPolicy::deterministic();
+        loop {
+            let actions = list_legal_actions(&state, state.active_player_id);
+            let Some(action) = policy.select_action(&state, &actions, &mut rng) else {
+                break;
+            };
+            state = apply_action(&state, &action).unwrap();
+            assert_conserved(&state, "draft");
+        }
+        assert_eq!(table(&state), 0);
+        
+        // Resolve: a full line puts 1 tile on the wall and the rest in the lid
+        resolve_pattern_lines(&mut state).unwrap();
+        assert_conserved(&state, "pattern line resolution");
+        apply_floor_penalties(&mut state);
+        assert_conserved(&state, "floor penalties");
+        
+        // Discard: floor tiles join the lid (bag still 80)
+        let lid_before = state.lid.total();
+        let floor_tiles = floors(&state);
+        clear_round_floors(&mut state);
+        assert_conserved(&state, "floor discard");
+        assert_eq!(state.lid.total(), lid_before + floor_tiles);
+        assert_eq!(state.bag.total(), 80);
+        
+        // Refill: the bag alone covers the next 20 tiles (bag 60, lid untouched)
+        let lid_before = state.lid.total();
+        refill_factories_with_rng(&mut state, &mut rng);
+        assert_conserved(&state, "refill");
+        assert_eq!((state.bag.total(), table(&state)), (60, 20));
+        assert_eq!(state.lid.total(), lid_before);
+    }
+
     #[test]
     fn test_complete_end_of_round_flow() {
         use crate::rules::end_of_round::resolve_end_of_round;
@@ -1478,6 +2445,59 @@ mod tests {
         assert!(factory_count > 0, "Factories should be refilled");
     }
 
+    #[test]
+    fn test_end_of_round_with_no_completed_lines() {
+        use crate::rules::end_of_round::resolve_end_of_round;
+        use crate::rules::calculate_floor_penalty;
+        
+        // Messy round: partial lines only, tiles on both floors
+        let mut state = create_test_state_with_tiles();
+        state.round_number = 2;
+        state.center.has_first_player_token = false;
+        for (player, (line_row, line_color, wall_row)) in
+            [(2, TileColor::Red, 0), (4, TileColor::Black, 1)].into_iter().enumerate()
+        {
+            let board = &mut state.players[player];
+            board.pattern_lines[line_row].color = Some(line_color);
+            board.pattern_lines[line_row].count_filled = 2;
+            *state.bag.get_mut(&line_color).unwrap() -= 2;
+            
+            // One tile already on the wall, isolated
+            let wall_col = get_wall_column_for_color(wall_row, TileColor::White);
+            board.wall[wall_row][wall_col] = true;
+            *state.bag.get_mut(&TileColor::White).unwrap() -= 1;
+        }
+        state.players[0].score = 10;
+        state.players[0].floor_line.has_first_player_token = true;
+        state.players[0].floor_line.tiles = vec![TileColor::Yellow, TileColor::Yellow];
+        state.players[1].score = 8;
+        state.players[1].floor_line.tiles = vec![TileColor::Blue];
+        *state.bag.get_mut(&TileColor::Yellow).unwrap() -= 2;
+        *state.bag.get_mut(&TileColor::Blue).unwrap() -= 1;
+        assert!(check_tile_conservation(&state).is_ok());
+        
+        let result = resolve_end_of_round(&state).unwrap();
+        
+        for player in 0..2 {
+            let before = &state.players[player];
+            let after = &result.players[player];
+            assert_eq!(after.score, before.score + calculate_floor_penalty(&before.floor_line));
+            assert_eq!(after.wall, before.wall, "Walls should be unchanged");
+            assert_eq!(after.pattern_lines, before.pattern_lines, "Partial lines carry over");
+            assert!(after.floor_line.tiles.is_empty());
+        }
+        assert_eq!(result.players[0].score, 6);
+        assert_eq!(result.players[1].score, 7);
+        
+        let factory_count: u8 = result.factories.iter()
+            .map(|f| f.values().sum::<u8>())
+            .sum();
+        assert_eq!(factory_count, 20, "Factories should refill to 20");
+        assert_eq!(result.round_number, 3);
+        assert_eq!(result.active_player_id, 0);
+        assert!(check_tile_conservation(&result).is_ok());
+    }
+
     #[test]
     fn test_bag_refill_from_lid() {
         use crate::rules::end_of_round::resolve_end_of_round;
@@ -1526,15 +2546,57 @@ mod tests {
     }
 
     #[test]
-    fn test_partial_factory_fill_late_game() {
-        use crate::rules::refill::refill_factories;
+    fn test_completed_row_ends_game_with_bonuses_and_no_actions() {
+        use crate::rules::end_of_round::resolve_end_of_round;
+        use crate::rules::get_wall_color;
         
-        let mut state = State::new_test_state();
+        let mut state = create_test_state_with_tiles();
+        state.center.has_first_player_token = false;
+        state.players[1].floor_line.has_first_player_token = true;
         
-        // Setup: Only 6 tiles available (bag + lid)
-        state.bag.insert(TileColor::Blue, 3);
-        state.bag.insert(TileColor::Red, 2);
-        state.lid.insert(TileColor::Yellow, 1);
+        // Player 0: Blue at [0][0] completes both row 0 and column 0
+        let mut wall_cells: Vec<(usize, usize)> = (1..5).map(|col| (0, col)).collect();
+        wall_cells.extend((1..5).map(|row| (row, 0)));
+        for (row, col) in wall_cells {
+            state.players[0].wall[row][col] = true;
+            *state.bag.get_mut(&get_wall_color(row, col)).unwrap() -= 1;
+        }
+        state.players[0].pattern_lines[0].color = Some(TileColor::Blue);
+        state.players[0].pattern_lines[0].count_filled = 1;
+        *state.bag.get_mut(&TileColor::Blue).unwrap() -= 1;
+        
+        // Player 1 also finishes a line this round
+        state.players[1].pattern_lines[1].color = Some(TileColor::Red);
+        state.players[1].pattern_lines[1].count_filled = 2;
+        *state.bag.get_mut(&TileColor::Red).unwrap() -= 2;
+        
+        let result = resolve_end_of_round(&state).unwrap();
+        
+        assert!(result.is_terminal);
+        // Placement 5 + 5, then row bonus 2 and column bonus 7
+        assert_eq!(result.players[0].score, 19);
+        // Opponent's final round is still scored (the token costs 1, floored at 0)
+        assert!(result.players[1].wall[1][get_wall_column_for_color(1, TileColor::Red)]);
+        assert_eq!(result.players[1].score, 0);
+        assert!(list_legal_actions(&result, 0).is_empty());
+        assert!(list_legal_actions(&result, 1).is_empty());
+        assert!(check_tile_conservation(&result).is_ok());
+        
+        let json = serde_json::to_string(&result).unwrap();
+        let restored: State = serde_json::from_str(&json).unwrap();
+        assert!(restored.is_terminal);
+    }
+
+    #[test]
+    fn test_partial_factory_fill_late_game() {
+        use crate::rules::refill::refill_factories;
+        
+        let mut state = State::new_test_state();
+        
+        // Setup: Only 6 tiles available (bag + lid)
+        state.bag.insert(TileColor::Blue, 3);
+        state.bag.insert(TileColor::Red, 2);
+        state.lid.insert(TileColor::Yellow, 1);
         
         refill_factories(&mut state);
         
@@ -1549,6 +2611,38 @@ mod tests {
         assert_eq!(state.lid.values().sum::<u8>(), 0);
     }
 
+    #[test]
+    fn test_refill_simulation_converges_to_analytic_probabilities() {
+        use crate::rules::refill::{refill_color_probabilities, simulate_refill_outcomes};
+        
+        // Skewed 18-tile bag forces the lid top-up
+        let mut state = State::new_test_state();
+        state.bag.insert(TileColor::Blue, 12);
+        state.bag.insert(TileColor::Red, 6);
+        state.lid.insert(TileColor::White, 10);
+        
+        let probabilities = refill_color_probabilities(&state);
+        assert!((probabilities[&TileColor::Blue] - 12.0 / 28.0).abs() < 1e-9);
+        assert_eq!(probabilities[&TileColor::Yellow], 0.0);
+        
+        let max_error = |samples: usize| {
+            let simulation = simulate_refill_outcomes(&state, samples, 2110);
+            assert_eq!(simulation.tiles_drawn, 20);
+            simulation.colors.iter()
+                .map(|dist| {
+                    let expected = probabilities[&dist.color] * simulation.tiles_drawn as f64;
+                    (dist.mean - expected).abs()
+                })
+                .fold(0.0, f64::max)
+        };
+        
+        let coarse = max_error(20);
+        let fine = max_error(5000);
+        
+        assert!(fine < 0.1, "Empirical means should approach analytic, error {}", fine);
+        assert!(fine < coarse, "Error should shrink with samples ({} vs {})", fine, coarse);
+    }
+
     #[test]
     fn test_first_player_determination() {
         use crate::rules::end_of_round::resolve_end_of_round;
@@ -1570,6 +2664,38 @@ mod tests {
         assert!(!result.players[1].floor_line.has_first_player_token);
     }
 
+    #[test]
+    fn test_double_claimed_token_resolves_to_floor_holder() {
+        use crate::rules::end_of_round::resolve_end_of_round;
+        use crate::rules::check_first_player_token_unique;
+        
+        // Imported mid-flow: player 1 took the token but the center still claims it
+        let mut state = create_test_state_with_tiles();
+        state.center.has_first_player_token = true;
+        state.players[1].floor_line.has_first_player_token = true;
+        state.players[1].score = 5;
+        state.active_player_id = 0;
+        assert!(check_first_player_token_unique(&state).is_err());
+        
+        let result = resolve_end_of_round(&state).unwrap();
+        
+        // Floor wins: player 1 pays for the token and starts next round
+        assert_eq!(result.active_player_id, 1);
+        assert_eq!(result.players[1].score, 4);
+        
+        // The center holds the only token again
+        assert!(result.center.has_first_player_token);
+        assert!(!result.players[1].floor_line.has_first_player_token);
+        assert!(check_first_player_token_unique(&result).is_ok());
+        
+        // Both floors claiming it is flagged too
+        let mut both_floors = create_test_state_with_tiles();
+        both_floors.center.has_first_player_token = false;
+        both_floors.players[0].floor_line.has_first_player_token = true;
+        both_floors.players[1].floor_line.has_first_player_token = true;
+        assert!(check_first_player_token_unique(&both_floors).is_err());
+    }
+
     #[test]
     fn test_tile_conservation_through_end_of_round() {
         use crate::rules::end_of_round::resolve_end_of_round;
@@ -1622,6 +2748,42 @@ mod tests {
         assert!(!check_game_end(&state));
     }
 
+    #[test]
+    fn test_clear_round_floors_discards_without_scoring() {
+        use crate::rules::end_of_round::clear_round_floors;
+        
+        let mut state = create_test_state_with_tiles();
+        *state.bag.get_mut(&TileColor::Red).unwrap() -= 2;
+        *state.bag.get_mut(&TileColor::Black).unwrap() -= 4;
+        
+        state.players[0].score = 12;
+        state.players[1].score = 7;
+        state.players[0].floor_line.tiles = vec![TileColor::Red, TileColor::Red];
+        state.players[1].floor_line.tiles = vec![TileColor::Black];
+        state.players[1].floor_line.has_first_player_token = true;
+        state.center.has_first_player_token = false;
+        // Pattern line progress is untouched
+        state.players[0].pattern_lines[2] = PatternLine {
+            capacity: 3,
+            color: Some(TileColor::Black),
+            count_filled: 3,
+        };
+        
+        clear_round_floors(&mut state);
+        
+        assert!(state.players[0].floor_line.tiles.is_empty());
+        assert!(state.players[1].floor_line.tiles.is_empty());
+        assert!(!state.players[1].floor_line.has_first_player_token);
+        assert!(state.center.has_first_player_token);
+        assert_eq!(state.lid.get(&TileColor::Red), Some(&2));
+        assert_eq!(state.lid.get(&TileColor::Black), Some(&1));
+        
+        assert_eq!(state.players[0].score, 12);
+        assert_eq!(state.players[1].score, 7);
+        assert_eq!(state.players[0].pattern_lines[2].count_filled, 3);
+        assert!(check_tile_conservation(&state).is_ok());
+    }
+
     // =====================================================================
     // Shared Test Helpers (Sprint 5A+)
     // =====================================================================
@@ -1792,6 +2954,159 @@ mod tests {
         use super::*;
         use crate::rules::{simulate_rollout, RolloutConfig, RolloutError, PolicyMix};
 
+        #[test]
+        fn test_floor_averse_profile_rarely_dumps_to_floor() {
+            use crate::rules::{refill_factories_with_rng, GreedyWeights};
+            use rand::rngs::StdRng;
+            use rand::SeedableRng;
+            
+            let floor_averse = PolicyMix::Profile {
+                weights: GreedyWeights { floor_avoidance: 50.0, ..GreedyWeights::default() },
+            };
+            // Tiles both players put on the floor over a full rollout each
+            let floor_tiles_drafted = |policy: PolicyMix| -> usize {
+                (0..100)
+                    .map(|seed| {
+                        let mut state = create_test_state_with_tiles();
+                        refill_factories_with_rng(&mut state, &mut StdRng::seed_from_u64(seed));
+                        let config = RolloutConfig {
+                            active_player_policy: policy,
+                            opponent_policy: policy,
+                            seed,
+                            max_actions: 100,
+                        };
+                        let result = simulate_rollout(&state, &config).unwrap();
+                        result.drafted_floors.iter().map(|floor| floor.tiles.len()).sum::<usize>()
+                    })
+                    .sum()
+            };
+            
+            let greedy = floor_tiles_drafted(PolicyMix::AllGreedy);
+            let profile = floor_tiles_drafted(floor_averse);
+            assert!(profile * 2 < greedy, "profile {} vs greedy {}", profile, greedy);
+        }
+
+        #[test]
+        fn test_game_rollout_stops_once_outcome_is_decided() {
+            use crate::rules::{simulate_game_rollout, refill_factories_with_rng, max_achievable_score};
+            use rand::rngs::StdRng;
+            use rand::SeedableRng;
+            
+            let mut state = create_test_state_with_tiles();
+            refill_factories_with_rng(&mut state, &mut StdRng::seed_from_u64(2178));
+            let config = RolloutConfig {
+                active_player_policy: PolicyMix::AllGreedy,
+                opponent_policy: PolicyMix::AllGreedy,
+                seed: 2178,
+                max_actions: 100,
+            };
+            
+            // An even game is played to its end
+            let full = simulate_game_rollout(&state, &config).unwrap();
+            assert!(full.completed_normally);
+            assert!(full.final_state.is_terminal);
+            assert!(full.final_state.round_number > 2);
+            
+            // Player 0 leads by more than player 1 can ever score
+            state.players[0].score = max_achievable_score(&state, 1).unwrap() + 1;
+            let decided = simulate_game_rollout(&state, &config).unwrap();
+            assert!(decided.completed_normally);
+            assert!(!decided.final_state.is_terminal);
+            assert_eq!(decided.final_state.round_number, 2);
+            assert!(decided.actions_simulated < full.actions_simulated);
+            assert!(decided.player_0_score > decided.player_1_score);
+        }
+
+        #[test]
+        fn test_outcome_decided_once_lead_exceeds_opponent_ceiling() {
+            use crate::rules::max_achievable_score;
+            use crate::rules::rollout::is_outcome_decided;
+            
+            // Player 1's wall is full but for the top-left cell
+            let mut state = create_test_state_with_tiles();
+            state.players[1].wall = [[true; 5]; 5];
+            state.players[1].wall[0][0] = false;
+            state.players[1].score = 60;
+            let ceiling = max_achievable_score(&state, 1).unwrap();
+            assert_eq!(ceiling, 89);
+            
+            // Matching the ceiling leaves player 1 a way to tie
+            state.players[0].score = ceiling;
+            assert!(!is_outcome_decided(&state));
+            
+            state.players[0].score = ceiling + 1;
+            assert!(is_outcome_decided(&state));
+        }
+        
+        #[test]
+        fn test_game_ending_rollout_counts_opponent_column_bonus() {
+            use crate::rules::COLUMN_BONUS;
+            
+            // Drafting is over; player 0's full row-0 line will end the game
+            let mut state = create_test_state_with_tiles();
+            state.factories.iter_mut().for_each(|f| f.clear());
+            for col in 1..5 {
+                state.players[0].wall[0][col] = true;
+            }
+            state.players[0].pattern_lines[0].color = Some(TileColor::Blue);
+            state.players[0].pattern_lines[0].count_filled = 1;
+            
+            // Player 1 has column 0 filled except the bottom cell
+            for row in 0..4 {
+                state.players[1].wall[row][0] = true;
+            }
+            let mut racing = state.clone();
+            racing.players[1].pattern_lines[4].color = Some(TileColor::Yellow);
+            racing.players[1].pattern_lines[4].count_filled = 5;
+            
+            let config = RolloutConfig {
+                active_player_policy: PolicyMix::AllGreedy,
+                opponent_policy: PolicyMix::AllGreedy,
+                seed: 2142,
+                max_actions: 100,
+            };
+            let utility = |state: &State| {
+                let result = simulate_rollout(state, &config).unwrap();
+                assert!(result.final_state.is_terminal);
+                result.player_0_score - result.player_1_score
+            };
+            
+            // Completing the column scores 5 adjacency points plus the bonus
+            assert_eq!(utility(&state) - utility(&racing), 5 + COLUMN_BONUS);
+        }
+
+        #[test]
+        fn test_rollout_round_deltas_match_resolution() {
+            use crate::rules::resolve_end_of_round;
+            
+            // Drafting is over: player 0 completes row 1, player 1 only has a floor
+            let mut state = create_test_state_with_tiles();
+            state.factories.iter_mut().for_each(|f| f.clear());
+            state.players[0].score = 12;
+            state.players[0].wall[1][2] = true;
+            state.players[0].pattern_lines[1].color = Some(TileColor::Blue);
+            state.players[0].pattern_lines[1].count_filled = 2;
+            state.players[1].score = 7;
+            state.players[1].floor_line.tiles = vec![TileColor::Red, TileColor::Red];
+            
+            let config = RolloutConfig {
+                active_player_policy: PolicyMix::AllGreedy,
+                opponent_policy: PolicyMix::AllGreedy,
+                seed: 2170,
+                max_actions: 100,
+            };
+            let result = simulate_rollout(&state, &config).unwrap();
+            let resolved = resolve_end_of_round(&state).unwrap();
+            
+            assert_eq!(result.actions_simulated, 0);
+            assert_eq!(result.player_0_round_delta, resolved.players[0].score - 12);
+            assert_eq!(result.player_1_round_delta, resolved.players[1].score - 7);
+            assert_eq!(result.player_0_score - result.player_0_round_delta, 12);
+            assert_eq!(result.player_1_score - result.player_1_round_delta, 7);
+            assert!(result.player_0_round_delta > 0);
+            assert!(result.player_1_round_delta < 0);
+        }
+
         #[test]
         fn test_rollout_completes_from_round_start() {
             let state = create_start_of_round_state();
@@ -2026,10 +3341,60 @@ mod tests {
     mod evaluator_tests {
         use super::*;
         use crate::rules::{
-            evaluate_best_move, grade_user_action, EvaluatorParams, RolloutPolicyConfig
+            evaluate_best_move, grade_user_action, EvaluatorParams, RolloutPolicyConfig, PolicyMix
         };
         use std::time::Instant;
 
+        /// Assert that `runs` evaluations of `state` serialize byte-identically
+        ///
+        /// Each run evaluates a fresh copy parsed from JSON, so its HashMaps
+        /// get new hashers and iterate in a different order. `elapsed_ms` is
+        /// wall-clock time and is zeroed before comparing; `params` should
+        /// give a time budget the clock never reaches.
+        fn assert_evaluation_deterministic(
+            state: &State,
+            player_id: u8,
+            params: &EvaluatorParams,
+            runs: usize,
+        ) {
+            let state_json = serde_json::to_string(state).unwrap();
+            let results: Vec<String> = (0..runs)
+                .map(|_| {
+                    let fresh: State = serde_json::from_str(&state_json).unwrap();
+                    let mut result = evaluate_best_move(&fresh, player_id, params).unwrap();
+                    result.metadata.elapsed_ms = 0;
+                    serde_json::to_string(&result).unwrap()
+                })
+                .collect();
+            
+            for (run, result) in results.iter().enumerate().skip(1) {
+                assert_eq!(result, &results[0], "Run {} differs from run 0", run);
+            }
+        }
+
+        #[test]
+        fn test_evaluation_is_deterministic_across_fixtures() {
+            let benchmark: State = serde_json::from_str(include_str!(
+                "../../tests/fixtures/evaluator_benchmark_state.json"
+            )).unwrap();
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 3,
+                evaluator_seed: 2161,
+                shortlist_size: 8,
+                ..EvaluatorParams::default()
+            };
+            
+            for state in [
+                create_start_of_round_state(),
+                create_mid_round_state(),
+                create_nearly_complete_round(),
+                benchmark,
+            ] {
+                assert_evaluation_deterministic(&state, state.active_player_id, &params, 3);
+            }
+        }
+
         #[test]
         fn test_evaluation_within_time_budget() {
             let state = create_start_of_round_state();
@@ -2039,6 +3404,7 @@ mod tests {
                 evaluator_seed: 12345,
                 shortlist_size: 10,
                 rollout_config: RolloutPolicyConfig::default(),
+                ..EvaluatorParams::default()
             };
             
             let start = Instant::now();
@@ -2061,6 +3427,7 @@ mod tests {
                 evaluator_seed: 67890,
                 shortlist_size: 10,
                 rollout_config: RolloutPolicyConfig::default(),
+                ..EvaluatorParams::default()
             };
             
             let result = evaluate_best_move(&state, 0, &params).unwrap();
@@ -2073,121 +3440,1303 @@ mod tests {
         }
 
         #[test]
-        fn test_deterministic_evaluation() {
-            let state = create_start_of_round_state();
-            let params = EvaluatorParams {
-                time_budget_ms: 250,
-                rollouts_per_action: 10,
-                evaluator_seed: 42,
-                shortlist_size: 0, // Disable shortlisting for full determinism
-                rollout_config: RolloutPolicyConfig::default(),
+        fn test_shortlist_keeps_underrated_clean_completion() {
+            use crate::rules::shortlist_actions;
+            
+            let mut state = State::new_test_state();
+            // 4 Blue ranks high everywhere (lots of tiles, factory bonus)
+            state.factories[0].insert(TileColor::Blue, 4);
+            // A single Black from the center cleanly completes row 0, but the
+            // heuristic ranks it below every Blue pattern-line move
+            state.center.tiles.insert(TileColor::Black, 1);
+            state.center.has_first_player_token = false;
+            state.players[1].floor_line.has_first_player_token = true;
+            
+            let clean_black = DraftAction {
+                source: ActionSource::Center,
+                color: TileColor::Black,
+                destination: Destination::PatternLine(0),
             };
             
-            let result1 = evaluate_best_move(&state, 0, &params).unwrap();
-            let result2 = evaluate_best_move(&state, 0, &params).unwrap();
+            let legal_actions = list_legal_actions(&state, 0);
+            let shortlist = shortlist_actions(&state, &legal_actions, 3);
             
-            // Same seed should produce identical results
-            assert_eq!(
-                serde_json::to_string(&result1.best_action).unwrap(),
-                serde_json::to_string(&result2.best_action).unwrap(),
-                "Best actions should be identical"
-            );
-            assert!(
-                (result1.best_action_ev - result2.best_action_ev).abs() < 0.001,
-                "EVs should be nearly identical"
-            );
+            assert_eq!(shortlist.len(), 3);
+            assert!(shortlist.contains(&clean_black), "Clean completion was pruned: {:?}", shortlist);
+            // Blue into row 3 is also clean; the single heuristic slot goes to the top-ranked rest
+            assert!(shortlist.contains(&DraftAction {
+                source: ActionSource::Factory(0),
+                color: TileColor::Blue,
+                destination: Destination::PatternLine(3),
+            }));
         }
 
         #[test]
-        fn test_different_seeds_different_evaluations() {
-            let state = create_start_of_round_state();
-            let params1 = EvaluatorParams {
-                time_budget_ms: 250,
-                rollouts_per_action: 10,
-                evaluator_seed: 111,
-                shortlist_size: 20,
-                rollout_config: RolloutPolicyConfig::default(),
+        fn test_seed_replicas_reduce_cross_run_variance() {
+            let state = create_mid_round_state();
+            let legal_actions = list_legal_actions(&state, 0);
+            let fixed_action = crate::rules::shortlist_actions(&state, &legal_actions, 5)[0].clone();
+            
+            // EV of one fixed action across several base seeds
+            let evs_across_runs = |seed_replicas: usize| -> Vec<f64> {
+                (0..8u64)
+                    .map(|run| {
+                        let params = EvaluatorParams {
+                            rollouts_per_action: 3,
+                            evaluator_seed: 1000 + run * 7919,
+                            shortlist_size: 5,
+                            seed_replicas,
+                            ..EvaluatorParams::default()
+                        };
+                        let result = evaluate_best_move(&state, 0, &params).unwrap();
+                        let candidates = result.candidates.unwrap();
+                        let candidate = candidates.iter()
+                            .find(|c| c.action == fixed_action)
+                            .unwrap();
+                        assert_eq!(candidate.rollouts, 3 * seed_replicas);
+                        candidate.ev
+                    })
+                    .collect()
             };
-            let params2 = EvaluatorParams {
-                evaluator_seed: 222,
-                ..params1.clone()
+            let variance = |values: &[f64]| {
+                let avg = values.iter().sum::<f64>() / values.len() as f64;
+                values.iter().map(|v| (v - avg).powi(2)).sum::<f64>() / values.len() as f64
             };
             
-            let result1 = evaluate_best_move(&state, 0, &params1).unwrap();
-            let result2 = evaluate_best_move(&state, 0, &params2).unwrap();
+            let single = variance(&evs_across_runs(1));
+            let replicated = variance(&evs_across_runs(3));
             
-            // Different seeds should likely produce different EVs (probabilistic)
-            // Note: Actions might be same if clearly dominant
-            assert_ne!(
-                (result1.best_action_ev * 100.0) as i32,
-                (result2.best_action_ev * 100.0) as i32,
-                "Different seeds should produce different EVs"
+            assert!(
+                replicated < single,
+                "3 replicas should vary less across runs ({} vs {})",
+                replicated,
+                single
             );
         }
 
         #[test]
-        fn test_grade_user_action() {
-            let state = create_start_of_round_state();
+        fn test_redacted_bag_still_evaluates_with_sampled_bags() {
+            let mut state = create_mid_round_state();
+            // Earlier rounds have discarded tiles to the lid
+            for (color, count) in [(TileColor::Blue, 6), (TileColor::Red, 4)] {
+                *state.bag.get_mut(&color).unwrap() -= count;
+                state.lid.insert(color, count);
+            }
+            let bag_total: u32 = state.bag.values().map(|&c| c as u32).sum();
+            
+            let redacted = crate::rules::redact_bag(&state);
+            assert!(redacted.hidden_bag);
+            assert!(redacted.bag.is_empty());
+            assert!(check_tile_conservation(&redacted).is_ok());
+            assert_eq!(crate::rules::hidden_bag_total(&redacted), bag_total);
+            
+            // Each sampled bag is a complete, conserving state of the known size
+            let mut distinct_bags = std::collections::HashSet::new();
+            for seed in 0..20u64 {
+                let mut rng = crate::rules::create_rng_from_seed(seed);
+                let sample = crate::rules::sample_hidden_bag(&redacted, &mut rng);
+                assert!(!sample.hidden_bag);
+                assert!(check_tile_conservation(&sample).is_ok());
+                assert_eq!(sample.bag.values().map(|&c| c as u32).sum::<u32>(), bag_total);
+                assert_eq!(sample.lid.values().map(|&c| c as u32).sum::<u32>(), 10);
+                
+                let mut bag: Vec<_> = sample.bag.into_iter().collect();
+                bag.sort();
+                distinct_bags.insert(bag);
+            }
+            assert!(distinct_bags.len() > 1, "Samples should vary with the seed");
+            
             let params = EvaluatorParams {
-                time_budget_ms: 250,
-                rollouts_per_action: 10,
-                evaluator_seed: 555,
-                shortlist_size: 20,
-                rollout_config: RolloutPolicyConfig::default(),
+                rollouts_per_action: 3,
+                evaluator_seed: 2115,
+                shortlist_size: 5,
+                ..EvaluatorParams::default()
             };
+            let result = evaluate_best_move(&redacted, 0, &params).unwrap();
+            assert!(result.best_action_ev.is_finite());
+            assert!(result.metadata.rollouts_run > 0);
+        }
+
+        #[test]
+        fn test_classify_theme_avoid_overflow() {
+            use crate::ScenarioTheme;
             
-            // Evaluate best move
-            let best_result = evaluate_best_move(&state, 0, &params).unwrap();
+            // Player 0 can only place Blue cleanly (row 3); every other
+            // placement overflows into row 0 or dumps to the floor.
+            let mut state = State::new_test_state();
+            for factory in &mut state.factories {
+                factory.clear();
+            }
+            state.factories[0].insert(TileColor::Blue, 4);
+            state.center.tiles.insert(TileColor::Red, 4);
+            state.center.has_first_player_token = false;
+            state.players[1].floor_line.has_first_player_token = true;
+            
+            let player = &mut state.players[0];
+            for color in [TileColor::Red, TileColor::Black, TileColor::White, TileColor::Yellow] {
+                player.wall[3][get_wall_column_for_color(3, color)] = true;
+            }
+            for (row, color) in [(1, TileColor::White), (2, TileColor::Black), (4, TileColor::Yellow)] {
+                player.pattern_lines[row].color = Some(color);
+                player.pattern_lines[row].count_filled = row as u8 + 1;
+            }
+            
+            // Remaining tiles in the bag
+            for color in crate::rules::ALL_COLORS {
+                state.bag.insert(color, 20);
+            }
+            for (color, used) in [
+                (TileColor::Blue, 4),
+                (TileColor::Red, 5),
+                (TileColor::Black, 4),
+                (TileColor::White, 3),
+                (TileColor::Yellow, 6),
+            ] {
+                *state.bag.get_mut(&color).unwrap() -= used;
+            }
+            assert!(check_tile_conservation(&state).is_ok());
+            
+            let params = EvaluatorParams {
+                rollouts_per_action: 4,
+                evaluator_seed: 2118,
+                shortlist_size: 0,
+                ..EvaluatorParams::default()
+            };
+            let theme = crate::rules::classify_scenario_theme(&state, &params).unwrap();
+            
+            assert_eq!(theme, ScenarioTheme::AvoidOverflow);
+        }
+
+        #[test]
+        fn test_forced_dump_when_every_row_is_blocked_for_red() {
+            use crate::rules::{apply_quality_filters, projected_round_score, FilterConfig};
+            use crate::model::ScenarioTheme;
+            
+            // Red fills rows 0-2 of the wall; rows 3-4 hold other colors
+            let mut state = create_test_state_with_tiles();
+            for factory in &mut state.factories {
+                factory.clear();
+                factory.insert(TileColor::Red, 2);
+            }
+            state.center.tiles.clear();
+            state.center.tiles.insert(TileColor::Red, 1);
+            state.center.has_first_player_token = true;
+            
+            let player = &mut state.players[0];
+            player.score = 10;
+            for row in 0..3 {
+                player.wall[row][get_wall_column_for_color(row, TileColor::Red)] = true;
+            }
+            player.pattern_lines[3].color = Some(TileColor::Blue);
+            player.pattern_lines[3].count_filled = 2;
+            player.pattern_lines[4].color = Some(TileColor::Yellow);
+            player.pattern_lines[4].count_filled = 1;
+            
+            *state.bag.get_mut(&TileColor::Red).unwrap() -= 14;
+            *state.bag.get_mut(&TileColor::Blue).unwrap() -= 2;
+            *state.bag.get_mut(&TileColor::Yellow).unwrap() -= 1;
+            assert!(check_tile_conservation(&state).is_ok());
             
-            // Get a different legal action (not the best)
             let legal_actions = list_legal_actions(&state, 0);
-            let user_action = legal_actions.iter()
-                .find(|a| *a != &best_result.best_action)
-                .expect("Should have at least 2 legal actions")
-                .clone();
+            assert_eq!(legal_actions.len(), 6, "Five factories and the center");
+            assert!(legal_actions.iter().all(|a| {
+                a.color == TileColor::Red && a.destination == Destination::Floor
+            }));
             
-            // Grade user action
-            let graded = grade_user_action(&state, 0, &user_action, &params, &best_result).unwrap();
+            // The dump applies and scores like any floor placement
+            let dump = DraftAction {
+                source: ActionSource::Factory(0),
+                color: TileColor::Red,
+                destination: Destination::Floor,
+            };
+            let after = apply_action(&state, &dump).unwrap();
+            let board = &after.players[0];
+            assert_eq!(board.floor_line.tiles, vec![TileColor::Red; 2]);
+            assert_eq!(board.pattern_lines, state.players[0].pattern_lines);
+            assert_eq!(after.active_player_id, 1);
+            assert_eq!(projected_round_score(&after, 0).unwrap(), -2);
             
-            // Should have user EV and delta
-            assert!(graded.user_action_ev.is_some());
-            assert!(graded.delta_ev.is_some());
+            // Rejected by the default filters, kept by the forced-dump preset
+            assert!(apply_quality_filters(&state, &FilterConfig::default()).is_err());
+            assert!(apply_quality_filters(&state, &FilterConfig::forced_dump()).is_ok());
             
-            // Delta should be negative or zero (user action not better than best)
-            let delta = graded.delta_ev.unwrap();
-            assert!(delta <= 0.0);
+            let params = EvaluatorParams {
+                rollouts_per_action: 2,
+                evaluator_seed: 2152,
+                ..EvaluatorParams::default()
+            };
+            let theme = crate::rules::classify_scenario_theme(&state, &params).unwrap();
+            assert_eq!(theme, ScenarioTheme::ForcedDump);
         }
 
         #[test]
-        fn test_best_action_is_legal() {
+        fn test_exhaustive_evaluation_reaches_every_action() {
             let state = create_start_of_round_state();
+            let mut legal_actions = list_legal_actions(&state, 0);
+            legal_actions.sort();
+            assert!(legal_actions.len() > 20, "Position should exceed the default shortlist");
+            
             let params = EvaluatorParams {
-                time_budget_ms: 250,
-                rollouts_per_action: 10,
-                evaluator_seed: 777,
-                shortlist_size: 20,
-                rollout_config: RolloutPolicyConfig::default(),
+                time_budget_ms: 60_000,
+                rollouts_per_action: 1,
+                evaluator_seed: 2122,
+                shortlist_size: 0,
+                exhaustive: true,
+                ..EvaluatorParams::default()
             };
-            
             let result = evaluate_best_move(&state, 0, &params).unwrap();
-            let legal_actions = list_legal_actions(&state, 0);
             
-            // Best action must be in legal action set
-            assert!(legal_actions.contains(&result.best_action));
+            assert_eq!(result.metadata.candidates_evaluated, legal_actions.len());
+            assert_eq!(result.metadata.total_legal_actions, legal_actions.len());
+            let evaluated: Vec<DraftAction> = result.candidates.unwrap()
+                .into_iter()
+                .map(|c| c.action)
+                .collect();
+            assert_eq!(evaluated, legal_actions, "Candidates should follow canonical order");
+            
+            let capped = EvaluatorParams { shortlist_size: 5, ..params };
+            assert!(matches!(
+                evaluate_best_move(&state, 0, &capped),
+                Err(crate::rules::EvaluatorError::InvalidParams(_))
+            ));
         }
 
         #[test]
-        fn test_no_shortlisting_when_few_actions() {
-            let state = create_nearly_complete_round();
+        fn test_zero_rollouts_is_invalid_params() {
             let params = EvaluatorParams {
-                time_budget_ms: 250,
-                rollouts_per_action: 10,
-                evaluator_seed: 888,
-                shortlist_size: 20, // Larger than available
-                rollout_config: RolloutPolicyConfig::default(),
+                rollouts_per_action: 0,
+                ..EvaluatorParams::default()
             };
+            assert!(matches!(
+                evaluate_best_move(&create_start_of_round_state(), 0, &params),
+                Err(crate::rules::EvaluatorError::InvalidParams(_))
+            ));
+        }
+
+        #[test]
+        fn test_zero_time_budget_needs_rollout_cap() {
+            let state = create_start_of_round_state();
             
-            let result = evaluate_best_move(&state, 0, &params).unwrap();
+            // No time limit: the rollout cap bounds the work, so every
+            // candidate is reached
+            let unlimited = EvaluatorParams {
+                time_budget_ms: 0,
+                rollouts_per_action: 2,
+                evaluator_seed: 2157,
+                shortlist_size: 6,
+                ..EvaluatorParams::default()
+            };
+            let result = evaluate_best_move(&state, 0, &unlimited).unwrap();
+            assert!(result.metadata.completed_within_budget);
+            assert_eq!(result.metadata.rollouts_run, 2 * result.metadata.candidates_evaluated);
+            
+            let uncapped = EvaluatorParams { rollouts_per_action: 0, ..unlimited };
+            assert!(matches!(
+                evaluate_best_move(&state, 0, &uncapped),
+                Err(crate::rules::EvaluatorError::InvalidParams(_))
+            ));
+        }
+
+        #[test]
+        fn test_dumping_unusable_color_ranks_above_needed_color() {
+            use crate::rules::evaluate_dump_options;
+            
+            let mut state = State::new_test_state();
+            state.center.has_first_player_token = false;
+            state.factories[0].insert(TileColor::Red, 2);
+            state.factories[1].insert(TileColor::Blue, 2);
+            
+            // Red is wanted for row 2; Blue is already on every wall row
+            let player = &mut state.players[0];
+            player.pattern_lines[2].color = Some(TileColor::Red);
+            player.pattern_lines[2].count_filled = 1;
+            for row in 0..5 {
+                player.wall[row][get_wall_column_for_color(row, TileColor::Blue)] = true;
+            }
+            
+            let dumps = evaluate_dump_options(&state, 0);
+            assert_eq!(dumps.len(), 2);
+            assert!(dumps.iter().all(|(action, _)| action.destination == Destination::Floor));
+            
+            let (best, best_score) = &dumps[0];
+            assert_eq!(best.color, TileColor::Blue);
+            assert_eq!(*best_score, -2, "Two floor tiles and nothing wasted");
+            assert_eq!(dumps[1].0.color, TileColor::Red);
+            assert_eq!(dumps[1].1, -4, "Two floor tiles plus two usable Reds wasted");
+            
+            assert!(evaluate_dump_options(&state, 2).is_empty());
+        }
+
+        #[test]
+        fn test_safest_move_avoids_catastrophic_worst_case() {
+            use crate::rules::evaluator::safest_candidate;
+            use crate::rules::{safest_move, ActionFeatures, CandidateAction};
+            
+            let candidate = |factory: usize, ev: f64, worst_utility: i32| CandidateAction {
+                action: DraftAction {
+                    source: ActionSource::Factory(factory),
+                    color: TileColor::Blue,
+                    destination: Destination::Floor,
+                },
+                ev,
+                rollouts: 10,
+                risk_adjusted_ev: ev,
+                ev_variance: 0.0,
+                optimality_probability: 0.0,
+                worst_utility,
+                features: ActionFeatures::default(),
+            };
+            
+            // Best mean, but one rollout loses by 20
+            let gamble = candidate(0, 5.0, -20);
+            let steady = candidate(1, 2.0, 0);
+            let weaker_steady = candidate(2, 1.0, 0);
+            let candidates = [gamble, steady.clone(), weaker_steady];
+            assert_eq!(safest_candidate(&candidates).unwrap().action, steady.action);
+            
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 6,
+                evaluator_seed: 2165,
+                shortlist_size: 8,
+                ..EvaluatorParams::default()
+            };
+            let safest = safest_move(&state, 0, &params).unwrap();
+            let candidates = evaluate_best_move(&state, 0, &params).unwrap().candidates.unwrap();
+            let max_worst = candidates.iter().map(|c| c.worst_utility).max().unwrap();
+            let chosen = candidates.iter().find(|c| c.action == safest).unwrap();
+            assert_eq!(chosen.worst_utility, max_worst);
+            assert!(candidates.iter().all(|c| c.worst_utility as f64 <= c.ev));
+        }
+
+        #[test]
+        fn test_risk_aversion_prefers_low_variance_move() {
+            use crate::rules::evaluator::risk_adjusted_value;
+            
+            // Mean 2 with stddev 8 vs a steady mean of 1
+            let volatile = [10.0, -6.0, 10.0, -6.0];
+            let steady = [1.0, 1.0, 1.0, 1.0];
+            assert!(risk_adjusted_value(&volatile, 0.0) > risk_adjusted_value(&steady, 0.0));
+            assert!(risk_adjusted_value(&volatile, 0.5) < risk_adjusted_value(&steady, 0.5));
+            assert_eq!(risk_adjusted_value(&steady, 0.5), 1.0);
+            
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 6,
+                evaluator_seed: 2127,
+                shortlist_size: 8,
+                risk_aversion: 1.0,
+                ..EvaluatorParams::default()
+            };
+            let result = evaluate_best_move(&state, 0, &params).unwrap();
+            let candidates = result.candidates.unwrap();
+            
+            for candidate in &candidates {
+                assert!(candidate.risk_adjusted_ev <= candidate.ev + 1e-9);
+            }
+            let best = candidates.iter()
+                .max_by(|a, b| a.risk_adjusted_ev.partial_cmp(&b.risk_adjusted_ev).unwrap())
+                .unwrap();
+            assert_eq!(
+                candidates.iter().find(|c| c.action == result.best_action).unwrap().risk_adjusted_ev,
+                best.risk_adjusted_ev
+            );
+            assert_eq!(
+                result.best_action_ev,
+                candidates.iter().find(|c| c.action == result.best_action).unwrap().ev
+            );
+        }
+
+        #[test]
+        fn test_heuristic_prior_breaks_only_ambiguous_rankings() {
+            use crate::rules::evaluator::blend_heuristic_prior;
+            
+            // Heuristic strongly prefers A (a pattern line) over B (a floor dump)
+            let (heuristic_a, heuristic_b) = (180.0, 30.0);
+            let pick_a = |ev_a: f64, ev_b: f64, weight: f64| {
+                blend_heuristic_prior(ev_a, heuristic_a, weight)
+                    > blend_heuristic_prior(ev_b, heuristic_b, weight)
+            };
+            
+            // Ambiguous rollouts: B edges ahead on noise, the prior flips it
+            assert!(!pick_a(2.0, 2.5, 0.0));
+            assert!(pick_a(2.0, 2.5, 0.2));
+            
+            // Decisive rollouts: the prior cannot overturn a 20-point gap
+            assert!(!pick_a(-10.0, 10.0, 0.0));
+            assert!(!pick_a(-10.0, 10.0, 0.2));
+            
+            // Zero weight leaves the rollout value untouched
+            assert_eq!(blend_heuristic_prior(2.5, heuristic_b, 0.0), 2.5);
+        }
+        
+        #[test]
+        fn test_heuristic_prior_weight_is_validated() {
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                rollouts_per_action: 2,
+                evaluator_seed: 2174,
+                shortlist_size: 6,
+                heuristic_prior_weight: 1.5,
+                ..EvaluatorParams::default()
+            };
+            assert!(matches!(
+                evaluate_best_move(&state, 0, &params),
+                Err(crate::rules::EvaluatorError::InvalidParams(_))
+            ));
+            
+            // Full weight ranks on the prior alone: rankings ignore the seed
+            let params = EvaluatorParams { heuristic_prior_weight: 1.0, ..params };
+            let reseeded = EvaluatorParams { evaluator_seed: 2175, ..params.clone() };
+            let first = evaluate_best_move(&state, 0, &params).unwrap();
+            let second = evaluate_best_move(&state, 0, &reseeded).unwrap();
+            assert_eq!(first.best_action, second.best_action);
+            let rankings = |result: crate::rules::EvaluationResult| -> Vec<f64> {
+                result.candidates.unwrap().iter().map(|c| c.risk_adjusted_ev).collect()
+            };
+            assert_eq!(rankings(first), rankings(second));
+        }
+        
+        #[test]
+        fn test_sharpness_high_for_dominant_move_low_for_flat_field() {
+            use crate::rules::evaluator::sharpness_of;
+            use crate::rules::{position_sharpness, ActionFeatures, CandidateAction};
+            
+            let state = create_start_of_round_state();
+            let actions = list_legal_actions(&state, 0);
+            let candidates = |evs: &[f64]| -> Vec<CandidateAction> {
+                evs.iter().zip(&actions).map(|(&ev, action)| CandidateAction {
+                    action: action.clone(),
+                    ev,
+                    rollouts: 10,
+                    risk_adjusted_ev: ev,
+                    ev_variance: 0.0,
+                    optimality_probability: 0.0,
+                    worst_utility: ev as i32,
+                    features: ActionFeatures::default(),
+                }).collect()
+            };
+            
+            // One move is 8 points clear of a pack of equal moves
+            let dominated = candidates(&[8.0, 0.0, 0.0, -0.5, 0.5]);
+            assert!(sharpness_of(&dominated, 8.0) > 0.85);
+            
+            // Every move is within a fraction of a point
+            let flat = candidates(&[1.1, 1.0, 0.9, 1.0, 1.05, 0.95]);
+            assert!(sharpness_of(&flat, 1.1) < 0.15);
+            
+            assert_eq!(sharpness_of(&candidates(&[3.0]), 3.0), 0.0);
+            
+            let params = EvaluatorParams {
+                rollouts_per_action: 4,
+                evaluator_seed: 2180,
+                shortlist_size: 8,
+                ..EvaluatorParams::default()
+            };
+            let sharpness = position_sharpness(&state, 0, &params).unwrap();
+            assert!((0.0..1.0).contains(&sharpness));
+        }
+
+        #[test]
+        fn test_value_of_waiting_negative_when_center_is_weak() {
+            use crate::rules::value_of_waiting;
+            
+            // Center holds a lone White and the token; Factory 0's two Blues
+            // complete the Blue line in row 3
+            let mut state = create_start_of_round_state();
+            state.players[0].pattern_lines[3] = PatternLine {
+                capacity: 4,
+                color: Some(TileColor::Blue),
+                count_filled: 2,
+            };
+            state.center.tiles.insert(TileColor::White, 1);
+            state.bag.insert(TileColor::Blue, 14);
+            state.bag.insert(TileColor::White, 15);
+            
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 6,
+                evaluator_seed: 2195,
+                shortlist_size: 8,
+                ..EvaluatorParams::default()
+            };
+            let value = value_of_waiting(&state, 0, &params).unwrap();
+            assert!(value < -1.0, "value of waiting on center: {}", value);
+            
+            // With nothing in the center there is no choice to weigh
+            state.center.tiles.clear();
+            state.bag.insert(TileColor::White, 16);
+            assert_eq!(value_of_waiting(&state, 0, &params).unwrap(), 0.0);
+        }
+
+        #[test]
+        fn test_second_best_is_runner_up_candidate() {
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 4,
+                evaluator_seed: 2130,
+                shortlist_size: 8,
+                ..EvaluatorParams::default()
+            };
+            let result = evaluate_best_move(&state, 0, &params).unwrap();
+            
+            let mut candidates = result.candidates.clone().unwrap();
+            assert!(candidates.len() > 1);
+            candidates.sort_by(|a, b| b.ev.partial_cmp(&a.ev).unwrap());
+            assert_eq!(result.best_action_ev, candidates[0].ev);
+            assert_eq!(result.second_best_ev, Some(candidates[1].ev));
+            let runner_up = result.second_best_action.unwrap();
+            assert_ne!(runner_up, result.best_action);
+            assert!(candidates.iter().any(|c| c.action == runner_up && c.ev == candidates[1].ev));
+        }
+
+        #[test]
+        fn test_second_best_is_none_with_single_action() {
+            let mut state = create_test_state_with_tiles();
+            state.factories.iter_mut().for_each(|f| f.clear());
+            state.center.tiles.clear();
+            state.center.has_first_player_token = false;
+            state.factories[0].insert(TileColor::Blue, 4);
+            *state.bag.get_mut(&TileColor::Blue).unwrap() -= 4;
+            *state.bag.get_mut(&TileColor::Red).unwrap() -= 5;
+            for row in 0..5 {
+                state.players[0].pattern_lines[row].color = Some(TileColor::Red);
+                state.players[0].pattern_lines[row].count_filled = 1;
+            }
+            assert_eq!(list_legal_actions(&state, 0).len(), 1);
+            
+            let params = EvaluatorParams {
+                rollouts_per_action: 2,
+                evaluator_seed: 2130,
+                ..EvaluatorParams::default()
+            };
+            let result = evaluate_best_move(&state, 0, &params).unwrap();
+            
+            assert_eq!(result.second_best_action, None);
+            assert_eq!(result.second_best_ev, None);
+        }
+
+        #[test]
+        fn test_seed_from_state_is_reproducible_per_position() {
+            use crate::rules::{fingerprint, SeedSource};
+            
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 3,
+                evaluator_seed: SeedSource::FromState.into(),
+                shortlist_size: 6,
+                ..EvaluatorParams::default()
+            };
+            assert_eq!(params.seed_source(), SeedSource::FromState);
+            
+            let state = create_start_of_round_state();
+            let first = evaluate_best_move(&state, 0, &params).unwrap();
+            let second = evaluate_best_move(&state.clone(), 0, &params).unwrap();
+            
+            assert_eq!(first.metadata.seed, fingerprint(&state));
+            assert_eq!(first.best_action, second.best_action);
+            assert_eq!(first.best_action_ev, second.best_action_ev);
+            
+            let other = create_mid_round_state();
+            let other_result = evaluate_best_move(&other, 0, &params).unwrap();
+            assert_ne!(other_result.metadata.seed, first.metadata.seed);
+            
+            let from_json: EvaluatorParams =
+                serde_json::from_str(r#"{"evaluator_seed": "from_state"}"#).unwrap();
+            assert_eq!(from_json.seed_source(), SeedSource::FromState);
+        }
+
+        #[test]
+        fn test_excellent_grade_has_no_large_floor_penalty_bullet() {
+            use crate::rules::{FeedbackCategory, Grade, GRADE_THRESHOLDS};
+            
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 4,
+                evaluator_seed: 2150,
+                shortlist_size: 0,
+                ..EvaluatorParams::default()
+            };
+            let best_result = evaluate_best_move(&state, 0, &params).unwrap();
+            
+            let mut excellent = 0;
+            for action in list_legal_actions(&state, 0) {
+                let graded = grade_user_action(&state, 0, &action, &params, &best_result).unwrap();
+                if graded.grade != Some(Grade::Excellent) {
+                    continue;
+                }
+                excellent += 1;
+                for bullet in graded.feedback.unwrap() {
+                    assert!(
+                        !(matches!(bullet.category, FeedbackCategory::FloorPenalty)
+                            && bullet.delta > GRADE_THRESHOLDS.okay_max),
+                        "Excellent grade for {:?} came with: {}", action, bullet.text
+                    );
+                }
+            }
+            assert!(excellent >= 1, "The best action itself grades Excellent");
+            
+            // Grading the best action reuses its rollouts, so nothing differs
+            let graded_best = grade_user_action(
+                &state, 0, &best_result.best_action, &params, &best_result
+            ).unwrap();
+            assert_eq!(graded_best.user_features.as_ref(), Some(&best_result.best_features));
+            assert!(graded_best.feedback.unwrap().is_empty());
+        }
+
+        #[test]
+        fn test_include_result_state_returns_position_after_best_move() {
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 2,
+                evaluator_seed: 2151,
+                shortlist_size: 4,
+                ..EvaluatorParams::default()
+            };
+            let plain = evaluate_best_move(&state, 0, &params).unwrap();
+            assert!(plain.best_action_state.is_none());
+            assert!(!serde_json::to_string(&plain).unwrap().contains("best_action_state"));
+            
+            let params = EvaluatorParams { include_result_state: true, ..params };
+            let result = evaluate_best_move(&state, 0, &params).unwrap();
+            let after = result.best_action_state.expect("Requested the post-move state");
+            
+            assert_eq!(state.active_player_id, 0);
+            assert_eq!(after.active_player_id, 1);
+            assert_eq!(after, apply_action(&state, &result.best_action).unwrap());
+        }
+
+        #[test]
+        fn test_optimality_probabilities_reflect_ev_separation() {
+            use crate::rules::{compute_optimality_probabilities, ActionFeatures, CandidateAction};
+            
+            let candidates = |evs: &[f64], variance: f64| -> Vec<CandidateAction> {
+                evs.iter().enumerate().map(|(i, &ev)| CandidateAction {
+                    action: DraftAction {
+                        source: ActionSource::Factory(i),
+                        color: TileColor::Blue,
+                        destination: Destination::Floor,
+                    },
+                    ev,
+                    rollouts: 10,
+                    risk_adjusted_ev: ev,
+                    ev_variance: variance,
+                    optimality_probability: 0.0,
+                    worst_utility: 0,
+                    features: ActionFeatures::default(),
+                }).collect()
+            };
+            
+            let clear = compute_optimality_probabilities(&candidates(&[8.0, 1.0, 0.0], 4.0));
+            assert!(clear[0] > 0.99, "Clear best move: {:?}", clear);
+            
+            let tie = compute_optimality_probabilities(&candidates(&[1.0, 0.9, 0.95], 25.0));
+            assert!(tie.iter().all(|&p| p > 0.15 && p < 0.6), "Near tie: {:?}", tie);
+            assert!((tie.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+            
+            // Evaluation fills in the probabilities for its candidates
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 3,
+                evaluator_seed: 2153,
+                shortlist_size: 5,
+                ..EvaluatorParams::default()
+            };
+            let result = evaluate_best_move(&create_start_of_round_state(), 0, &params).unwrap();
+            let total: f64 = result.candidates.unwrap().iter().map(|c| c.optimality_probability).sum();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+
+        #[test]
+        fn test_evaluate_both_players_results_are_consistent() {
+            use crate::rules::evaluate_both_players;
+
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 3,
+                evaluator_seed: 2156,
+                shortlist_size: 5,
+                ..EvaluatorParams::default()
+            };
+
+            let (seat_0, seat_1) = evaluate_both_players(&state, &params).unwrap();
+
+            // The mover's result matches a single-seat evaluation
+            let single = evaluate_best_move(&state, state.active_player_id, &params).unwrap();
+            assert_eq!(seat_0.best_action, single.best_action);
+            assert_eq!(seat_0.best_action_ev, single.best_action_ev);
+
+            // Utility is the score margin, so the seats see negated EVs
+            assert_eq!(seat_1.best_action, seat_0.best_action);
+            assert_eq!(seat_1.best_action_ev, -seat_0.best_action_ev);
+            let candidates_0 = seat_0.candidates.unwrap();
+            let candidates_1 = seat_1.candidates.unwrap();
+            assert_eq!(candidates_0.len(), candidates_1.len());
+            for (c0, c1) in candidates_0.iter().zip(&candidates_1) {
+                assert_eq!(c0.action, c1.action);
+                assert_eq!(c0.ev, -c1.ev);
+            }
+        }
+
+        #[test]
+        fn test_waiting_seat_win_probability_is_complement() {
+            use crate::rules::{evaluate_both_players, UtilityMode};
+            
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 4,
+                evaluator_seed: 2189,
+                shortlist_size: 5,
+                utility_mode: UtilityMode::WinProbability,
+                ..EvaluatorParams::default()
+            };
+            
+            let (seat_0, seat_1) = evaluate_both_players(&state, &params).unwrap();
+            assert!((seat_0.best_action_ev + seat_1.best_action_ev - 1.0).abs() < 1e-9);
+            for (c0, c1) in seat_0.candidates.unwrap().iter().zip(&seat_1.candidates.unwrap()) {
+                assert!((0.0..=1.0).contains(&c1.ev));
+                assert!((c0.ev + c1.ev - 1.0).abs() < 1e-9);
+                assert!((c0.ev_variance - c1.ev_variance).abs() < 1e-9);
+                assert_eq!(c1.optimality_probability, 0.0);
+            }
+        }
+
+        #[test]
+        fn test_opening_book_overrides_booked_position_only() {
+            use crate::rules::{evaluate_best_move_with_book, OpeningBook};
+            
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 2,
+                evaluator_seed: 2149,
+                shortlist_size: 4,
+                ..EvaluatorParams::default()
+            };
+            let booked_state = create_start_of_round_state();
+            let booked_move = list_legal_actions(&booked_state, 0).pop().unwrap();
+            let mut book = OpeningBook::new();
+            book.insert(&booked_state, booked_move.clone());
+            
+            let result = evaluate_best_move_with_book(&booked_state, 0, &params, Some(&book)).unwrap();
+            assert_eq!(result.best_action, booked_move);
+            assert!(result.metadata.from_book);
+            assert_eq!(result.metadata.rollouts_run, 0);
+            
+            // An unbooked position is evaluated exactly as without a book
+            let other = create_mid_round_state();
+            let with_book = evaluate_best_move_with_book(&other, 0, &params, Some(&book)).unwrap();
+            let without = evaluate_best_move(&other, 0, &params).unwrap();
+            assert!(!with_book.metadata.from_book);
+            assert!(with_book.metadata.rollouts_run > 0);
+            assert_eq!(with_book.best_action, without.best_action);
+            assert_eq!(with_book.best_action_ev, without.best_action_ev);
+            
+            // A booked move that is illegal here falls back to rollouts
+            let mut bad_book = OpeningBook::new();
+            bad_book.insert(&other, DraftAction {
+                source: ActionSource::Factory(0),
+                color: TileColor::White,
+                destination: Destination::Floor,
+            });
+            let fallback = evaluate_best_move_with_book(&other, 0, &params, Some(&bad_book)).unwrap();
+            assert!(!fallback.metadata.from_book);
+            assert_eq!(fallback.best_action, without.best_action);
+        }
+
+        #[test]
+        fn test_analysis_report_has_best_action_and_candidates() {
+            use crate::rules::export_analysis;
+            
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 3,
+                evaluator_seed: 2146,
+                shortlist_size: 6,
+                ..EvaluatorParams::default()
+            };
+            let report = export_analysis(&state, 0, &params).unwrap();
+            
+            assert!(!report.candidates.is_empty());
+            assert!(report.candidates.iter().any(|c| c.action == report.best_action));
+            assert!(report.candidates.windows(2).all(|w| w[0].ev >= w[1].ev));
+            assert_eq!(report.game_stage, crate::model::GameStage::Early);
+            
+            let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+            assert!(json["best_action"].is_object());
+            assert_eq!(json["score_breakdown"].as_array().unwrap().len(), 2);
+        }
+
+        #[test]
+        fn test_plan_step_can_rely_on_earlier_step() {
+            use crate::rules::{evaluate_plan, EvaluatorError, PlacementLegality};
+            
+            // Taking Blue from factory 0 pushes the 3 Reds into the center
+            let mut state = create_test_state_with_tiles();
+            state.factories[0].insert(TileColor::Blue, 1);
+            state.factories[0].insert(TileColor::Red, 3);
+            state.factories[1].insert(TileColor::Yellow, 4);
+            *state.bag.get_mut(&TileColor::Blue).unwrap() -= 1;
+            *state.bag.get_mut(&TileColor::Red).unwrap() -= 3;
+            *state.bag.get_mut(&TileColor::Yellow).unwrap() -= 4;
+            
+            let take_blue = DraftAction {
+                source: ActionSource::Factory(0),
+                color: TileColor::Blue,
+                destination: Destination::PatternLine(0),
+            };
+            let take_red = DraftAction {
+                source: ActionSource::Center,
+                color: TileColor::Red,
+                destination: Destination::PatternLine(2),
+            };
+            let params = EvaluatorParams {
+                rollouts_per_action: 4,
+                evaluator_seed: 2138,
+                ..EvaluatorParams::default()
+            };
+            
+            let result = evaluate_plan(&state, 0, &[take_blue.clone(), take_red.clone()], &params)
+                .unwrap();
+            assert_eq!(result.opponent_moves.len(), 1);
+            assert_eq!(result.rollouts, 4);
+            assert_eq!(result.final_state.players[0].pattern_lines[2].count_filled, 3);
+            
+            // Reversed, the Reds are not in the center yet
+            match evaluate_plan(&state, 0, &[take_red, take_blue.clone()], &params) {
+                Err(EvaluatorError::IllegalPlanStep { step, legality }) => {
+                    assert_eq!(step, 0);
+                    assert_eq!(legality, PlacementLegality::SourceEmpty { color: TileColor::Red });
+                }
+                other => panic!("Expected illegal first step, got {:?}", other.map(|r| r.ev)),
+            }
+            
+            // Params are checked as strictly as for evaluate_best_move
+            let no_rollouts = EvaluatorParams { rollouts_per_action: 0, ..params };
+            assert!(matches!(
+                evaluate_plan(&state, 0, &[take_blue], &no_rollouts),
+                Err(EvaluatorError::InvalidParams(_))
+            ));
+        }
+
+        #[test]
+        fn test_deterministic_evaluation() {
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                time_budget_ms: 250,
+                rollouts_per_action: 10,
+                evaluator_seed: 42,
+                shortlist_size: 0, // Disable shortlisting for full determinism
+                rollout_config: RolloutPolicyConfig::default(),
+                ..EvaluatorParams::default()
+            };
+            
+            let result1 = evaluate_best_move(&state, 0, &params).unwrap();
+            let result2 = evaluate_best_move(&state, 0, &params).unwrap();
+            
+            // Same seed should produce identical results
+            assert_eq!(
+                serde_json::to_string(&result1.best_action).unwrap(),
+                serde_json::to_string(&result2.best_action).unwrap(),
+                "Best actions should be identical"
+            );
+            assert!(
+                (result1.best_action_ev - result2.best_action_ev).abs() < 0.001,
+                "EVs should be nearly identical"
+            );
+        }
+
+        #[test]
+        fn test_different_seeds_different_evaluations() {
+            let state = create_start_of_round_state();
+            let params1 = EvaluatorParams {
+                time_budget_ms: 250,
+                rollouts_per_action: 10,
+                evaluator_seed: 111,
+                shortlist_size: 20,
+                rollout_config: RolloutPolicyConfig::default(),
+                ..EvaluatorParams::default()
+            };
+            let params2 = EvaluatorParams {
+                evaluator_seed: 222,
+                ..params1.clone()
+            };
+            
+            let result1 = evaluate_best_move(&state, 0, &params1).unwrap();
+            let result2 = evaluate_best_move(&state, 0, &params2).unwrap();
+            
+            // Different seeds should likely produce different EVs (probabilistic)
+            // Note: Actions might be same if clearly dominant
+            assert_ne!(
+                (result1.best_action_ev * 100.0) as i32,
+                (result2.best_action_ev * 100.0) as i32,
+                "Different seeds should produce different EVs"
+            );
+        }
+
+        #[test]
+        fn test_grade_user_action() {
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                time_budget_ms: 250,
+                rollouts_per_action: 10,
+                evaluator_seed: 555,
+                shortlist_size: 20,
+                rollout_config: RolloutPolicyConfig::default(),
+                ..EvaluatorParams::default()
+            };
+            
+            // Evaluate best move
+            let best_result = evaluate_best_move(&state, 0, &params).unwrap();
+            
+            // Get a different legal action (not the best)
+            let legal_actions = list_legal_actions(&state, 0);
+            let user_action = legal_actions.iter()
+                .find(|a| *a != &best_result.best_action)
+                .expect("Should have at least 2 legal actions")
+                .clone();
+            
+            // Grade user action
+            let graded = grade_user_action(&state, 0, &user_action, &params, &best_result).unwrap();
+            
+            // Should have user EV and delta
+            assert!(graded.user_action_ev.is_some());
+            assert!(graded.delta_ev.is_some());
+            
+            // Delta should be negative or zero (user action not better than best)
+            let delta = graded.delta_ev.unwrap();
+            assert!(delta <= 0.0);
+        }
+
+        #[test]
+        fn test_floor_dump_reports_floor_penalty() {
+            let state = create_start_of_round_state();
+            let dump = list_legal_actions(&state, 0).into_iter()
+                .find(|a| a.destination == Destination::Floor)
+                .unwrap();
+            let params = EvaluatorParams {
+                rollouts_per_action: 4,
+                evaluator_seed: 2194,
+                shortlist_size: 4,
+                must_include: Some(dump.clone()),
+                ..EvaluatorParams::default()
+            };
+            
+            // Features come from the floors as drafting ended, not after
+            // resolution has cleared them
+            let best_result = evaluate_best_move(&state, 0, &params).unwrap();
+            let candidates = best_result.candidates.clone().unwrap();
+            let candidate = candidates.iter().find(|c| c.action == dump).unwrap();
+            assert!(candidate.features.expected_floor_penalty < 0.0);
+            assert!(candidate.features.expected_tiles_to_floor >= 1.0);
+            
+            // Fresh rollouts for a graded move report the same way
+            let plain_params = EvaluatorParams { must_include: None, ..params };
+            let best_result = evaluate_best_move(&state, 0, &plain_params).unwrap();
+            let graded = grade_user_action(&state, 0, &dump, &plain_params, &best_result).unwrap();
+            assert!(graded.user_features.unwrap().expected_floor_penalty < 0.0);
+        }
+
+        #[test]
+        fn test_game_ending_best_move_gets_game_end_bullet() {
+            use crate::rules::{FeedbackCategory, FeedbackThresholds};
+            
+            // Player 0's top wall row is only missing Blue, and one Blue is on offer
+            let mut state = create_start_of_round_state();
+            state.factories[0].insert(TileColor::Blue, 1);
+            state.factories[2].remove(&TileColor::Blue);
+            *state.bag.get_mut(&TileColor::Blue).unwrap() += 3;
+            for color in [TileColor::Yellow, TileColor::Red, TileColor::Black, TileColor::White] {
+                state.players[0].wall[0][get_wall_column_for_color(0, color)] = true;
+                *state.bag.get_mut(&color).unwrap() -= 1;
+            }
+            
+            // Silence the other cutoffs so only the game-end bullet can appear
+            let params = EvaluatorParams {
+                rollouts_per_action: 4,
+                evaluator_seed: 2175,
+                shortlist_size: 8,
+                feedback_thresholds: FeedbackThresholds {
+                    floor_delta: 100.0,
+                    completion_delta: 100.0,
+                    waste_delta: 100.0,
+                    adjacency_delta: 100.0,
+                    ..FeedbackThresholds::default()
+                },
+                ..EvaluatorParams::default()
+            };
+            let best_result = evaluate_best_move(&state, 0, &params).unwrap();
+            assert_eq!(best_result.best_action.color, TileColor::Blue);
+            assert_eq!(best_result.best_action.destination, Destination::PatternLine(0));
+            assert_eq!(best_result.best_features.game_end_probability, 1.0);
+            
+            // A factory move that ignores Blue, so no first-player token bullet
+            let user_action = DraftAction {
+                source: ActionSource::Factory(1),
+                color: TileColor::Yellow,
+                destination: Destination::PatternLine(4),
+            };
+            let graded = grade_user_action(&state, 0, &user_action, &params, &best_result).unwrap();
+            let bullet = graded.feedback.unwrap().into_iter()
+                .find(|b| b.category == FeedbackCategory::GameEndTrigger)
+                .expect("Should note that the game ends");
+            assert!(bullet.text.contains("ends the game"), "{}", bullet.text);
+            
+            // The row is player 0's, so player 1's moves do not trigger the end
+            let (_, seat_1) = crate::rules::evaluate_both_players(&state, &params).unwrap();
+            assert_eq!(seat_1.best_features.game_end_probability, 0.0);
+        }
+
+        #[test]
+        fn test_must_include_grades_from_candidate_ev() {
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                rollouts_per_action: 6,
+                evaluator_seed: 2181,
+                shortlist_size: 4,
+                ..EvaluatorParams::default()
+            };
+            
+            // A legal move the shortlist leaves out
+            let shortlisted = evaluate_best_move(&state, 0, &params).unwrap().candidates.unwrap();
+            let user_action = list_legal_actions(&state, 0).into_iter()
+                .find(|a| shortlisted.iter().all(|c| &c.action != a))
+                .unwrap();
+            
+            let forced_params = EvaluatorParams {
+                must_include: Some(user_action.clone()),
+                ..params.clone()
+            };
+            let best_result = evaluate_best_move(&state, 0, &forced_params).unwrap();
+            let candidates = best_result.candidates.clone().unwrap();
+            assert_eq!(candidates.len(), shortlisted.len() + 1);
+            let candidate = candidates.iter().find(|c| c.action == user_action).unwrap();
+            
+            // Shortlisted candidates keep their seeds and EVs
+            for (before, after) in shortlisted.iter().zip(&candidates) {
+                assert_eq!(before.action, after.action);
+                assert_eq!(before.ev, after.ev);
+            }
+            
+            let graded = grade_user_action(&state, 0, &user_action, &forced_params, &best_result).unwrap();
+            assert_eq!(graded.user_action_ev, Some(candidate.ev));
+            
+            // An illegal forced action is rejected
+            let mut illegal = user_action.clone();
+            illegal.source = crate::model::ActionSource::Factory(99);
+            let bad_params = EvaluatorParams { must_include: Some(illegal), ..params };
+            assert!(matches!(
+                evaluate_best_move(&state, 0, &bad_params),
+                Err(crate::rules::EvaluatorError::InvalidParams(_))
+            ));
+        }
+
+        #[test]
+        fn test_win_probability_counts_ties_as_half_wins() {
+            use crate::rules::{apply_action, simulate_rollout, PolicyMix, RolloutConfig, UtilityMode};
+            use crate::model::{ActionSource, Destination};
+            
+            // Level at 10 after player 1's token penalty, with one Blue left:
+            // row 0 wins, row 1 ties, the floor loses
+            let mut state = State::new_test_state();
+            state.center.has_first_player_token = false;
+            state.center.tiles.insert(TileColor::Blue, 1);
+            state.players[1].floor_line.has_first_player_token = true;
+            state.players[0].score = 10;
+            state.players[1].score = 11;
+            state.bag.insert(TileColor::Red, 20);
+            state.lid.insert(TileColor::Blue, 19);
+            for color in [TileColor::Yellow, TileColor::Black, TileColor::White] {
+                state.lid.insert(color, 20);
+            }
+            let place = |destination| DraftAction {
+                source: ActionSource::Center,
+                color: TileColor::Blue,
+                destination,
+            };
+            let wins = place(Destination::PatternLine(0));
+            let ties = place(Destination::PatternLine(1));
+            
+            let config = RolloutConfig {
+                active_player_policy: PolicyMix::AllGreedy,
+                opponent_policy: PolicyMix::AllGreedy,
+                seed: 2189,
+                max_actions: 100,
+            };
+            let rollout = simulate_rollout(&apply_action(&state, &ties).unwrap(), &config).unwrap();
+            assert!(rollout.tied);
+            
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 2,
+                evaluator_seed: 2189,
+                shortlist_size: 0,
+                utility_mode: UtilityMode::WinProbability,
+                ..EvaluatorParams::default()
+            };
+            let result = evaluate_best_move(&state, 0, &params).unwrap();
+            let candidates = result.candidates.unwrap();
+            let ev_of = |action: &DraftAction| {
+                candidates.iter().find(|c| &c.action == action).unwrap().ev
+            };
+            
+            assert_eq!(result.best_action, wins);
+            assert_eq!(ev_of(&wins), 1.0);
+            assert_eq!(ev_of(&ties), 0.5);
+            assert_eq!(ev_of(&place(Destination::Floor)), 0.0);
+        }
+
+        #[test]
+        fn test_token_tempo_weight_lowers_ev_of_cheap_token_handoff() {
+            use crate::rules::{apply_action, token_tempo_exposure, CandidateAction};
+            use crate::model::{ActionSource, Destination};
+            
+            // Factory 0 holds Blue 3 + Red 1
+            let mut state = create_start_of_round_state();
+            state.factories[0].insert(TileColor::Blue, 3);
+            state.factories[0].insert(TileColor::Red, 1);
+            state.bag.insert(TileColor::Blue, 15);
+            state.bag.insert(TileColor::Red, 17);
+            
+            let take = |color| DraftAction {
+                source: ActionSource::Factory(0),
+                color,
+                destination: Destination::Floor,
+            };
+            // Taking Red leaves the token beside 3 Blue for the opponent's row 3
+            let leaves_blue = take(TileColor::Red);
+            let leaves_red = take(TileColor::Blue);
+            let exposure = |action| token_tempo_exposure(&apply_action(&state, action).unwrap(), 0);
+            assert_eq!(exposure(&leaves_blue), 3.0);
+            assert_eq!(exposure(&leaves_red), 1.0);
+            
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 4,
+                evaluator_seed: 2186,
+                shortlist_size: 0,
+                ..EvaluatorParams::default()
+            };
+            let tempo_params = EvaluatorParams { token_tempo_weight: 2.0, ..params.clone() };
+            let plain = evaluate_best_move(&state, 0, &params).unwrap().candidates.unwrap();
+            let tempo = evaluate_best_move(&state, 0, &tempo_params).unwrap().candidates.unwrap();
+            
+            let ev_of = |candidates: &[CandidateAction], action: &DraftAction| {
+                candidates.iter().find(|c| &c.action == action).unwrap().ev
+            };
+            assert_eq!(ev_of(&tempo, &leaves_blue), ev_of(&plain, &leaves_blue) - 6.0);
+            assert_eq!(ev_of(&tempo, &leaves_red), ev_of(&plain, &leaves_red) - 2.0);
+        }
+
+        #[test]
+        fn test_tiered_budget_gives_tail_candidates_fewer_rollouts() {
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                time_budget_ms: 60_000,
+                rollouts_per_action: 8,
+                evaluator_seed: 2184,
+                shortlist_size: 8,
+                top_tier_size: 3,
+                tail_rollouts: 2,
+                ..EvaluatorParams::default()
+            };
+            
+            let result = evaluate_best_move(&state, 0, &params).unwrap();
+            let candidates = result.candidates.unwrap();
+            assert!(candidates.len() > 3);
+            
+            // Shortlisted candidates arrive in heuristic order
+            let counts: Vec<usize> = candidates.iter().map(|c| c.rollouts).collect();
+            assert!(counts[..3].iter().all(|&n| n == 8), "{:?}", counts);
+            assert!(counts[3..].iter().all(|&n| n == 2), "{:?}", counts);
+            assert_eq!(result.metadata.rollouts_run, counts.iter().sum::<usize>());
+            
+            // The tail cannot get more than the top tier
+            let bad_params = EvaluatorParams { tail_rollouts: 9, ..params };
+            assert!(matches!(
+                evaluate_best_move(&state, 0, &bad_params),
+                Err(crate::rules::EvaluatorError::InvalidParams(_))
+            ));
+        }
+
+        #[test]
+        fn test_grade_illegal_wall_conflict_explains_and_suggests() {
+            use crate::rules::{EvaluatorError, PlacementLegality};
+            
+            let mut state = create_start_of_round_state();
+            let source_idx = state.factories.iter()
+                .position(|f| !f.is_empty())
+                .unwrap();
+            let color = *state.factories[source_idx].keys().min().unwrap();
+            
+            // Player 0 already has that color in wall row 0 (moved from the bag)
+            let col = get_wall_column_for_color(0, color);
+            state.players[0].wall[0][col] = true;
+            *state.bag.get_mut(&color).unwrap() -= 1;
+            
+            let params = EvaluatorParams {
+                rollouts_per_action: 2,
+                evaluator_seed: 2119,
+                shortlist_size: 5,
+                ..EvaluatorParams::default()
+            };
+            let best_result = evaluate_best_move(&state, 0, &params).unwrap();
+            let user_action = DraftAction {
+                source: ActionSource::Factory(source_idx),
+                color,
+                destination: Destination::PatternLine(0),
+            };
+            
+            let err = grade_user_action(&state, 0, &user_action, &params, &best_result).unwrap_err();
+            let EvaluatorError::IllegalUserMove(illegal) = err else {
+                panic!("Expected IllegalUserMove, got {:?}", err);
+            };
+            
+            assert_eq!(illegal.action, user_action);
+            assert_eq!(illegal.legality, PlacementLegality::WallConflict { row: 0, col, color });
+            assert!(illegal.explanation.contains("Wall row 1 already has"));
+            assert!(list_legal_actions(&state, 0).contains(&illegal.best_action));
+        }
+
+        #[test]
+        fn test_best_action_is_legal() {
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                time_budget_ms: 250,
+                rollouts_per_action: 10,
+                evaluator_seed: 777,
+                shortlist_size: 20,
+                rollout_config: RolloutPolicyConfig::default(),
+                ..EvaluatorParams::default()
+            };
+            
+            let result = evaluate_best_move(&state, 0, &params).unwrap();
+            let legal_actions = list_legal_actions(&state, 0);
+            
+            // Best action must be in legal action set
+            assert!(legal_actions.contains(&result.best_action));
+        }
+
+        #[test]
+        fn test_no_shortlisting_when_few_actions() {
+            let state = create_nearly_complete_round();
+            let params = EvaluatorParams {
+                time_budget_ms: 250,
+                rollouts_per_action: 10,
+                evaluator_seed: 888,
+                shortlist_size: 20, // Larger than available
+                rollout_config: RolloutPolicyConfig::default(),
+                ..EvaluatorParams::default()
+            };
+            
+            let result = evaluate_best_move(&state, 0, &params).unwrap();
             
             // Should evaluate all actions when fewer than shortlist size
             assert_eq!(
@@ -2206,6 +4755,7 @@ mod tests {
                 evaluator_seed: 999,
                 shortlist_size: 20,
                 rollout_config: RolloutPolicyConfig::default(),
+                ..EvaluatorParams::default()
             };
             
             let start = Instant::now();
@@ -2221,6 +4771,175 @@ mod tests {
             // Likely didn't evaluate all shortlist candidates
             assert!(result.metadata.candidates_evaluated < 20);
         }
+
+        #[test]
+        fn test_evaluation_rejects_game_over_state() {
+            let mut state = create_start_of_round_state();
+            
+            // Player 0 has completed wall row 0 (one tile of each color moved out of bag)
+            for col in 0..5 {
+                state.players[0].wall[0][col] = true;
+            }
+            for count in state.bag.values_mut() {
+                *count -= 1;
+            }
+            
+            let params = EvaluatorParams {
+                time_budget_ms: 250,
+                rollouts_per_action: 10,
+                evaluator_seed: 12345,
+                shortlist_size: 20,
+                rollout_config: RolloutPolicyConfig::default(),
+                ..EvaluatorParams::default()
+            };
+            
+            // Factories still hold tiles, but the game has ended
+            assert!(!list_legal_actions(&state, 0).is_empty());
+            
+            let result = evaluate_best_move(&state, 0, &params);
+            assert_eq!(result.unwrap_err(), crate::rules::EvaluatorError::GameOver);
+        }
+
+        #[test]
+        fn test_out_of_range_active_player_is_rejected_before_shortlisting() {
+            let mut state = create_start_of_round_state();
+            state.active_player_id = 5;
+            // Enough moves that shortlisting would score them
+            assert!(list_legal_actions(&state, 0).len() > 20);
+            
+            let params = EvaluatorParams {
+                shortlist_size: 8,
+                ..EvaluatorParams::default()
+            };
+            let result = evaluate_best_move(&state, 0, &params);
+            assert_eq!(result.unwrap_err(), crate::rules::EvaluatorError::InvalidActivePlayer(5));
+        }
+
+        #[test]
+        fn test_realistic_preset_lowers_ev_against_strong_counter() {
+            let mut state = create_start_of_round_state();
+            
+            // Opponent is one Red from completing row 3 and one Yellow from row 1,
+            // next to wall tiles that make both completions score well
+            *state.bag.get_mut(&TileColor::Red).unwrap() -= 3;
+            *state.bag.get_mut(&TileColor::Yellow).unwrap() -= 1;
+            *state.bag.get_mut(&TileColor::Blue).unwrap() -= 2;
+            state.players[1].pattern_lines[3] = PatternLine {
+                capacity: 4,
+                color: Some(TileColor::Red),
+                count_filled: 3,
+            };
+            state.players[1].pattern_lines[1] = PatternLine {
+                capacity: 2,
+                color: Some(TileColor::Yellow),
+                count_filled: 1,
+            };
+            state.players[1].wall[3][get_wall_column_for_color(3, TileColor::Blue)] = true;
+            state.players[1].wall[1][get_wall_column_for_color(1, TileColor::Blue)] = true;
+            assert!(check_tile_conservation(&state).is_ok());
+            
+            let realistic = EvaluatorParams {
+                rollouts_per_action: 20,
+                evaluator_seed: 2109,
+                ..EvaluatorParams::realistic()
+            };
+            let random_opponent = EvaluatorParams {
+                rollout_config: RolloutPolicyConfig {
+                    active_player_policy: PolicyMix::AllGreedy,
+                    opponent_policy: PolicyMix::AllRandom,
+                },
+                ..realistic.clone()
+            };
+            
+            let realistic_result = evaluate_best_move(&state, 0, &realistic).unwrap();
+            let random_result = evaluate_best_move(&state, 0, &random_opponent).unwrap();
+            
+            assert!(
+                realistic_result.best_action_ev < random_result.best_action_ev,
+                "Realistic EV {} should be below random-opponent EV {}",
+                realistic_result.best_action_ev,
+                random_result.best_action_ev
+            );
+        }
+
+        #[test]
+        fn test_normalized_grades_depend_on_candidate_spread() {
+            use crate::rules::{ActionFeatures, CandidateAction, Grade};
+            
+            let state = create_start_of_round_state();
+            let params = EvaluatorParams {
+                rollouts_per_action: 2,
+                evaluator_seed: 777,
+                normalize_grades: true,
+                ..EvaluatorParams::default()
+            };
+            let best_result = evaluate_best_move(&state, 0, &params).unwrap();
+            let user_action = list_legal_actions(&state, 0).into_iter()
+                .find(|a| *a != best_result.best_action)
+                .unwrap();
+            
+            // User action is always 2.0 below the best; only the other EVs differ
+            let with_spread = |other_evs: &[f64]| {
+                let mut candidates = vec![
+                    CandidateAction {
+                        action: best_result.best_action.clone(),
+                        ev: 0.0,
+                        rollouts: 2,
+                        risk_adjusted_ev: 0.0,
+                        ev_variance: 0.0,
+                        optimality_probability: 0.0,
+                        worst_utility: 0,
+                        features: ActionFeatures::default(),
+                    },
+                    CandidateAction {
+                        action: user_action.clone(),
+                        ev: -2.0,
+                        rollouts: 2,
+                        risk_adjusted_ev: -2.0,
+                        ev_variance: 0.0,
+                        optimality_probability: 0.0,
+                        worst_utility: 0,
+                        features: ActionFeatures::default(),
+                    },
+                ];
+                candidates.extend(other_evs.iter().map(|&ev| CandidateAction {
+                    action: best_result.best_action.clone(),
+                    ev,
+                    rollouts: 2,
+                    risk_adjusted_ev: ev,
+                    ev_variance: 0.0,
+                    optimality_probability: 0.0,
+                    worst_utility: 0,
+                    features: ActionFeatures::default(),
+                }));
+                crate::rules::EvaluationResult {
+                    best_action_ev: 0.0,
+                    candidates: Some(candidates),
+                    ..best_result.clone()
+                }
+            };
+            
+            let narrow = with_spread(&[-1.0, -1.0]);
+            let wide = with_spread(&[-20.0, -30.0]);
+            
+            let graded_narrow = grade_user_action(&state, 0, &user_action, &params, &narrow).unwrap();
+            let graded_wide = grade_user_action(&state, 0, &user_action, &params, &wide).unwrap();
+            
+            // Reported delta is unchanged by normalization
+            assert_eq!(graded_narrow.delta_ev, Some(-2.0));
+            assert_eq!(graded_wide.delta_ev, Some(-2.0));
+            
+            // Stddev ~0.71 → normalized ~2.8 (Miss); stddev ~12.5 → ~0.16 (Excellent)
+            assert_eq!(graded_narrow.grade, Some(Grade::Miss));
+            assert_eq!(graded_wide.grade, Some(Grade::Excellent));
+            
+            // Without normalization both get the same raw-delta grade
+            let raw_params = EvaluatorParams { normalize_grades: false, ..params.clone() };
+            let raw_narrow = grade_user_action(&state, 0, &user_action, &raw_params, &narrow).unwrap();
+            let raw_wide = grade_user_action(&state, 0, &user_action, &raw_params, &wide).unwrap();
+            assert_eq!(raw_narrow.grade, Some(Grade::Okay));
+            assert_eq!(raw_wide.grade, Some(Grade::Okay));
+        }
     }
 
     // =====================================================================
@@ -2230,10 +4949,75 @@ mod tests {
     mod feedback_tests {
         use super::*;
         use crate::rules::{
-            compute_grade, generate_feedback_bullets, ActionFeatures, Grade,
-            count_pattern_lines_completed, calculate_floor_penalty_for_player
+            compute_grade, generate_feedback_bullets, ActionFeatures, Grade, FeedbackThresholds,
+            count_pattern_lines_completed, calculate_floor_penalty_for_player,
+            summarize_best_move
         };
 
+        #[test]
+        fn test_aggregate_grades_counts_and_longest_streak() {
+            use crate::rules::aggregate_grades;
+            
+            let grades = [
+                Grade::Good, Grade::Excellent, Grade::Miss,
+                Grade::Excellent, Grade::Good, Grade::Good, Grade::Okay,
+                Grade::Excellent,
+            ];
+            let deltas = [0.5, 0.0, 3.0, 0.1, 0.8, 0.6, 2.0, 0.2];
+            
+            let stats = aggregate_grades(&grades, Some(&deltas));
+            assert_eq!(stats.total, 8);
+            assert_eq!((stats.excellent, stats.good, stats.okay, stats.miss), (3, 3, 1, 1));
+            assert_eq!(stats.longest_streak, 3);
+            assert_eq!(stats.current_streak, 1);
+            assert!((stats.average_delta_ev.unwrap() - 0.9).abs() < 1e-9);
+            
+            assert_eq!(aggregate_grades(&grades, None).average_delta_ev, None);
+        }
+
+        #[test]
+        fn test_best_move_summary_mentions_completed_line() {
+            let mut state = create_start_of_round_state();
+            state.players[0].pattern_lines[2] = PatternLine {
+                capacity: 3,
+                color: Some(TileColor::Red),
+                count_filled: 1,
+            };
+            *state.bag.get_mut(&TileColor::Red).unwrap() -= 1;
+            
+            let action = DraftAction {
+                source: ActionSource::Factory(0),
+                color: TileColor::Red,
+                destination: Destination::PatternLine(2),
+            };
+            let features = ActionFeatures {
+                expected_completions: 1.0,
+                tiles_acquired: 2,
+                ..ActionFeatures::default()
+            };
+            
+            let summary = summarize_best_move(&features, &action, &state);
+            
+            assert!(summary.contains("Take 2 Red tiles from factory 1"), "{}", summary);
+            assert!(summary.contains("completes pattern line 3"), "{}", summary);
+            assert!(summary.contains("floor clean"), "{}", summary);
+        }
+
+        #[test]
+        fn test_evaluation_includes_best_move_summary() {
+            let state = create_start_of_round_state();
+            let params = crate::rules::EvaluatorParams {
+                rollouts_per_action: 2,
+                evaluator_seed: 2114,
+                ..crate::rules::EvaluatorParams::default()
+            };
+            
+            let result = crate::rules::evaluate_best_move(&state, 0, &params).unwrap();
+            
+            let summary = result.best_move_summary.expect("Summary should be populated");
+            assert!(summary.starts_with("Take "), "{}", summary);
+        }
+
         #[test]
         fn test_grade_computation() {
             assert_eq!(compute_grade(0.1), Grade::Excellent);
@@ -2258,6 +5042,7 @@ mod tests {
                 expected_tiles_to_floor: 2.0,
                 takes_first_player_token: false,
                 tiles_acquired: 3,
+                ..ActionFeatures::default()
             };
             
             let best_features = ActionFeatures {
@@ -2267,15 +5052,40 @@ mod tests {
                 expected_tiles_to_floor: 2.0,
                 takes_first_player_token: false,
                 tiles_acquired: 4,
+                ..ActionFeatures::default()
             };
             
-            let feedback = generate_feedback_bullets(&user_features, &best_features);
+            let feedback = generate_feedback_bullets(&user_features, &best_features, &FeedbackThresholds::default());
             
             // Should generate floor penalty feedback
             assert!(!feedback.is_empty());
             assert!(feedback.iter().any(|b| matches!(b.category, crate::rules::FeedbackCategory::FloorPenalty)));
         }
 
+        #[test]
+        fn test_strict_floor_threshold_surfaces_small_difference() {
+            let user_features = ActionFeatures {
+                expected_floor_penalty: -1.3,
+                ..ActionFeatures::default()
+            };
+            let best_features = ActionFeatures {
+                expected_floor_penalty: -1.0,
+                ..ActionFeatures::default()
+            };
+            let is_floor = |b: &crate::rules::FeedbackBullet| {
+                matches!(b.category, crate::rules::FeedbackCategory::FloorPenalty)
+            };
+            
+            let default_feedback = generate_feedback_bullets(
+                &user_features, &best_features, &FeedbackThresholds::default()
+            );
+            assert!(!default_feedback.iter().any(is_floor));
+            
+            let strict = FeedbackThresholds { floor_delta: 0.2, ..FeedbackThresholds::default() };
+            let strict_feedback = generate_feedback_bullets(&user_features, &best_features, &strict);
+            assert!(strict_feedback.iter().any(is_floor));
+        }
+
         #[test]
         fn test_feedback_generation_completions() {
             let user_features = ActionFeatures {
@@ -2285,6 +5095,7 @@ mod tests {
                 expected_tiles_to_floor: 1.0,
                 takes_first_player_token: false,
                 tiles_acquired: 3,
+                ..ActionFeatures::default()
             };
             
             let best_features = ActionFeatures {
@@ -2294,15 +5105,53 @@ mod tests {
                 expected_tiles_to_floor: 1.0,
                 takes_first_player_token: false,
                 tiles_acquired: 4,
+                ..ActionFeatures::default()
             };
             
-            let feedback = generate_feedback_bullets(&user_features, &best_features);
+            let feedback = generate_feedback_bullets(&user_features, &best_features, &FeedbackThresholds::default());
             
             // Should generate completion feedback
             assert!(!feedback.is_empty());
             assert!(feedback.iter().any(|b| matches!(b.category, crate::rules::FeedbackCategory::LineCompletion)));
         }
 
+        #[test]
+        fn test_feedback_names_colors_of_dumped_tiles() {
+            let mut state = create_start_of_round_state();
+            state.factories[0].clear();
+            state.factories[0].insert(TileColor::Red, 2);
+            state.factories[0].insert(TileColor::Blue, 2);
+            
+            let dump_red = DraftAction {
+                source: ActionSource::Factory(0),
+                color: TileColor::Red,
+                destination: Destination::Floor,
+            };
+            let place_blue = DraftAction {
+                source: ActionSource::Factory(0),
+                color: TileColor::Blue,
+                destination: Destination::PatternLine(1),
+            };
+            
+            let user_features = ActionFeatures {
+                floor_tiles_by_color: crate::rules::floor_tiles_by_color(&state, &dump_red),
+                ..ActionFeatures::default()
+            };
+            let best_features = ActionFeatures {
+                floor_tiles_by_color: crate::rules::floor_tiles_by_color(&state, &place_blue),
+                ..ActionFeatures::default()
+            };
+            assert_eq!(user_features.floor_tiles_by_color.get(&TileColor::Red), Some(&2));
+            assert!(best_features.floor_tiles_by_color.is_empty());
+            
+            let feedback = generate_feedback_bullets(&user_features, &best_features, &FeedbackThresholds::default());
+            let wasted = feedback.iter()
+                .find(|b| matches!(b.category, crate::rules::FeedbackCategory::WastedTiles))
+                .expect("Should explain the wasted tiles");
+            
+            assert!(wasted.text.contains("2 Red tiles"), "{}", wasted.text);
+        }
+
         #[test]
         fn test_feedback_sorting_and_limit() {
             let user_features = ActionFeatures {
@@ -2312,6 +5161,7 @@ mod tests {
                 expected_tiles_to_floor: 3.0,
                 takes_first_player_token: true,
                 tiles_acquired: 2,
+                ..ActionFeatures::default()
             };
             
             let best_features = ActionFeatures {
@@ -2321,9 +5171,10 @@ mod tests {
                 expected_tiles_to_floor: 0.5,
                 takes_first_player_token: false,
                 tiles_acquired: 4,
+                ..ActionFeatures::default()
             };
             
-            let feedback = generate_feedback_bullets(&user_features, &best_features);
+            let feedback = generate_feedback_bullets(&user_features, &best_features, &FeedbackThresholds::default());
             
             // Should not exceed 3 bullets
             assert!(feedback.len() <= 3);
@@ -2336,6 +5187,30 @@ mod tests {
             }
         }
 
+        #[test]
+        fn test_game_end_bullet_only_when_moves_differ() {
+            use crate::rules::FeedbackCategory;
+            
+            let ends = ActionFeatures { game_end_probability: 1.0, ..ActionFeatures::default() };
+            let plays_on = ActionFeatures::default();
+            let thresholds = FeedbackThresholds::default();
+            let game_end_bullet = |user: &ActionFeatures, best: &ActionFeatures| {
+                generate_feedback_bullets(user, best, &thresholds).into_iter()
+                    .find(|b| b.category == FeedbackCategory::GameEndTrigger)
+            };
+            
+            // Both moves end the game, so there is nothing to point out
+            assert!(game_end_bullet(&ends, &ends).is_none());
+            assert!(game_end_bullet(&plays_on, &plays_on).is_none());
+            
+            // Ranked in points like the other bullets, not by probability
+            let bullet = game_end_bullet(&ends, &plays_on).unwrap();
+            assert!(bullet.text.starts_with("Your move"), "{}", bullet.text);
+            assert!(bullet.delta > 1.0);
+            let bullet = game_end_bullet(&plays_on, &ends).unwrap();
+            assert!(bullet.text.starts_with("Best move"), "{}", bullet.text);
+        }
+
         #[test]
         fn test_count_pattern_lines_completed() {
             let mut before = crate::model::PlayerBoard::new();
@@ -2386,6 +5261,7 @@ mod tests {
                 evaluator_seed: 12345,
                 shortlist_size: 20,
                 rollout_config: crate::rules::RolloutPolicyConfig::default(),
+                ..crate::rules::EvaluatorParams::default()
             };
             
             let result = crate::rules::evaluate_best_move(&state, 0, &params).unwrap();
@@ -2405,6 +5281,7 @@ mod tests {
                 evaluator_seed: 12345,
                 shortlist_size: 20,
                 rollout_config: crate::rules::RolloutPolicyConfig::default(),
+                ..crate::rules::EvaluatorParams::default()
             };
             
             // Evaluate best move
@@ -2441,6 +5318,7 @@ mod tests {
                 evaluator_seed: 12345,
                 shortlist_size: 20,
                 rollout_config: crate::rules::RolloutPolicyConfig::default(),
+                ..crate::rules::EvaluatorParams::default()
             };
             
             // Evaluate best move
@@ -0,0 +1,129 @@
+use crate::model::{State, DraftAction, Destination, ScenarioTheme, TileColor, TileMultisetExt};
+use super::evaluator::{evaluate_best_move, EvaluatorParams, EvaluatorError, CandidateAction};
+use super::legality::list_legal_actions;
+use super::feedback::{ActionFeatures, count_tiles_in_action};
+use super::wall_utils::get_wall_column_for_color;
+
+/// Number of next-best candidates the best move is compared against
+const THEME_RIVALS: usize = 3;
+
+/// Floor points the best move must save over its rivals for `AvoidOverflow`
+const OVERFLOW_MARGIN: f64 = 2.0;
+
+/// Extra expected line completions over the rivals for `CompleteLine`
+const COMPLETION_MARGIN: f64 = 0.5;
+
+/// Classify the dominant decision a scenario showcases
+///
+/// Evaluates the active player's best move and compares its rollout
+/// features with the next-best candidates (the moves a student is most
+/// likely to weigh it against). A position where every legal move goes to
+/// the floor is `ForcedDump`; otherwise themes are checked in priority order:
+///
+/// 1. `AvoidOverflow`: the best move's expected floor penalty is at least
+///    2 points lighter than the rivals' on average
+/// 2. `TakeFirstPlayerToken`: best move takes the token and some rival
+///    does not
+/// 3. `CompleteLine`: best move completes at least half a line more
+/// 4. `BlockOpponent`: best move leaves too few tiles of its color on the
+///    table for the opponent to finish a started line of that color
+/// 5. `SetUpColumn`: best move fills a line whose wall cell is vertically
+///    adjacent to an existing wall tile
+///
+/// Anything else, including positions with a single candidate, is
+/// `General`.
+///
+/// # Errors
+///
+/// Returns `EvaluatorError` if the position cannot be evaluated.
+pub fn classify_scenario_theme(
+    state: &State,
+    params: &EvaluatorParams,
+) -> Result<ScenarioTheme, EvaluatorError> {
+    let legal_actions = list_legal_actions(state, state.active_player_id);
+    if !legal_actions.is_empty()
+        && legal_actions.iter().all(|a| matches!(a.destination, Destination::Floor))
+    {
+        return Ok(ScenarioTheme::ForcedDump);
+    }
+    
+    let result = evaluate_best_move(state, state.active_player_id, params)?;
+    
+    let mut rivals: Vec<&CandidateAction> = result.candidates
+        .iter()
+        .flatten()
+        .filter(|c| c.action != result.best_action)
+        .collect();
+    rivals.sort_by(|a, b| b.ev.total_cmp(&a.ev));
+    rivals.truncate(THEME_RIVALS);
+    
+    if rivals.is_empty() {
+        return Ok(ScenarioTheme::General);
+    }
+    
+    let best = &result.best_features;
+    let rival_mean = |feature: fn(&ActionFeatures) -> f64| {
+        rivals.iter().map(|c| feature(&c.features)).sum::<f64>() / rivals.len() as f64
+    };
+    
+    // Penalties are negative: a lighter penalty is a larger value
+    if best.expected_floor_penalty - rival_mean(|f| f.expected_floor_penalty) >= OVERFLOW_MARGIN {
+        return Ok(ScenarioTheme::AvoidOverflow);
+    }
+    
+    if best.takes_first_player_token
+        && rivals.iter().any(|c| !c.features.takes_first_player_token)
+    {
+        return Ok(ScenarioTheme::TakeFirstPlayerToken);
+    }
+    
+    if best.expected_completions - rival_mean(|f| f.expected_completions) >= COMPLETION_MARGIN {
+        return Ok(ScenarioTheme::CompleteLine);
+    }
+    
+    if blocks_opponent_line(state, &result.best_action) {
+        return Ok(ScenarioTheme::BlockOpponent);
+    }
+    
+    if sets_up_column(state, &result.best_action) {
+        return Ok(ScenarioTheme::SetUpColumn);
+    }
+    
+    Ok(ScenarioTheme::General)
+}
+
+/// Whether the action denies the opponent the tiles to finish a started line
+fn blocks_opponent_line(state: &State, action: &DraftAction) -> bool {
+    let opponent = &state.players[1 - state.active_player_id as usize];
+    let on_table = count_color_on_table(state, action.color);
+    let remaining = on_table - count_tiles_in_action(state, action) as u32;
+    
+    opponent.pattern_lines.iter().any(|line| {
+        let needed = (line.capacity - line.count_filled) as u32;
+        line.color == Some(action.color)
+            && needed > 0
+            && on_table >= needed
+            && remaining < needed
+    })
+}
+
+/// Whether the action's wall cell touches a placed tile above or below it
+fn sets_up_column(state: &State, action: &DraftAction) -> bool {
+    let row = match action.destination {
+        Destination::PatternLine(row) => row,
+        Destination::Floor => return false,
+    };
+    let wall = &state.players[state.active_player_id as usize].wall;
+    let col = get_wall_column_for_color(row, action.color);
+    
+    (row > 0 && wall[row - 1][col]) || (row + 1 < wall.len() && wall[row + 1][col])
+}
+
+/// Count tiles of a color in the factories and center
+fn count_color_on_table(state: &State, color: TileColor) -> u32 {
+    state.factories
+        .iter()
+        .chain(std::iter::once(&state.center.tiles))
+        .map(|tiles| tiles.count(color) as u32)
+        .sum()
+}
@@ -0,0 +1,106 @@
+use crate::model::TileColor;
+
+/// Log target for tile movements (enabled by the `trace` feature)
+///
+/// Each movement is one `trace`-level record such as
+/// `2 Blue: Factory(0) -> PatternLine { player: 0, row: 1 }`.
+pub const TILE_TRACE_TARGET: &str = "engine::tiles";
+
+/// Where a tile can sit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TileLocation {
+    Bag,
+    Lid,
+    Factory(usize),
+    Center,
+    PatternLine { player: usize, row: usize },
+    Wall { player: usize, row: usize },
+    Floor { player: usize },
+}
+
+/// Record `count` tiles of `color` moving from `from` to `to`
+///
+/// Compiles to nothing without the `trace` feature. Moves of zero tiles are
+/// not logged.
+#[inline]
+pub(crate) fn trace_tile_move(from: TileLocation, to: TileLocation, color: TileColor, count: u8) {
+    #[cfg(feature = "trace")]
+    if count > 0 {
+        log::trace!(target: TILE_TRACE_TARGET, "{} {:?}: {:?} -> {:?}", count, color, from, to);
+    }
+    #[cfg(not(feature = "trace"))]
+    let _ = (from, to, color, count);
+}
+
+#[cfg(all(test, feature = "trace"))]
+mod tests {
+    use super::*;
+    use crate::model::{ActionSource, Destination, DraftAction, State};
+    use crate::rules::apply_action;
+    use std::sync::Mutex;
+    use std::thread::{self, ThreadId};
+
+    /// Collects tile trace records, tagged with the thread that logged them
+    struct CaptureLogger;
+
+    static CAPTURED: Mutex<Vec<(ThreadId, String)>> = Mutex::new(Vec::new());
+    static LOGGER: CaptureLogger = CaptureLogger;
+
+    impl log::Log for CaptureLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.target() == TILE_TRACE_TARGET
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                let entry = (thread::current().id(), record.args().to_string());
+                CAPTURED.lock().unwrap().push(entry);
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// Records this thread has logged (tests run in parallel)
+    fn captured_on_this_thread() -> Vec<String> {
+        let id = thread::current().id();
+        CAPTURED.lock().unwrap()
+            .iter()
+            .filter(|(thread_id, _)| *thread_id == id)
+            .map(|(_, message)| message.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_factory_pick_traces_each_tile_movement() {
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let mut state = State::new_test_state();
+        state.factories[0].insert(TileColor::Blue, 2);
+        state.factories[0].insert(TileColor::Red, 1);
+        state.factories[0].insert(TileColor::Yellow, 1);
+        state.bag.insert(TileColor::Blue, 18);
+        state.bag.insert(TileColor::Yellow, 19);
+        state.bag.insert(TileColor::Red, 19);
+        state.bag.insert(TileColor::Black, 20);
+        state.bag.insert(TileColor::White, 20);
+
+        // Row 0 holds one Blue; the other overflows to the floor
+        let action = DraftAction {
+            source: ActionSource::Factory(0),
+            color: TileColor::Blue,
+            destination: Destination::PatternLine(0),
+        };
+        apply_action(&state, &action).unwrap();
+
+        let mut events = captured_on_this_thread();
+        events.sort();
+        assert_eq!(events, vec![
+            "1 Blue: Factory(0) -> Floor { player: 0 }",
+            "1 Blue: Factory(0) -> PatternLine { player: 0, row: 0 }",
+            "1 Red: Factory(0) -> Center",
+            "1 Yellow: Factory(0) -> Center",
+        ]);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::TileColor;
+use serde::{Deserialize, Serialize};
+use crate::{PlayerBoard, TileColor};
+use super::scoring::calculate_wall_tile_score;
 
 /// Get the wall column index for a given row and tile color
 ///
@@ -123,6 +125,128 @@ pub fn get_wall_color(row: usize, col: usize) -> TileColor {
     }
 }
 
+/// Get the wall cell a pattern line's tile moves to when the round resolves
+///
+/// Returns `(row, col)` for a complete line, or None if the line is empty,
+/// still partial, or `row` is out of range.
+///
+/// # Example
+///
+/// ```
+/// use engine::{PlayerBoard, TileColor, wall_target_for_line};
+///
+/// let mut player = PlayerBoard::new();
+/// assert_eq!(wall_target_for_line(&player, 1), None);
+///
+/// player.pattern_lines[1].color = Some(TileColor::Red);
+/// player.pattern_lines[1].count_filled = 2;
+/// assert_eq!(wall_target_for_line(&player, 1), Some((1, 3)));
+/// ```
+pub fn wall_target_for_line(player: &PlayerBoard, row: usize) -> Option<(usize, usize)> {
+    let line = player.pattern_lines.get(row)?;
+    if line.count_filled < line.capacity {
+        return None;
+    }
+
+    let color = line.color?;
+    Some((row, get_wall_column_for_color(row, color)))
+}
+
+/// Complete pattern lines and the points each scores when the round resolves
+///
+/// Returns `(row, color, points)` for every full line, top to bottom. Lines
+/// resolve in that order, so a tile placed by an upper line counts toward
+/// the adjacency of the ones below it. Nothing drafted later can change
+/// these placements, only add to them.
+///
+/// # Example
+///
+/// ```
+/// use engine::{PlayerBoard, TileColor, guaranteed_placements};
+///
+/// let mut player = PlayerBoard::new();
+/// player.pattern_lines[0].color = Some(TileColor::Blue);
+/// player.pattern_lines[0].count_filled = 1;
+/// assert_eq!(guaranteed_placements(&player), vec![(0, TileColor::Blue, 1)]);
+/// ```
+pub fn guaranteed_placements(player: &PlayerBoard) -> Vec<(usize, TileColor, i32)> {
+    let mut wall = player.wall;
+
+    (0..player.pattern_lines.len())
+        .filter_map(|row| {
+            let (row, col) = wall_target_for_line(player, row)?;
+            let color = get_wall_color(row, col);
+            wall[row][col] = true;
+            Some((row, color, calculate_wall_tile_score(&wall, row, col)))
+        })
+        .collect()
+}
+
+/// An empty wall cell that would join existing tiles when filled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct WallGap {
+    pub row: usize,
+    pub col: usize,
+    /// Color that belongs in this cell
+    pub color: TileColor,
+    /// Adjacency points the placement would score on the current wall
+    pub points: i32,
+}
+
+/// Find empty wall cells bordered by at least two placed tiles
+///
+/// Filling such a cell links separate chains, so it scores more than the
+/// tiles around it would suggest. Gaps are returned in row-major order.
+///
+/// # Example
+///
+/// ```
+/// use engine::{PlayerBoard, TileColor, wall_gaps};
+///
+/// let mut player = PlayerBoard::new();
+/// player.wall[0][0] = true;
+/// player.wall[0][2] = true;
+///
+/// let gaps = wall_gaps(&player);
+/// assert_eq!(gaps.len(), 1);
+/// assert_eq!((gaps[0].row, gaps[0].col, gaps[0].color), (0, 1, TileColor::Yellow));
+/// assert_eq!(gaps[0].points, 3);
+/// ```
+pub fn wall_gaps(player: &PlayerBoard) -> Vec<WallGap> {
+    let wall = &player.wall;
+    let mut gaps = Vec::new();
+
+    for row in 0..5 {
+        for col in 0..5 {
+            if wall[row][col] {
+                continue;
+            }
+
+            let neighbors = [
+                row > 0 && wall[row - 1][col],
+                row < 4 && wall[row + 1][col],
+                col > 0 && wall[row][col - 1],
+                col < 4 && wall[row][col + 1],
+            ];
+            if neighbors.iter().filter(|&&filled| filled).count() < 2 {
+                continue;
+            }
+
+            let mut filled = *wall;
+            filled[row][col] = true;
+            gaps.push(WallGap {
+                row,
+                col,
+                color: get_wall_color(row, col),
+                points: calculate_wall_tile_score(&filled, row, col),
+            });
+        }
+    }
+
+    gaps
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -159,4 +283,57 @@ mod tests {
             assert_eq!(colors_seen.len(), 5);
         }
     }
+
+    #[test]
+    fn test_wall_target_for_complete_line() {
+        let mut player = PlayerBoard::new();
+        player.pattern_lines[3].color = Some(TileColor::Blue);
+        player.pattern_lines[3].count_filled = 4;
+
+        assert_eq!(wall_target_for_line(&player, 3), Some((3, 3)));
+        assert_eq!(get_wall_color(3, 3), TileColor::Blue);
+    }
+
+    #[test]
+    fn test_wall_target_for_empty_or_partial_line() {
+        let mut player = PlayerBoard::new();
+        assert_eq!(wall_target_for_line(&player, 2), None);
+
+        player.pattern_lines[2].color = Some(TileColor::White);
+        player.pattern_lines[2].count_filled = 2;
+        assert_eq!(wall_target_for_line(&player, 2), None);
+        assert_eq!(wall_target_for_line(&player, 5), None);
+    }
+
+    #[test]
+    fn test_wall_gap_between_two_chains() {
+        let mut player = PlayerBoard::new();
+        // Row 2 chains: cols 0-1 and col 3, with col 2 empty
+        player.wall[2][0] = true;
+        player.wall[2][1] = true;
+        player.wall[2][3] = true;
+        // Isolated tile that borders nothing
+        player.wall[4][4] = true;
+
+        let gaps = wall_gaps(&player);
+
+        assert_eq!(
+            gaps,
+            vec![WallGap { row: 2, col: 2, color: TileColor::Blue, points: 4 }]
+        );
+    }
+
+    #[test]
+    fn test_guaranteed_placements_skip_partial_lines() {
+        let mut player = PlayerBoard::new();
+        // Complete Red line in row 1 lands at [1][3], next to [1][4]
+        player.pattern_lines[1].color = Some(TileColor::Red);
+        player.pattern_lines[1].count_filled = 2;
+        player.wall[1][4] = true;
+        // Partial Yellow line in row 3 scores nothing yet
+        player.pattern_lines[3].color = Some(TileColor::Yellow);
+        player.pattern_lines[3].count_filled = 2;
+
+        assert_eq!(guaranteed_placements(&player), vec![(1, TileColor::Red, 2)]);
+    }
 }
@@ -1,3 +1,29 @@
 pub const ENGINE_VERSION: &str = "0.1.0";
 pub const STATE_VERSION: u32 = 1;
 pub const RULESET_ID: &str = "azul_v1_2p";
+
+/// State versions this engine can read, oldest first
+///
+/// Always ends with `STATE_VERSION`. Older entries are versions a migration
+/// can bring up to date.
+pub const SUPPORTED_STATE_VERSIONS: &[u32] = &[STATE_VERSION];
+
+/// Version of this engine build
+pub fn engine_version() -> &'static str {
+    ENGINE_VERSION
+}
+
+/// Version of the state JSON this engine writes
+pub fn state_version() -> u32 {
+    STATE_VERSION
+}
+
+/// Identifier of the ruleset this engine implements
+pub fn ruleset_id() -> &'static str {
+    RULESET_ID
+}
+
+/// State versions this engine can read (see `SUPPORTED_STATE_VERSIONS`)
+pub fn supported_state_versions() -> &'static [u32] {
+    SUPPORTED_STATE_VERSIONS
+}
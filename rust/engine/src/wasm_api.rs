@@ -1,15 +1,52 @@
 use wasm_bindgen::prelude::*;
 use serde_json::json;
-use crate::{State, DraftAction};
+use std::collections::BTreeMap;
+use crate::{State, StateDiff, DraftAction, TileColor, PlayerBoard};
 use crate::rules::{
     list_legal_actions as list_legal_actions_internal,
     apply_action as apply_action_internal,
     resolve_end_of_round as resolve_end_of_round_internal,
+    end_of_round_steps as end_of_round_steps_internal,
     GeneratorParamsJson,
     generate_scenario_with_filters,
     evaluate_best_move as evaluate_best_move_internal,
+    safest_move as safest_move_internal,
+    position_sharpness as position_sharpness_internal,
+    value_of_waiting as value_of_waiting_internal,
+    evaluate_dump_options as evaluate_dump_options_internal,
     grade_user_action as grade_user_action_internal,
+    evaluate_plan as evaluate_plan_internal,
+    export_analysis as export_analysis_internal,
     EvaluatorParams,
+    EvaluatorError,
+    ValidationError,
+    PartialState,
+    import_position as import_position_internal,
+    validate_authored_state as validate_authored_state_internal,
+    find_clean_completions as find_clean_completions_internal,
+    describe_position as describe_position_internal,
+    draftable_colors as draftable_colors_internal,
+    table_color_histogram as table_color_histogram_internal,
+    factory_tile_count as factory_tile_count_internal,
+    opponent_threats as opponent_threats_internal,
+    projected_round_score as projected_round_score_internal,
+    max_achievable_score as max_achievable_score_internal,
+    clear_round_floors as clear_round_floors_internal,
+    simulate_refill_outcomes as simulate_refill_outcomes_internal,
+    redact_bag as redact_bag_internal,
+    wall_target_for_line as wall_target_for_line_internal,
+    wall_gaps as wall_gaps_internal,
+    guaranteed_placements as guaranteed_placements_internal,
+    classify_scenario_theme as classify_scenario_theme_internal,
+    encode_board as encode_board_internal,
+    decode_board as decode_board_internal,
+    unfinishable_lines as unfinishable_lines_internal,
+    is_trap_position as is_trap_position_internal,
+    list_non_overflow_actions as list_non_overflow_actions_internal,
+    is_action_legal as is_action_legal_internal,
+    parse_seed_string,
+    compute_game_stage,
+    compute_round_stage,
 };
 
 /// Helper function to serialize errors consistently
@@ -24,6 +61,30 @@ fn serialize_error(code: &str, message: &str, context: Option<serde_json::Value>
     serde_json::to_string(&error).unwrap()
 }
 
+/// Parse state JSON, rejecting a state whose turn marker names no seat
+///
+/// On failure returns the error JSON to hand back: `INVALID_STATE_JSON` if
+/// the JSON does not parse, `INVALID_STATE` if `active_player_id` is out of
+/// range.
+fn parse_state(state_json: &str) -> Result<State, String> {
+    let state: State = serde_json::from_str(state_json).map_err(|e| serialize_error(
+        "INVALID_STATE_JSON",
+        &format!("Failed to parse state JSON: {}", e),
+        Some(json!({"parse_error": e.to_string()}))
+    ))?;
+    check_active_player(&state)?;
+    Ok(state)
+}
+
+/// Reject a state whose `active_player_id` is not 0 or 1
+fn check_active_player(state: &State) -> Result<(), String> {
+    if state.active_player_id > 1 {
+        let e = ValidationError::invalid_active_player(state.active_player_id);
+        return Err(serialize_error(&e.code, &e.message, e.context));
+    }
+    Ok(())
+}
+
 /// List all legal draft actions for the given player
 ///
 /// # Arguments
@@ -55,6 +116,11 @@ pub fn list_legal_actions(state_json: &str, player_id: u8) -> String {
         );
     }
     
+    // Validate the state's own turn marker
+    if let Err(e) = check_active_player(&state) {
+        return e;
+    }
+    
     // Call engine function
     let actions = list_legal_actions_internal(&state, player_id);
     
@@ -82,15 +148,9 @@ pub fn list_legal_actions(state_json: &str, player_id: u8) -> String {
 #[wasm_bindgen]
 pub fn apply_action(state_json: &str, action_json: &str) -> String {
     // Parse state JSON
-    let state: State = match serde_json::from_str(state_json) {
+    let state = match parse_state(state_json) {
         Ok(s) => s,
-        Err(e) => {
-            return serialize_error(
-                "INVALID_STATE_JSON",
-                &format!("Failed to parse state JSON: {}", e),
-                Some(json!({"parse_error": e.to_string()}))
-            );
-        }
+        Err(e) => return e,
     };
     
     // Parse action JSON
@@ -144,15 +204,9 @@ pub fn apply_action(state_json: &str, action_json: &str) -> String {
 #[wasm_bindgen]
 pub fn resolve_end_of_round(state_json: &str) -> String {
     // Parse state
-    let state: State = match serde_json::from_str(state_json) {
+    let state = match parse_state(state_json) {
         Ok(s) => s,
-        Err(e) => {
-            return serialize_error(
-                "INVALID_STATE_JSON",
-                &format!("Failed to parse state: {}", e),
-                None
-            );
-        }
+        Err(e) => return e,
     };
     
     // Resolve end of round
@@ -180,6 +234,34 @@ pub fn resolve_end_of_round(state_json: &str) -> String {
     }
 }
 
+/// List end-of-round resolution step by step, for animated tutorials
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+///
+/// # Returns
+/// JSON string: either `[{"event": {...}, "state": {...}}, ...]` (the last
+/// state is the resolved round) or error object
+#[wasm_bindgen]
+pub fn end_of_round_steps(state_json: &str) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    match end_of_round_steps_internal(&state) {
+        Ok(steps) => match serde_json::to_string(&steps) {
+            Ok(json) => json,
+            Err(e) => serialize_error(
+                "SERIALIZATION_ERROR",
+                &format!("Failed to serialize steps: {}", e),
+                None
+            ),
+        },
+        Err(e) => serialize_error(&e.code, &e.message, e.context),
+    }
+}
+
 /// Generate a practice scenario using play-forward method
 ///
 /// Creates a plausible game state by:
@@ -271,13 +353,9 @@ pub fn evaluate_best_move(
     player_id: u8,
     params_json: &str,
 ) -> String {
-    let state: State = match serde_json::from_str(state_json) {
+    let state = match parse_state(state_json) {
         Ok(s) => s,
-        Err(e) => return serialize_error(
-            "INVALID_STATE_JSON",
-            &format!("Failed to parse state JSON: {}", e),
-            Some(json!({"parse_error": e.to_string()}))
-        ),
+        Err(e) => return e,
     };
     
     let params: EvaluatorParams = match serde_json::from_str(params_json) {
@@ -298,6 +376,188 @@ pub fn evaluate_best_move(
                 None
             ),
         },
+        Err(e @ EvaluatorError::GameOver) => serialize_error(
+            "GAME_OVER",
+            &e.to_string(),
+            None
+        ),
+        Err(e) => serialize_error(
+            "EVALUATION_FAILED",
+            &e.to_string(),
+            None
+        ),
+    }
+}
+
+/// Find the move with the best worst-case rollout (maximin)
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+/// * `params_json` - JSON string with EvaluatorParams
+///
+/// # Returns
+/// JSON string: either DraftAction or error object
+#[wasm_bindgen]
+pub fn safest_move(
+    state_json: &str,
+    player_id: u8,
+    params_json: &str,
+) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    let params: EvaluatorParams = match serde_json::from_str(params_json) {
+        Ok(p) => p,
+        Err(e) => return serialize_error(
+            "INVALID_PARAMS_JSON",
+            &format!("Failed to parse params JSON: {}", e),
+            Some(json!({"parse_error": e.to_string()}))
+        ),
+    };
+    
+    match safest_move_internal(&state, player_id, &params) {
+        Ok(action) => match serde_json::to_string(&action) {
+            Ok(json) => json,
+            Err(e) => serialize_error(
+                "SERIALIZATION_ERROR",
+                &format!("Failed to serialize action: {}", e),
+                None
+            ),
+        },
+        Err(e @ EvaluatorError::GameOver) => serialize_error(
+            "GAME_OVER",
+            &e.to_string(),
+            None
+        ),
+        Err(e) => serialize_error(
+            "EVALUATION_FAILED",
+            &e.to_string(),
+            None
+        ),
+    }
+}
+
+/// Measure how clearly one move stands out, for adaptive hint frequency
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+/// * `params_json` - JSON string with EvaluatorParams
+///
+/// # Returns
+/// JSON string: either `{"sharpness": x}` (0 to 1) or error object
+#[wasm_bindgen]
+pub fn position_sharpness(
+    state_json: &str,
+    player_id: u8,
+    params_json: &str,
+) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    let params: EvaluatorParams = match serde_json::from_str(params_json) {
+        Ok(p) => p,
+        Err(e) => return serialize_error(
+            "INVALID_PARAMS_JSON",
+            &format!("Failed to parse params JSON: {}", e),
+            Some(json!({"parse_error": e.to_string()}))
+        ),
+    };
+    
+    match position_sharpness_internal(&state, player_id, &params) {
+        Ok(sharpness) => json!({"sharpness": sharpness}).to_string(),
+        Err(e @ EvaluatorError::GameOver) => serialize_error(
+            "GAME_OVER",
+            &e.to_string(),
+            None
+        ),
+        Err(e) => serialize_error(
+            "EVALUATION_FAILED",
+            &e.to_string(),
+            None
+        ),
+    }
+}
+
+/// Rank floor-dump actions by how little they cost (no rollouts)
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+///
+/// # Returns
+/// JSON string: either array of `{action, score}` (best first) or error object
+#[wasm_bindgen]
+pub fn evaluate_dump_options(state_json: &str, player_id: u8) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if player_id > 1 {
+        return serialize_error(
+            "INVALID_PLAYER",
+            &format!("Player ID {} is out of range (must be 0 or 1)", player_id),
+            Some(json!({"player_id": player_id}))
+        );
+    }
+    
+    let options: Vec<_> = evaluate_dump_options_internal(&state, player_id)
+        .into_iter()
+        .map(|(action, score)| json!({"action": action, "score": score}))
+        .collect();
+    
+    match serde_json::to_string(&options) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize dump options: {}", e),
+            None
+        ),
+    }
+}
+
+/// Export a full analysis of a position as a shareable report
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+/// * `params_json` - JSON string with EvaluatorParams
+///
+/// # Returns
+/// JSON string: either AnalysisReport or error object
+#[wasm_bindgen]
+pub fn export_analysis(
+    state_json: &str,
+    player_id: u8,
+    params_json: &str,
+) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    let params: EvaluatorParams = match serde_json::from_str(params_json) {
+        Ok(p) => p,
+        Err(e) => return serialize_error(
+            "INVALID_PARAMS_JSON",
+            &format!("Failed to parse params JSON: {}", e),
+            Some(json!({"parse_error": e.to_string()}))
+        ),
+    };
+    
+    match export_analysis_internal(&state, player_id, &params) {
+        Ok(report) => report.to_json(),
+        Err(e @ EvaluatorError::GameOver) => serialize_error(
+            "GAME_OVER",
+            &e.to_string(),
+            None
+        ),
         Err(e) => serialize_error(
             "EVALUATION_FAILED",
             &e.to_string(),
@@ -315,7 +575,9 @@ pub fn evaluate_best_move(
 /// * `params_json` - JSON string with EvaluatorParams
 ///
 /// # Returns
-/// JSON string: either EvaluationResult with user action grading or error object
+/// JSON string: EvaluationResult with user action grading,
+/// `{"illegal_user_move": {...}}` explaining an illegal action alongside the
+/// best legal move, or error object
 #[wasm_bindgen]
 pub fn grade_user_action(
     state_json: &str,
@@ -323,13 +585,9 @@ pub fn grade_user_action(
     user_action_json: &str,
     params_json: &str,
 ) -> String {
-    let state: State = match serde_json::from_str(state_json) {
+    let state = match parse_state(state_json) {
         Ok(s) => s,
-        Err(e) => return serialize_error(
-            "INVALID_STATE_JSON",
-            &format!("Failed to parse state JSON: {}", e),
-            Some(json!({"parse_error": e.to_string()}))
-        ),
+        Err(e) => return e,
     };
     
     let user_action: DraftAction = match serde_json::from_str(user_action_json) {
@@ -353,6 +611,11 @@ pub fn grade_user_action(
     // First evaluate best move
     let best_result = match evaluate_best_move_internal(&state, player_id, &params) {
         Ok(r) => r,
+        Err(e @ EvaluatorError::GameOver) => return serialize_error(
+            "GAME_OVER",
+            &e.to_string(),
+            None
+        ),
         Err(e) => return serialize_error(
             "EVALUATION_FAILED",
             &e.to_string(),
@@ -370,6 +633,9 @@ pub fn grade_user_action(
                 None
             ),
         },
+        Err(EvaluatorError::IllegalUserMove(illegal)) => {
+            json!({"illegal_user_move": illegal}).to_string()
+        }
         Err(e) => serialize_error(
             "GRADING_FAILED",
             &e.to_string(),
@@ -377,3 +643,1007 @@ pub fn grade_user_action(
         ),
     }
 }
+
+/// Evaluate a sequence of the player's moves across several turns
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1), who must be on turn
+/// * `plan_json` - JSON array of DraftActions, in the order they are played
+/// * `params_json` - JSON string with EvaluatorParams
+///
+/// # Returns
+/// JSON string: PlanEvaluation, `{"illegal_plan_step": {"step", "legality",
+/// "explanation"}}` for the first step that breaks a rule, or error object
+#[wasm_bindgen]
+pub fn evaluate_plan(
+    state_json: &str,
+    player_id: u8,
+    plan_json: &str,
+    params_json: &str,
+) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    let plan: Vec<DraftAction> = match serde_json::from_str(plan_json) {
+        Ok(p) => p,
+        Err(e) => return serialize_error(
+            "INVALID_ACTION_JSON",
+            &format!("Failed to parse plan JSON: {}", e),
+            Some(json!({"parse_error": e.to_string()}))
+        ),
+    };
+    
+    let params: EvaluatorParams = match serde_json::from_str(params_json) {
+        Ok(p) => p,
+        Err(e) => return serialize_error(
+            "INVALID_PARAMS_JSON",
+            &format!("Failed to parse params JSON: {}", e),
+            Some(json!({"parse_error": e.to_string()}))
+        ),
+    };
+    
+    match evaluate_plan_internal(&state, player_id, &plan, &params) {
+        Ok(result) => match serde_json::to_string(&result) {
+            Ok(json) => json,
+            Err(e) => serialize_error(
+                "SERIALIZATION_ERROR",
+                &format!("Failed to serialize result: {}", e),
+                None
+            ),
+        },
+        Err(EvaluatorError::IllegalPlanStep { step, legality }) => json!({
+            "illegal_plan_step": {
+                "step": step,
+                "legality": legality,
+                "explanation": legality.to_string(),
+            }
+        }).to_string(),
+        Err(e @ EvaluatorError::GameOver) => serialize_error(
+            "GAME_OVER",
+            &e.to_string(),
+            None
+        ),
+        Err(e) => serialize_error(
+            "EVALUATION_FAILED",
+            &e.to_string(),
+            None
+        ),
+    }
+}
+
+/// Import a position transcribed from a physical game
+///
+/// The bag is inferred from the tiles in play so the result satisfies tile
+/// conservation; any bag in the input is ignored.
+///
+/// # Arguments
+/// * `partial_json` - JSON string with a PartialState (state without bag)
+///
+/// # Returns
+/// JSON string: either the completed state or error object
+#[wasm_bindgen]
+pub fn import_position(partial_json: &str) -> String {
+    let partial: PartialState = match serde_json::from_str(partial_json) {
+        Ok(p) => p,
+        Err(e) => return serialize_error(
+            "INVALID_STATE_JSON",
+            &format!("Failed to parse position JSON: {}", e),
+            Some(json!({"parse_error": e.to_string()}))
+        ),
+    };
+    
+    match import_position_internal(partial) {
+        Ok(state) => match serde_json::to_string(&state) {
+            Ok(json) => json,
+            Err(e) => serialize_error(
+                "SERIALIZATION_ERROR",
+                &format!("Failed to serialize state: {}", e),
+                None
+            ),
+        },
+        Err(e) => serialize_error(
+            "IMPORT_FAILED",
+            &e.to_string(),
+            None
+        ),
+    }
+}
+
+/// Check a hand-authored puzzle position
+///
+/// Verifies tile conservation, that declared stages match the board, and
+/// that the bag and lid can support a full refill.
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state, with explicit bag and lid
+///
+/// # Returns
+/// JSON string: `{"valid": true}` or error object with code `INVALID_AUTHORED_STATE`
+#[wasm_bindgen]
+pub fn validate_authored_state(state_json: &str) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    match validate_authored_state_internal(&state) {
+        Ok(()) => json!({"valid": true}).to_string(),
+        Err(e) => serialize_error(
+            "INVALID_AUTHORED_STATE",
+            &e.to_string(),
+            None
+        ),
+    }
+}
+
+/// List moves that complete a pattern line with zero tiles to the floor
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+///
+/// # Returns
+/// JSON string: either action array (possibly empty) or error object
+#[wasm_bindgen]
+pub fn find_clean_completions(state_json: &str, player_id: u8) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if player_id > 1 {
+        return serialize_error(
+            "INVALID_PLAYER",
+            &format!("Player ID {} is out of range (must be 0 or 1)", player_id),
+            Some(json!({"player_id": player_id}))
+        );
+    }
+    
+    let actions = find_clean_completions_internal(&state, player_id);
+    
+    match serde_json::to_string(&actions) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize actions: {}", e),
+            None
+        ),
+    }
+}
+
+/// Count the tiles left in one factory
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `factory_idx` - Factory index (0-based)
+///
+/// # Returns
+/// JSON string: either `{"count": n}` (0-4 in a valid state) or error object
+#[wasm_bindgen]
+pub fn factory_tile_count(state_json: &str, factory_idx: usize) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if factory_idx >= state.factories.len() {
+        return serialize_error(
+            "INVALID_SOURCE",
+            &format!("Factory index {} is out of bounds", factory_idx),
+            Some(json!({"factory_index": factory_idx}))
+        );
+    }
+    
+    json!({"count": factory_tile_count_internal(&state, factory_idx)}).to_string()
+}
+
+/// Summarize a position in one call
+///
+/// Batches the lookups a UI needs when showing a position, saving separate
+/// round-trips for legal actions, colors, stages and clean completions.
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+///
+/// # Returns
+/// JSON string: either `{legal_action_count, draftable_colors, game_stage,
+/// round_stage, clean_completions}` plus `forced_move` when exactly one
+/// action is legal, or error object
+#[wasm_bindgen]
+pub fn analyze_state(state_json: &str, player_id: u8) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if player_id > 1 {
+        return serialize_error(
+            "INVALID_PLAYER",
+            &format!("Player ID {} is out of range (must be 0 or 1)", player_id),
+            Some(json!({"player_id": player_id}))
+        );
+    }
+    
+    let legal_actions = list_legal_actions_internal(&state, player_id);
+    let mut colors: Vec<_> = draftable_colors_internal(&state).into_iter().collect();
+    colors.sort();
+    
+    let mut analysis = json!({
+        "legal_action_count": legal_actions.len(),
+        "draftable_colors": colors,
+        "game_stage": compute_game_stage(&state),
+        "round_stage": compute_round_stage(&state),
+        "clean_completions": find_clean_completions_internal(&state, player_id),
+    });
+    if let [forced_move] = legal_actions.as_slice() {
+        analysis["forced_move"] = json!(forced_move);
+    }
+    
+    analysis.to_string()
+}
+
+/// Describe a position in plain language for screen readers
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player whose board is described (0 or 1)
+///
+/// # Returns
+/// JSON string: either PositionDescription or error object
+#[wasm_bindgen]
+pub fn describe_position(state_json: &str, player_id: u8) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if player_id > 1 {
+        return serialize_error(
+            "INVALID_PLAYER",
+            &format!("Player ID {} is out of range (must be 0 or 1)", player_id),
+            Some(json!({"player_id": player_id}))
+        );
+    }
+    
+    let description = describe_position_internal(&state, player_id);
+    
+    match serde_json::to_string(&description) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize description: {}", e),
+            None
+        ),
+    }
+}
+
+/// List the colors that can be drafted this turn
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+///
+/// # Returns
+/// JSON string: either color array (canonical order, possibly empty) or
+/// error object
+#[wasm_bindgen]
+pub fn draftable_colors(state_json: &str) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    let mut colors: Vec<_> = draftable_colors_internal(&state).into_iter().collect();
+    colors.sort();
+    
+    match serde_json::to_string(&colors) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize colors: {}", e),
+            None
+        ),
+    }
+}
+
+/// Count draftable tiles on the table by color
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+///
+/// # Returns
+/// JSON string: either `{color: count}` (canonical order, colors on the
+/// table only) or error object
+#[wasm_bindgen]
+pub fn table_color_histogram(state_json: &str) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    let histogram: BTreeMap<TileColor, u8> = table_color_histogram_internal(&state)
+        .into_iter()
+        .collect();
+    
+    match serde_json::to_string(&histogram) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize histogram: {}", e),
+            None
+        ),
+    }
+}
+
+/// Color legend for rendering tiles by symbol as well as color
+///
+/// # Returns
+/// JSON string: array of `{color, symbol, label}` in canonical color order
+#[wasm_bindgen]
+pub fn tile_legend() -> String {
+    let legend: Vec<_> = TileColor::all()
+        .map(|color| json!({
+            "color": color,
+            "symbol": color.symbol().to_string(),
+            "label": color.label(),
+        }))
+        .collect();
+    
+    match serde_json::to_string(&legend) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize tile legend: {}", e),
+            None
+        ),
+    }
+}
+
+/// List the pattern lines an opponent could complete next turn
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `opponent_id` - Player whose lines are checked (0 or 1)
+///
+/// # Returns
+/// JSON string: either threat array (possibly empty) or error object
+#[wasm_bindgen]
+pub fn opponent_threats(state_json: &str, opponent_id: u8) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if opponent_id > 1 {
+        return serialize_error(
+            "INVALID_PLAYER",
+            &format!("Player ID {} is out of range (must be 0 or 1)", opponent_id),
+            Some(json!({"player_id": opponent_id}))
+        );
+    }
+    
+    let threats = opponent_threats_internal(&state, opponent_id);
+    
+    match serde_json::to_string(&threats) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize threats: {}", e),
+            None
+        ),
+    }
+}
+
+/// Project a player's score change if the round ended immediately
+///
+/// Resolves pattern lines and floor penalties on a copy of the state; no
+/// rollouts are run.
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+///
+/// # Returns
+/// JSON string: either `{"projected_delta": n}` or error object
+#[wasm_bindgen]
+pub fn projected_round_score(state_json: &str, player_id: u8) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if player_id > 1 {
+        return serialize_error(
+            "INVALID_PLAYER",
+            &format!("Player ID {} is out of range (must be 0 or 1)", player_id),
+            Some(json!({"player_id": player_id}))
+        );
+    }
+    
+    match projected_round_score_internal(&state, player_id) {
+        Ok(delta) => json!({"projected_delta": delta}).to_string(),
+        Err(e) => {
+            let error = json!({
+                "error": {
+                    "code": e.code,
+                    "message": e.message,
+                    "context": e.context,
+                }
+            });
+            serde_json::to_string(&error).unwrap()
+        }
+    }
+}
+
+/// Upper bound on the final score a player can still reach
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+///
+/// # Returns
+/// JSON string: either `{"max_score": n}` or error object
+#[wasm_bindgen]
+pub fn max_achievable_score(state_json: &str, player_id: u8) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if player_id > 1 {
+        return serialize_error(
+            "INVALID_PLAYER",
+            &format!("Player ID {} is out of range (must be 0 or 1)", player_id),
+            Some(json!({"player_id": player_id}))
+        );
+    }
+    
+    match max_achievable_score_internal(&state, player_id) {
+        Ok(max_score) => json!({"max_score": max_score}).to_string(),
+        Err(e) => serialize_error(&e.code, &e.message, e.context),
+    }
+}
+
+/// Get the wall cell a completed pattern line resolves to
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+/// * `row` - Pattern line index (0-4)
+///
+/// # Returns
+/// JSON string: `{"row": r, "col": c}`, `null` if the line is not complete,
+/// or error object
+#[wasm_bindgen]
+pub fn wall_target_for_line(state_json: &str, player_id: u8, row: usize) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if player_id > 1 {
+        return serialize_error(
+            "INVALID_PLAYER",
+            &format!("Player ID {} is out of range (must be 0 or 1)", player_id),
+            Some(json!({"player_id": player_id}))
+        );
+    }
+    
+    match wall_target_for_line_internal(&state.players[player_id as usize], row) {
+        Some((row, col)) => json!({"row": row, "col": col}).to_string(),
+        None => "null".to_string(),
+    }
+}
+
+/// List empty wall cells that would connect existing tiles
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+///
+/// # Returns
+/// JSON string: either gap array (possibly empty) or error object
+#[wasm_bindgen]
+pub fn wall_gaps(state_json: &str, player_id: u8) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if player_id > 1 {
+        return serialize_error(
+            "INVALID_PLAYER",
+            &format!("Player ID {} is out of range (must be 0 or 1)", player_id),
+            Some(json!({"player_id": player_id}))
+        );
+    }
+    
+    let gaps = wall_gaps_internal(&state.players[player_id as usize]);
+    
+    match serde_json::to_string(&gaps) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize wall gaps: {}", e),
+            None
+        ),
+    }
+}
+
+/// List the wall placements a player's complete lines lock in this round
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+///
+/// # Returns
+/// JSON string: either `[{"row", "color", "points"}, ...]` (top to bottom,
+/// possibly empty) or error object
+#[wasm_bindgen]
+pub fn guaranteed_placements(state_json: &str, player_id: u8) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if player_id > 1 {
+        return serialize_error(
+            "INVALID_PLAYER",
+            &format!("Player ID {} is out of range (must be 0 or 1)", player_id),
+            Some(json!({"player_id": player_id}))
+        );
+    }
+    
+    let placements: Vec<_> = guaranteed_placements_internal(&state.players[player_id as usize])
+        .into_iter()
+        .map(|(row, color, points)| json!({"row": row, "color": color, "points": points}))
+        .collect();
+    
+    json!(placements).to_string()
+}
+
+/// Discard all floor tiles to the lid without scoring
+///
+/// Sandbox helper for rewinding a round; scores and walls are unchanged.
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+///
+/// # Returns
+/// JSON string: either new state or error object
+#[wasm_bindgen]
+pub fn clear_round_floors(state_json: &str) -> String {
+    let mut state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    clear_round_floors_internal(&mut state);
+    
+    match serde_json::to_string(&state) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize state: {}", e),
+            None
+        ),
+    }
+}
+
+/// Tag a scenario with the dominant decision it showcases
+///
+/// Evaluates the active player's best move against its closest rivals and
+/// stamps the resulting theme into the state's `scenario_theme`.
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `params_json` - JSON string with EvaluatorParams
+///
+/// # Returns
+/// JSON string: either the tagged state or error object
+#[wasm_bindgen]
+pub fn classify_scenario_theme(state_json: &str, params_json: &str) -> String {
+    let mut state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    let params: EvaluatorParams = match serde_json::from_str(params_json) {
+        Ok(p) => p,
+        Err(e) => return serialize_error(
+            "INVALID_PARAMS_JSON",
+            &format!("Failed to parse params JSON: {}", e),
+            Some(json!({"parse_error": e.to_string()}))
+        ),
+    };
+    
+    match classify_scenario_theme_internal(&state, &params) {
+        Ok(theme) => {
+            state.scenario_theme = Some(theme);
+            match serde_json::to_string(&state) {
+                Ok(json) => json,
+                Err(e) => serialize_error(
+                    "SERIALIZATION_ERROR",
+                    &format!("Failed to serialize state: {}", e),
+                    None
+                ),
+            }
+        }
+        Err(e @ EvaluatorError::GameOver) => serialize_error(
+            "GAME_OVER",
+            &e.to_string(),
+            None
+        ),
+        Err(e) => serialize_error(
+            "EVALUATION_FAILED",
+            &e.to_string(),
+            None
+        ),
+    }
+}
+
+/// Compute the changes between two states
+///
+/// # Arguments
+/// * `from_json` - JSON string with the earlier state
+/// * `to_json` - JSON string with the later state
+///
+/// # Returns
+/// JSON string: either StateDiff or error object
+#[wasm_bindgen]
+pub fn diff_states(from_json: &str, to_json: &str) -> String {
+    let from: State = match serde_json::from_str(from_json) {
+        Ok(s) => s,
+        Err(e) => return serialize_error(
+            "INVALID_STATE_JSON",
+            &format!("Failed to parse state JSON: {}", e),
+            Some(json!({"parse_error": e.to_string()}))
+        ),
+    };
+    
+    let to: State = match serde_json::from_str(to_json) {
+        Ok(s) => s,
+        Err(e) => return serialize_error(
+            "INVALID_STATE_JSON",
+            &format!("Failed to parse state JSON: {}", e),
+            Some(json!({"parse_error": e.to_string()}))
+        ),
+    };
+    
+    match serde_json::to_string(&from.diff(&to)) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize diff: {}", e),
+            None
+        ),
+    }
+}
+
+/// Apply a diff produced by `diff_states`
+///
+/// # Arguments
+/// * `state_json` - JSON string with the state the diff was taken from
+/// * `diff_json` - JSON string with a StateDiff
+///
+/// # Returns
+/// JSON string: either updated state or error object
+#[wasm_bindgen]
+pub fn apply_state_diff(state_json: &str, diff_json: &str) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    let diff: StateDiff = match serde_json::from_str(diff_json) {
+        Ok(d) => d,
+        Err(e) => return serialize_error(
+            "INVALID_DIFF_JSON",
+            &format!("Failed to parse diff JSON: {}", e),
+            Some(json!({"parse_error": e.to_string()}))
+        ),
+    };
+    
+    match serde_json::to_string(&state.apply_diff(&diff)) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize state: {}", e),
+            None
+        ),
+    }
+}
+
+/// Hide the bag contents, keeping only the implied bag size
+///
+/// Evaluating the returned state samples plausible bags per rollout.
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+///
+/// # Returns
+/// JSON string: either redacted state or error object
+#[wasm_bindgen]
+pub fn redact_bag(state_json: &str) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    match serde_json::to_string(&redact_bag_internal(&state)) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize state: {}", e),
+            None
+        ),
+    }
+}
+
+/// Sample factory refills from the current bag and lid
+///
+/// Returns, per color, a histogram of how many tiles of that color appeared
+/// across all factories, for teaching draw variance.
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `samples` - Number of refills to simulate
+/// * `seed` - String seed for reproducibility
+///
+/// # Returns
+/// JSON string: either RefillSimulation or error object
+#[wasm_bindgen]
+pub fn simulate_refill_outcomes(state_json: &str, samples: u32, seed: &str) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    let seed = match parse_seed_string(seed) {
+        Ok(s) => s,
+        Err(e) => return serialize_error("INVALID_PARAMS", &e, None),
+    };
+    
+    let simulation = simulate_refill_outcomes_internal(&state, samples as usize, seed);
+    
+    match serde_json::to_string(&simulation) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize simulation: {}", e),
+            None
+        ),
+    }
+}
+
+/// Encode one player's board as a short shareable code
+///
+/// # Arguments
+/// * `board_json` - JSON string representing a PlayerBoard
+///
+/// # Returns
+/// JSON string: either `{"code": "..."}` or error object
+#[wasm_bindgen]
+pub fn encode_board(board_json: &str) -> String {
+    let board: PlayerBoard = match serde_json::from_str(board_json) {
+        Ok(b) => b,
+        Err(e) => return serialize_error(
+            "INVALID_BOARD_JSON",
+            &format!("Failed to parse board JSON: {}", e),
+            Some(json!({"parse_error": e.to_string()}))
+        ),
+    };
+    
+    json!({"code": encode_board_internal(&board)}).to_string()
+}
+
+/// Decode a board code produced by `encode_board`
+///
+/// # Arguments
+/// * `code` - Board code string
+///
+/// # Returns
+/// JSON string: either PlayerBoard or error object with code `INVALID_BOARD_CODE`
+#[wasm_bindgen]
+pub fn decode_board(code: &str) -> String {
+    let board = match decode_board_internal(code) {
+        Ok(b) => b,
+        Err(e) => return serialize_error(
+            "INVALID_BOARD_CODE",
+            &e.to_string(),
+            Some(json!({"code": code}))
+        ),
+    };
+    
+    match serde_json::to_string(&board) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize board: {}", e),
+            None
+        ),
+    }
+}
+
+/// List started pattern lines that can no longer be finished
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+///
+/// # Returns
+/// JSON string: either array of row indices (possibly empty) or error object
+#[wasm_bindgen]
+pub fn unfinishable_lines(state_json: &str, player_id: u8) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if player_id > 1 {
+        return serialize_error(
+            "INVALID_PLAYER",
+            &format!("Player ID {} is out of range (must be 0 or 1)", player_id),
+            Some(json!({"player_id": player_id}))
+        );
+    }
+    
+    let rows = unfinishable_lines_internal(&state, player_id);
+    
+    match serde_json::to_string(&rows) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize rows: {}", e),
+            None
+        ),
+    }
+}
+
+/// Label a position where every reasonable move hurts (coaching mode)
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+///
+/// # Returns
+/// JSON string: `{"trap": null}` or `{"trap": {"kind": ...}}`, or error object
+#[wasm_bindgen]
+pub fn is_trap_position(state_json: &str, player_id: u8) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if player_id > 1 {
+        return serialize_error(
+            "INVALID_PLAYER",
+            &format!("Player ID {} is out of range (must be 0 or 1)", player_id),
+            Some(json!({"player_id": player_id}))
+        );
+    }
+    
+    json!({"trap": is_trap_position_internal(&state, player_id)}).to_string()
+}
+
+/// List legal actions whose tiles fit without overflowing ("safe moves")
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+/// * `include_floor` - Also list floor dumps
+///
+/// # Returns
+/// JSON string: either array of DraftAction or error object
+#[wasm_bindgen]
+pub fn list_non_overflow_actions(state_json: &str, player_id: u8, include_floor: bool) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    if player_id > 1 {
+        return serialize_error(
+            "INVALID_PLAYER",
+            &format!("Player ID {} is out of range (must be 0 or 1)", player_id),
+            Some(json!({"player_id": player_id}))
+        );
+    }
+    
+    let actions = list_non_overflow_actions_internal(&state, player_id, include_floor);
+    
+    match serde_json::to_string(&actions) {
+        Ok(json) => json,
+        Err(e) => serialize_error(
+            "SERIALIZATION_ERROR",
+            &format!("Failed to serialize actions: {}", e),
+            None
+        ),
+    }
+}
+
+/// Check whether one action is legal, and if not, why
+///
+/// Cheaper than searching `list_legal_actions` for the action.
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+/// * `action_json` - JSON string representing draft action
+///
+/// # Returns
+/// JSON string: `{"legal": true}`, `{"legal": false, "reason": ..., "message": ...}`,
+/// or error object
+#[wasm_bindgen]
+pub fn check_action_legal(state_json: &str, player_id: u8, action_json: &str) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    let action: DraftAction = match serde_json::from_str(action_json) {
+        Ok(a) => a,
+        Err(e) => return serialize_error(
+            "INVALID_ACTION_JSON",
+            &format!("Failed to parse action JSON: {}", e),
+            Some(json!({"parse_error": e.to_string()}))
+        ),
+    };
+    
+    match is_action_legal_internal(&state, player_id, &action) {
+        Ok(()) => json!({"legal": true}).to_string(),
+        Err(reason) => json!({
+            "legal": false,
+            "reason": reason,
+            "message": reason.to_string(),
+        }).to_string(),
+    }
+}
+
+/// Weigh grabbing the center now against the best factory pick
+///
+/// # Arguments
+/// * `state_json` - JSON string representing game state
+/// * `player_id` - Player ID (0 or 1)
+/// * `params_json` - JSON string with EvaluatorParams
+///
+/// # Returns
+/// JSON string: either `{"value_of_waiting": x}` (center EV minus factory EV;
+/// negative means a factory pick is better) or error object
+#[wasm_bindgen]
+pub fn value_of_waiting(
+    state_json: &str,
+    player_id: u8,
+    params_json: &str,
+) -> String {
+    let state = match parse_state(state_json) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    let params: EvaluatorParams = match serde_json::from_str(params_json) {
+        Ok(p) => p,
+        Err(e) => return serialize_error(
+            "INVALID_PARAMS_JSON",
+            &format!("Failed to parse params JSON: {}", e),
+            Some(json!({"parse_error": e.to_string()}))
+        ),
+    };
+    
+    match value_of_waiting_internal(&state, player_id, &params) {
+        Ok(value) => json!({"value_of_waiting": value}).to_string(),
+        Err(e @ EvaluatorError::GameOver) => serialize_error(
+            "GAME_OVER",
+            &e.to_string(),
+            None
+        ),
+        Err(e) => serialize_error(
+            "EVALUATION_FAILED",
+            &e.to_string(),
+            None
+        ),
+    }
+}
@@ -29,7 +29,7 @@ fn test_list_legal_actions_valid_state() {
     assert!(parsed.is_array(), "Expected action array, got: {}", result);
     
     let actions = parsed.as_array().unwrap();
-    assert!(actions.len() > 0, "Should have at least one action");
+    assert!(!actions.is_empty(), "Should have at least one action");
 }
 
 #[test]
@@ -52,6 +52,28 @@ fn test_list_legal_actions_invalid_player() {
     assert_eq!(error["error"]["code"], "INVALID_PLAYER");
 }
 
+#[test]
+fn test_out_of_range_active_player_is_clean_error() {
+    let mut state: Value = serde_json::from_str(include_str!("fixtures/mid_game_state.json")).unwrap();
+    state["active_player_id"] = Value::from(5);
+    let state_json = state.to_string();
+    
+    let result = engine::wasm_api::list_legal_actions(&state_json, 0);
+    let error: Value = serde_json::from_str(&result).unwrap();
+    assert_eq!(error["error"]["code"], "INVALID_STATE");
+    
+    let action_json = r#"{"source":{"Factory":0},"color":"Blue","destination":"Floor"}"#;
+    let result = engine::wasm_api::apply_action(&state_json, action_json);
+    let error: Value = serde_json::from_str(&result).unwrap();
+    assert_eq!(error["error"]["code"], "INVALID_STATE");
+    assert_eq!(error["error"]["context"]["active_player_id"], 5);
+    
+    let result = engine::wasm_api::evaluate_best_move(&state_json, 0, "{}");
+    let error: Value = serde_json::from_str(&result).unwrap();
+    assert_eq!(error["error"]["code"], "INVALID_STATE");
+    assert_eq!(error["error"]["message"], "Active player ID 5 is out of range");
+}
+
 #[test]
 fn test_apply_action_returns_json_string() {
     let state_json = include_str!("fixtures/mid_game_state.json");
@@ -126,3 +148,97 @@ fn test_apply_action_illegal_move() {
     // Should be an engine validation error (SOURCE_EMPTY)
     assert_eq!(error["error"]["code"], "SOURCE_EMPTY");
 }
+
+#[test]
+fn test_evaluate_best_move_game_over() {
+    let state_json = include_str!("fixtures/mid_game_state.json");
+    let mut state: Value = serde_json::from_str(state_json).unwrap();
+    
+    // Complete wall row 0 for player 1 to end the game
+    state["players"][1]["wall"][0] = serde_json::json!([true, true, true, true, true]);
+    
+    let result = engine::wasm_api::evaluate_best_move(
+        &state.to_string(),
+        0,
+        r#"{"evaluator_seed": 42}"#,
+    );
+    
+    let error: Value = serde_json::from_str(&result).unwrap();
+    assert_eq!(error["error"]["code"], "GAME_OVER");
+}
+
+#[test]
+fn test_factory_tile_count_returns_object() {
+    let state_json = include_str!("fixtures/mid_game_state.json");
+    
+    let parsed: Value = serde_json::from_str(&engine::wasm_api::factory_tile_count(state_json, 0)).unwrap();
+    assert_eq!(parsed, serde_json::json!({"count": 4}));
+    
+    let parsed: Value = serde_json::from_str(&engine::wasm_api::factory_tile_count(state_json, 2)).unwrap();
+    assert_eq!(parsed["count"], 0);
+    
+    let error: Value = serde_json::from_str(&engine::wasm_api::factory_tile_count(state_json, 9)).unwrap();
+    assert_eq!(error["error"]["code"], "INVALID_SOURCE");
+}
+
+#[test]
+fn test_import_position_infers_bag() {
+    let state_json = include_str!("fixtures/mid_game_state.json");
+    let mut position: Value = serde_json::from_str(state_json).unwrap();
+    position.as_object_mut().unwrap().remove("bag");
+    
+    let result = engine::wasm_api::import_position(&position.to_string());
+    
+    let state: engine::State = serde_json::from_str(&result)
+        .unwrap_or_else(|_| panic!("Expected state, got: {}", result));
+    assert!(engine::check_tile_conservation(&state).is_ok());
+}
+
+#[test]
+fn test_get_version_matches_getters() {
+    let parsed: Value = serde_json::from_str(&engine::get_version()).unwrap();
+    
+    assert_eq!(parsed["engine_version"], engine::engine_version());
+    assert_eq!(parsed["state_version"], engine::state_version());
+    assert_eq!(parsed["ruleset_id"], engine::ruleset_id());
+    assert_eq!(engine::supported_state_versions().last(), Some(&engine::state_version()));
+}
+
+#[test]
+fn test_get_capabilities_keys() {
+    let parsed: Value = serde_json::from_str(&engine::get_capabilities()).unwrap();
+    
+    for key in ["player_counts", "policy_mixes", "utility_modes", "caching", "parallelism"] {
+        assert!(parsed.get(key).is_some(), "Missing key {}", key);
+    }
+    assert_eq!(parsed["player_counts"], serde_json::json!([2]));
+    assert!(parsed["policy_mixes"].as_array().unwrap().contains(&Value::from("mixed")));
+}
+
+#[test]
+fn test_analyze_state_payload() {
+    let state_json = include_str!("fixtures/mid_game_state.json");
+    let parsed: Value = serde_json::from_str(&engine::wasm_api::analyze_state(state_json, 0)).unwrap();
+    
+    for key in ["legal_action_count", "draftable_colors", "game_stage", "round_stage", "clean_completions"] {
+        assert!(parsed.get(key).is_some(), "Missing key {}", key);
+    }
+    assert!(parsed["legal_action_count"].as_u64().unwrap() > 1);
+    assert!(parsed.get("forced_move").is_none());
+    
+    // Leave one Blue tile and block every pattern line, so only Floor is legal
+    let mut state: Value = serde_json::from_str(state_json).unwrap();
+    state["factories"] = serde_json::json!([{"Blue": 1}, {}, {}, {}, {}]);
+    state["center"]["tiles"] = serde_json::json!({});
+    for row in 0..5 {
+        state["players"][0]["pattern_lines"][row] =
+            serde_json::json!({"capacity": row + 1, "color": "Red", "count_filled": 1});
+    }
+    
+    let parsed: Value = serde_json::from_str(
+        &engine::wasm_api::analyze_state(&state.to_string(), 0)
+    ).unwrap();
+    assert_eq!(parsed["legal_action_count"], 1);
+    assert_eq!(parsed["draftable_colors"], serde_json::json!(["Blue"]));
+    assert_eq!(parsed["forced_move"]["destination"], "Floor");
+}
@@ -0,0 +1,36 @@
+use std::time::Instant;
+use engine::{evaluate_best_move, EvaluatorParams, State};
+
+// Guards against rollout-cost regressions that would blow the interactive
+// 250ms budget. The threshold is deliberately generous: it has to hold for
+// unoptimized test builds on shared CI runners, so it only catches large
+// (multi-x) slowdowns. Use `cargo bench` for precise measurements.
+const MAX_ELAPSED_MS: u128 = 2_000;
+
+#[test]
+fn test_evaluate_best_move_20_candidates_10_rollouts_within_threshold() {
+    let state_json = include_str!("fixtures/evaluator_benchmark_state.json");
+    let state: State = serde_json::from_str(state_json).unwrap();
+    
+    let params = EvaluatorParams {
+        // Large budget so every candidate gets its full rollout count
+        time_budget_ms: 60_000,
+        rollouts_per_action: 10,
+        evaluator_seed: 12345,
+        shortlist_size: 20,
+        ..EvaluatorParams::default()
+    };
+    
+    let start = Instant::now();
+    let result = evaluate_best_move(&state, state.active_player_id, &params).unwrap();
+    let elapsed = start.elapsed().as_millis();
+    
+    assert_eq!(result.metadata.candidates_evaluated, 20);
+    assert_eq!(result.metadata.rollouts_run, 200);
+    assert!(
+        elapsed < MAX_ELAPSED_MS,
+        "20 candidates x 10 rollouts took {}ms (limit {}ms)",
+        elapsed,
+        MAX_ELAPSED_MS
+    );
+}